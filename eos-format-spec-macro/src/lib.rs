@@ -54,13 +54,55 @@ fn parse_directive(directive: u8) -> Result<&'static str, String> {
         b'f' => Ok("eos::fmt::FormatSpecKind::Nanosecond"),
         b'o' => Ok("eos::fmt::FormatSpecKind::UtcOffset"),
         b'z' => Ok("eos::fmt::FormatSpecKind::UtcOffsetBrief"),
+        b'J' => Ok("eos::fmt::FormatSpecKind::UtcOffsetBriefZ"),
         b'Z' => Ok("eos::fmt::FormatSpecKind::ZoneName"),
+        b's' => Ok("eos::fmt::FormatSpecKind::Timestamp"),
         b'%' => Ok("eos::fmt::FormatSpecKind::Escape"),
         b'_' | b'#' => Err("expected specifier after `_` or `#` modifier".to_owned()),
         _ => Err(format!("unexpected specifier (`{}`)", directive as char)),
     }
 }
 
+/// Expands a compound specifier such as `%F` into its equivalent directives and separator.
+fn parse_compound_directive(directive: u8) -> Option<([&'static str; 3], &'static str)> {
+    match directive {
+        b'F' => Some((
+            [
+                "eos::fmt::FormatSpecKind::Year",
+                "eos::fmt::FormatSpecKind::Month",
+                "eos::fmt::FormatSpecKind::Day",
+            ],
+            "-",
+        )),
+        b'T' => Some((
+            [
+                "eos::fmt::FormatSpecKind::Hour",
+                "eos::fmt::FormatSpecKind::Minute",
+                "eos::fmt::FormatSpecKind::Second",
+            ],
+            ":",
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the code for the given directive with the modifier (e.g. `.with_no_padding()`) applied.
+///
+/// Compound specifiers expand into multiple comma separated array elements.
+fn directive_code(directive: u8, modifier: &str) -> Result<String, String> {
+    match parse_compound_directive(directive) {
+        Some((kinds, separator)) => {
+            let raw = format!("eos::fmt::FormatSpec::raw({:?})", separator);
+            let code: Vec<_> = kinds
+                .iter()
+                .map(|kind| format!("eos::fmt::FormatSpec::new({}){}", kind, modifier))
+                .collect();
+            Ok(code.join(&format!(",\n{},\n", raw)))
+        }
+        None => parse_directive(directive).map(|s| format!("eos::fmt::FormatSpec::new({}){}", s, modifier)),
+    }
+}
+
 impl<'a> Iterator for CodeIterator<'a> {
     type Item = Result<String, String>;
 
@@ -70,21 +112,15 @@ impl<'a> Iterator for CodeIterator<'a> {
             match self.data {
                 [b'%', b'#', directive, rest @ ..] => {
                     self.data = rest;
-                    Some(
-                        parse_directive(*directive)
-                            .map(|s| format!("eos::fmt::FormatSpec::new({}).with_no_padding()", s)),
-                    )
+                    Some(directive_code(*directive, ".with_no_padding()"))
                 }
                 [b'%', b'_', directive, rest @ ..] => {
                     self.data = rest;
-                    Some(
-                        parse_directive(*directive)
-                            .map(|s| format!("eos::fmt::FormatSpec::new({}).with_space_padding()", s)),
-                    )
+                    Some(directive_code(*directive, ".with_space_padding()"))
                 }
                 [b'%', directive, rest @ ..] => {
                     self.data = rest;
-                    Some(parse_directive(*directive).map(|s| format!("eos::fmt::FormatSpec::new({})", s)))
+                    Some(directive_code(*directive, ""))
                 }
                 [b'%'] => Some(Err("expected specifier after `%`".to_owned())),
                 _ => Some(Err("expected specifier".to_owned())),
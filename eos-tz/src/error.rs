@@ -14,6 +14,12 @@ pub enum ParseError {
     InvalidAbbreviation,
     /// The POSIX TZ string was invalid.
     InvalidPosixTz,
+    /// The POSIX TZ string had a DST start rule without a DST end rule,
+    /// e.g. `EST5EDT,M3.2.0/2`.
+    MissingDstEndRule,
+    /// The POSIX TZ string had DST start and end rules that transition at the same instant,
+    /// e.g. `EST5EDT,M3.2.0/2,M3.2.0/2`.
+    IdenticalDstRules,
 }
 
 /// The error type for most operations in the library.
@@ -41,6 +47,8 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidOffset => f.write_str("utcoffset is invalid"),
             ParseError::InvalidAbbreviation => f.write_str("abbreviation data was not UTF-8"),
             ParseError::InvalidPosixTz => f.write_str("POSIX TZ string is invalid"),
+            ParseError::MissingDstEndRule => f.write_str("POSIX TZ string is missing a DST end rule"),
+            ParseError::IdenticalDstRules => f.write_str("POSIX TZ string has identical DST start and end rules"),
         }
     }
 }
@@ -87,6 +95,8 @@ impl std::error::Error for ParseError {
             ParseError::InvalidOffset => None,
             ParseError::InvalidAbbreviation => None,
             ParseError::InvalidPosixTz => None,
+            ParseError::MissingDstEndRule => None,
+            ParseError::IdenticalDstRules => None,
         }
     }
 }
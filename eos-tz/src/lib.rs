@@ -42,6 +42,6 @@ pub use eos_tzdata;
 macro_rules! zone {
     ($zone_id:literal) => {{
         const DATA: &'static [u8] = $crate::eos_tzdata::tzif!($zone_id);
-        $crate::TimeZone::load(std::io::Cursor::new(DATA), std::string::String::from($zone_id)).unwrap()
+        $crate::TimeZone::load(std::io::Cursor::new(DATA), $zone_id).unwrap()
     }};
 }
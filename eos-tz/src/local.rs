@@ -26,14 +26,54 @@ use crate::sys::localtime::LocalTime;
 ///
 #[cfg(feature = "localtime")]
 #[derive(Clone, PartialEq, Eq)]
-pub struct Local(pub(crate) LocalTime);
+pub struct Local(pub(crate) LocalKind);
+
+#[cfg(feature = "localtime")]
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum LocalKind {
+    System(LocalTime),
+    Fallback(eos::UtcOffset),
+}
 
 #[cfg(feature = "localtime")]
 impl Local {
     /// Creates a new `Local`.
     #[inline]
     pub fn new() -> Result<Self, crate::Error> {
-        Ok(Self(crate::sys::localtime::LocalTime::new()?))
+        Ok(Self(LocalKind::System(LocalTime::new()?)))
+    }
+
+    /// Creates a new `Local` that falls back to a fixed offset if the local timezone
+    /// could not be detected.
+    ///
+    /// Unlike [`Local::new`] this never fails. When detection fails, every
+    /// [`eos::TimeZone`] operation acts as if it were the given [`eos::UtcOffset`].
+    ///
+    /// ```
+    /// use eos::{TimeZone, UtcOffset};
+    /// use eos_tz::Local;
+    ///
+    /// let tz = Local::with_fallback(UtcOffset::UTC);
+    /// let now = eos::DateTime::utc_now().in_timezone(tz);
+    /// ```
+    #[inline]
+    pub fn with_fallback(offset: eos::UtcOffset) -> Self {
+        Self::from_detection(LocalTime::new(), offset)
+    }
+
+    pub(crate) fn from_detection(detected: Result<LocalTime, crate::Error>, offset: eos::UtcOffset) -> Self {
+        match detected {
+            Ok(local) => Self(LocalKind::System(local)),
+            Err(_) => Self(LocalKind::Fallback(offset)),
+        }
+    }
+
+    /// Returns `true` if the local timezone could not be detected and
+    /// the fallback offset given in [`Local::with_fallback`] is used instead.
+    #[inline]
+    #[must_use]
+    pub fn is_fallback(&self) -> bool {
+        matches!(self.0, LocalKind::Fallback(_))
     }
 
     /// Returns the current date and time in the local timezone.
@@ -47,30 +87,90 @@ impl Local {
 #[cfg(feature = "localtime")]
 impl eos::TimeZone for Local {
     fn offset(&self, ts: eos::Timestamp) -> eos::UtcOffset {
-        self.0.as_inner().offset(ts)
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().offset(ts),
+            LocalKind::Fallback(offset) => *offset,
+        }
     }
 
-    fn resolve(self, date: eos::Date, time: eos::Time) -> eos::DateTimeResolution<Self>
+    fn resolve(&self, date: eos::Date, time: eos::Time) -> eos::DateTimeResolution<Self>
     where
         Self: Sized,
     {
-        let tz = self.0.as_inner().clone();
-        tz.resolve(date, time).with_timezone(self)
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().resolve(date, time).with_timezone(self.clone()),
+            LocalKind::Fallback(offset) => offset.resolve(date, time).with_timezone(self.clone()),
+        }
     }
 
-    fn convert_utc(self, utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
+    fn convert_utc(&self, utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
     where
         Self: Sized,
     {
-        let tz = self.0.as_inner().clone();
-        tz.convert_utc(utc).with_timezone(self)
+        let (date, time, offset) = match &self.0 {
+            LocalKind::System(local) => {
+                let dt = local.as_inner().convert_utc(utc);
+                (dt.date(), dt.time(), dt.offset())
+            }
+            LocalKind::Fallback(offset) => {
+                let dt = offset.convert_utc(utc);
+                (dt.date(), dt.time(), dt.offset())
+            }
+        };
+        eos::DateTimeResolution::unambiguous(date, time, offset, self.clone()).lenient()
     }
 
     fn name(&self, ts: eos::Timestamp) -> Option<&str> {
-        self.0.as_inner().name(ts)
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().name(ts),
+            LocalKind::Fallback(_) => None,
+        }
+    }
+
+    fn previous_transition(&self, ts: eos::Timestamp) -> Option<eos::Timestamp> {
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().previous_transition(ts),
+            LocalKind::Fallback(_) => None,
+        }
+    }
+
+    fn covers(&self, ts: eos::Timestamp) -> bool {
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().covers(ts),
+            LocalKind::Fallback(_) => true,
+        }
     }
 
     fn is_fixed(&self) -> bool {
-        self.0.as_inner().is_fixed()
+        match &self.0 {
+            LocalKind::System(local) => local.as_inner().is_fixed(),
+            LocalKind::Fallback(_) => true,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "localtime"))]
+mod tests {
+    use super::*;
+    use eos::{datetime, utc_offset, TimeZone};
+
+    #[test]
+    fn test_fallback() {
+        let offset = utc_offset!(+05:30);
+        let tz = Local::from_detection(Err(crate::Error::NoLocalTime), offset);
+        assert!(tz.is_fallback());
+        assert!(tz.is_fixed());
+        assert_eq!(tz.name(datetime!(2021-01-01 00:00).timestamp()), None);
+
+        let utc = datetime!(2021-03-14 12:00);
+        assert_eq!(tz.offset(utc.timestamp()), offset);
+        let local = utc.in_timezone(tz.clone());
+        assert_eq!(local.offset(), offset);
+        assert_eq!(local.hour(), 17);
+        assert_eq!(local.minute(), 30);
+
+        let resolved = tz.resolve(utc.date(), utc.time()).lenient();
+        assert_eq!(resolved.offset(), offset);
+        assert_eq!(resolved.in_timezone(eos::Utc), datetime!(2021-03-14 06:30));
     }
 }
@@ -205,10 +205,15 @@ impl PosixTimeZone {
                         None => return Err(ParseError::InvalidPosixTz),
                     };
                     let start = parse_dst_transition_rule(&mut parser)?;
-                    if parser.next_if_eq(&',').is_none() {
-                        return Err(ParseError::InvalidPosixTz);
+                    match parser.next() {
+                        Some(',') if parser.peek().is_some() => {}
+                        Some(',') | None => return Err(ParseError::MissingDstEndRule),
+                        Some(_) => return Err(ParseError::InvalidPosixTz),
                     }
                     let end = parse_dst_transition_rule(&mut parser)?;
+                    if start == end {
+                        return Err(ParseError::IdenticalDstRules);
+                    }
                     let base_offset = offset.saturating_sub(std_offset);
                     Some(DstTransitionInfo {
                         abbr,
@@ -235,32 +240,99 @@ impl PosixTimeZone {
         }
     }
 
-    pub(crate) fn shift_utc(&self, utc: &mut eos::DateTime<eos::Utc>) {
-        let ts = NaiveTimestamp::new(&utc.date(), &utc.time());
-        match self.dst.as_ref() {
-            None => {
-                utc.shift(self.std_offset);
-            }
+    /// Returns the UTC offset of this timezone at the given UNIX timestamp.
+    ///
+    /// This is the same as [`eos::TimeZone::offset`] without requiring the trait in scope.
+    pub fn offset_at(&self, ts: eos::Timestamp) -> UtcOffset {
+        match &self.dst {
             Some(dst) => {
-                let mut dst_on = dst.start.timestamp_in_year(utc.year());
-                let mut dst_off = dst.end.timestamp_in_year(utc.year());
-                dst_on.0 -= self.std_offset.total_seconds() as i64;
-                dst_off.0 -= dst.offset.total_seconds() as i64;
-
-                let is_dst = if dst_on < dst_off {
-                    dst_on <= ts && ts < dst_off
-                } else {
-                    !(dst_off <= ts && ts < dst_on)
-                };
-                if is_dst {
-                    utc.shift(dst.offset);
+                if dst.is_dst_utc(ts, &self.std_offset) {
+                    dst.offset
                 } else {
-                    utc.shift(self.std_offset);
+                    self.std_offset
                 }
             }
+            None => self.std_offset,
+        }
+    }
+
+    /// Returns the UTC offset, whether DST is in effect, and the abbreviation of this
+    /// timezone at the given UNIX timestamp.
+    ///
+    /// This is equivalent to calling [`PosixTimeZone::offset_at`], [`eos::TimeZone::name`], and
+    /// checking for DST separately except the DST rules are only computed once.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset};
+    /// use eos_tz::PosixTimeZone;
+    ///
+    /// let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+    /// let info = tz.transition_info(datetime!(2021-07-01 12:00).timestamp());
+    /// assert_eq!(info, (utc_offset!(-04:00), true, "EDT"));
+    /// # Ok::<_, eos_tz::ParseError>(())
+    /// ```
+    pub fn transition_info(&self, ts: eos::Timestamp) -> (UtcOffset, bool, &str) {
+        match &self.dst {
+            Some(dst) if dst.is_dst_utc(ts, &self.std_offset) => (dst.offset, true, dst.abbr.as_str()),
+            _ => (self.std_offset, false, self.std_abbr.as_str()),
         }
     }
 
+    /// Returns the instants DST starts and ends in the given year, in that order.
+    ///
+    /// If this timezone does not observe DST then [`None`] is returned.
+    ///
+    /// Note that in the southern hemisphere DST starts near the end of the year
+    /// and ends near the start of it, so the start is after the end.
+    ///
+    /// ```
+    /// use eos::datetime;
+    /// use eos_tz::PosixTimeZone;
+    ///
+    /// let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+    /// let (start, end) = tz.transitions_in_year(2021).unwrap();
+    /// assert_eq!(start, datetime!(2021-03-14 07:00));
+    /// assert_eq!(end, datetime!(2021-11-07 06:00));
+    /// # Ok::<_, eos_tz::ParseError>(())
+    /// ```
+    pub fn transitions_in_year(&self, year: i16) -> Option<(eos::DateTime<eos::Utc>, eos::DateTime<eos::Utc>)> {
+        let dst = self.dst.as_ref()?;
+        let start = dst.start.timestamp_in_year(year).to_regular(&self.std_offset);
+        let end = dst.end.timestamp_in_year(year).to_regular(&dst.offset);
+        Some((start.to_utc(), end.to_utc()))
+    }
+
+    /// Returns the DST transitions in the years surrounding the given UNIX timestamp.
+    ///
+    /// The transitions of the surrounding years are needed near the year boundaries.
+    /// If there is no DST then [`None`] is returned.
+    fn transitions_around(&self, ts: eos::Timestamp) -> Option<impl Iterator<Item = eos::Timestamp> + '_> {
+        let dst = self.dst.as_ref()?;
+        let year = ts.to_utc().year();
+        let transitions = [year.checked_sub(1), Some(year), year.checked_add(1)]
+            .into_iter()
+            .flatten()
+            .flat_map(move |year| {
+                [
+                    dst.start.timestamp_in_year(year).to_regular(&self.std_offset),
+                    dst.end.timestamp_in_year(year).to_regular(&dst.offset),
+                ]
+            });
+        Some(transitions)
+    }
+
+    /// Returns the most recent DST transition at or before the given UNIX timestamp.
+    pub(crate) fn previous_transition_at(&self, ts: eos::Timestamp) -> Option<eos::Timestamp> {
+        self.transitions_around(ts)?
+            .filter(|&transition| transition <= ts)
+            .max()
+    }
+
+    /// Returns the earliest DST transition strictly after the given UNIX timestamp.
+    pub(crate) fn next_transition_at(&self, ts: eos::Timestamp) -> Option<eos::Timestamp> {
+        self.transitions_around(ts)?.filter(|&transition| transition > ts).min()
+    }
+
     /// A "hack" to partially construct an eos::DateTimeResolution due to the lack of
     /// Copy semantics in this type and how it requires moving the TimeZone type.
     pub(crate) fn partial_resolution(
@@ -358,38 +430,38 @@ impl eos::TimeZone for PosixTimeZone {
     }
 
     fn offset(&self, ts: eos::Timestamp) -> UtcOffset {
-        match &self.dst {
-            Some(dst) => {
-                if dst.is_dst_utc(ts, &self.std_offset) {
-                    dst.offset
-                } else {
-                    self.std_offset
-                }
-            }
-            None => self.std_offset,
-        }
+        self.offset_at(ts)
     }
 
-    fn resolve(self, date: eos::Date, time: Time) -> eos::DateTimeResolution<Self>
+    fn previous_transition(&self, ts: eos::Timestamp) -> Option<eos::Timestamp> {
+        self.previous_transition_at(ts)
+    }
+
+    fn resolve(&self, date: eos::Date, time: Time) -> eos::DateTimeResolution<Self>
     where
         Self: Sized,
     {
         let (kind, earlier, later) = self.partial_resolution(&date, &time);
         match kind {
-            eos::DateTimeResolutionKind::Missing => eos::DateTimeResolution::missing(date, time, earlier, later, self),
-            eos::DateTimeResolutionKind::Unambiguous => eos::DateTimeResolution::unambiguous(date, time, earlier, self),
+            eos::DateTimeResolutionKind::Missing => {
+                eos::DateTimeResolution::missing(date, time, earlier, later, self.clone())
+            }
+            eos::DateTimeResolutionKind::Unambiguous => {
+                eos::DateTimeResolution::unambiguous(date, time, earlier, self.clone())
+            }
             eos::DateTimeResolutionKind::Ambiguous => {
-                eos::DateTimeResolution::ambiguous(date, time, earlier, later, self)
+                eos::DateTimeResolution::ambiguous(date, time, earlier, later, self.clone())
             }
         }
     }
 
-    fn convert_utc(self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
+    fn convert_utc(&self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
     where
         Self: Sized,
     {
-        self.shift_utc(&mut utc);
-        utc.with_timezone(self)
+        let offset = self.offset(utc.timestamp());
+        utc.shift(offset);
+        eos::DateTimeResolution::unambiguous(utc.date(), utc.time(), offset, self.clone()).lenient()
     }
 
     fn is_fixed(&self) -> bool {
@@ -687,7 +759,7 @@ impl std::fmt::Display for PosixTimeZone {
 
 #[cfg(test)]
 mod tests {
-    use eos::{datetime, ext::IntervalLiteral, DateTime, TimeZone, Utc};
+    use eos::{date, datetime, ext::IntervalLiteral, time, DateTime, TimeZone, Utc};
 
     use super::*;
 
@@ -756,7 +828,7 @@ mod tests {
         }
 
         let local = datetime!(2021-11-07 1:30 am);
-        let resolve = result.clone().resolve(local.date(), local.time());
+        let resolve = result.resolve(local.date(), local.time());
         assert!(resolve.is_ambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2021-11-07 1:30 am -04:00));
         assert_eq!(resolve.clone().later().unwrap(), datetime!(2021-11-07 1:30 am -05:00));
@@ -764,7 +836,7 @@ mod tests {
 
         // This is not ambiguous
         let unambiguous = datetime!(2021-11-07 12:30 am);
-        let resolve = result.clone().resolve(unambiguous.date(), unambiguous.time());
+        let resolve = result.resolve(unambiguous.date(), unambiguous.time());
         assert!(resolve.is_unambiguous());
         assert_eq!(
             resolve.clone().earlier().unwrap(),
@@ -781,6 +853,28 @@ mod tests {
         assert_eq!(resolve.lenient(), datetime!(2021-03-14 03:30 am -04:00));
     }
 
+    #[test]
+    fn test_from_local() {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2").unwrap();
+
+        // Regular unambiguous time in DST
+        let local = datetime!(2021-07-04 12:00);
+        let dt = DateTime::from_local(local.date(), local.time(), tz.clone());
+        assert_eq!(dt, datetime!(2021-07-04 12:00 -04:00));
+        assert_eq!(dt.tzname(), Some("EDT"));
+
+        // Ambiguous time picks the earlier time
+        let local = datetime!(2021-11-07 1:30 am);
+        let dt = DateTime::from_local(local.date(), local.time(), tz.clone());
+        assert_eq!(dt, datetime!(2021-11-07 1:30 am -04:00));
+        assert_eq!(dt.offset(), utc_offset!(-04:00));
+
+        // Missing time is shifted forward
+        let local = datetime!(2021-03-14 2:30 am);
+        let dt = DateTime::from_local(local.date(), local.time(), tz);
+        assert_eq!(dt, datetime!(2021-03-14 3:30 am -04:00));
+    }
+
     #[test]
     fn test_aest_posix() {
         let result = PosixTimeZone::from_str("AEST-10AEDT,M10.1.0/2,M4.1.0/3");
@@ -825,7 +919,7 @@ mod tests {
         }
 
         let local = datetime!(2022-04-03 2:30 am);
-        let resolve = result.clone().resolve(local.date(), local.time());
+        let resolve = result.resolve(local.date(), local.time());
         assert!(resolve.is_ambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2022-04-03 2:30 am +11:00));
         assert_eq!(resolve.clone().later().unwrap(), datetime!(2022-04-03 2:30 am +10:00));
@@ -833,7 +927,7 @@ mod tests {
 
         // This is not ambiguous
         let unambiguous = datetime!(2022-04-03 1:30 am);
-        let resolve = result.clone().resolve(unambiguous.date(), unambiguous.time());
+        let resolve = result.resolve(unambiguous.date(), unambiguous.time());
         assert!(resolve.is_unambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2022-04-03 1:30 am +11:00));
         assert_eq!(resolve.lenient(), datetime!(2022-04-03 1:30 am +11:00));
@@ -847,6 +941,109 @@ mod tests {
         assert_eq!(resolve.lenient(), datetime!(2021-10-03 03:30 am +11:00));
     }
 
+    #[test]
+    fn test_from_local_unambiguous() -> Result<(), ParseError> {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+
+        let dt = DateTime::from_local_unambiguous(date!(2021 - 11 - 07), time!(1:30), tz.clone());
+        assert_eq!(
+            dt,
+            Err(eos::Error::AmbiguousDateTime(date!(2021 - 11 - 07), time!(1:30)))
+        );
+
+        let dt = DateTime::from_local_unambiguous(date!(2021 - 03 - 14), time!(2:30), tz.clone());
+        assert_eq!(dt, Err(eos::Error::SkippedDateTime(date!(2021 - 03 - 14), time!(2:30))));
+
+        let dt = DateTime::from_local_unambiguous(date!(2021 - 11 - 07), time!(3:30), tz.clone()).unwrap();
+        assert_eq!(dt.offset(), utc_offset!(-05:00));
+        assert_eq!(dt.in_timezone(Utc), datetime!(2021-11-07 08:30));
+
+        let dt = DateTime::from_local_unambiguous(date!(2021 - 07 - 01), time!(1:30), tz).unwrap();
+        assert_eq!(dt.offset(), utc_offset!(-04:00));
+        assert_eq!(dt.in_timezone(Utc), datetime!(2021-07-01 05:30));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timezone_ref() -> Result<(), ParseError> {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+
+        let winter = datetime!(2021-01-01 12:00).in_timezone_ref(&tz);
+        assert_eq!(winter.offset(), utc_offset!(-05:00));
+        assert_eq!(winter, datetime!(2021-01-01 07:00 -05:00));
+
+        let summer = datetime!(2021-07-01 12:00).in_timezone_ref(&tz);
+        assert_eq!(summer.offset(), utc_offset!(-04:00));
+        assert_eq!(summer, datetime!(2021-07-01 08:00 -04:00));
+
+        let local = datetime!(2021-07-01 12:00).with_timezone_ref(&tz);
+        assert_eq!(local.offset(), utc_offset!(-04:00));
+        assert_eq!(local, datetime!(2021-07-01 12:00 -04:00));
+
+        // The timezone is still usable afterwards
+        assert_eq!(tz, PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_previous_transition() -> Result<(), ParseError> {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+        let dt = datetime!(2021-06-01 12:00).in_timezone(tz.clone());
+        assert_eq!(dt.tzname(), Some("EDT"));
+        assert_eq!(dt.current_offset_since(), Some(datetime!(2021-03-14 07:00).timestamp()));
+
+        let dt = datetime!(2021-03-14 07:00).in_timezone(tz.clone());
+        assert_eq!(dt.current_offset_since(), Some(dt.timestamp()));
+        let dt = datetime!(2021-03-14 06:59:59).in_timezone(tz.clone());
+        assert_eq!(dt.current_offset_since(), Some(datetime!(2020-11-01 06:00).timestamp()));
+
+        // Crossing the year boundary
+        let dt = datetime!(2022-01-15 12:00).in_timezone(tz);
+        assert_eq!(dt.current_offset_since(), Some(datetime!(2021-11-07 06:00).timestamp()));
+
+        let tz = PosixTimeZone::new("AEST-10AEDT,M10.1.0/2,M4.1.0/3")?;
+        let dt = datetime!(2022-01-15 12:00).in_timezone(tz);
+        assert_eq!(dt.current_offset_since(), Some(datetime!(2021-10-02 16:00).timestamp()));
+
+        let tz = PosixTimeZone::new("UTC")?;
+        assert_eq!(datetime!(2021-06-01 12:00).in_timezone(tz).current_offset_since(), None);
+        assert_eq!(datetime!(2021-06-01 12:00).current_offset_since(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transitions_in_year() -> Result<(), ParseError> {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
+        assert_eq!(
+            tz.transitions_in_year(2021),
+            Some((datetime!(2021-03-14 07:00), datetime!(2021-11-07 06:00)))
+        );
+        assert_eq!(
+            tz.transitions_in_year(2022),
+            Some((datetime!(2022-03-13 07:00), datetime!(2022-11-06 06:00)))
+        );
+
+        let (start, end) = tz.transitions_in_year(2021).unwrap();
+        assert_eq!(tz.offset_at((start - 1.seconds()).timestamp()), utc_offset!(-05:00));
+        assert_eq!(tz.offset_at(start.timestamp()), utc_offset!(-04:00));
+        assert_eq!(tz.offset_at((end - 1.seconds()).timestamp()), utc_offset!(-04:00));
+        assert_eq!(tz.offset_at(end.timestamp()), utc_offset!(-05:00));
+
+        // Southern hemisphere transitions start after they end
+        let tz = PosixTimeZone::new("AEST-10AEDT,M10.1.0/2,M4.1.0/3")?;
+        let (start, end) = tz.transitions_in_year(2021).unwrap();
+        assert_eq!(start, datetime!(2021-10-02 16:00));
+        assert_eq!(end, datetime!(2021-04-03 16:00));
+        assert!(end < start);
+        assert_eq!(tz.offset_at(start.timestamp()), utc_offset!(11:00));
+        assert_eq!(tz.offset_at(end.timestamp()), utc_offset!(10:00));
+
+        let tz = PosixTimeZone::new("UTC")?;
+        assert_eq!(tz.transitions_in_year(2021), None);
+        assert_eq!(tz.offset_at(datetime!(2021-06-01 00:00).timestamp()), UtcOffset::UTC);
+        Ok(())
+    }
+
     #[test]
     fn test_america_santiago() {
         let result = PosixTimeZone::from_str("<-04>4<-03>,M9.1.6/24,M4.1.6/24");
@@ -899,7 +1096,7 @@ mod tests {
         // This means the ambiguous times are 2022-04-02 23:00:00 to 2022-04-03 00:00:00
 
         let local = datetime!(2022-04-02 23:30:00);
-        let resolve = result.clone().resolve(local.date(), local.time());
+        let resolve = result.resolve(local.date(), local.time());
         assert!(resolve.is_ambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2022-04-02 23:30 -03:00));
         assert_eq!(resolve.clone().later().unwrap(), datetime!(2022-04-02 23:30 -04:00));
@@ -907,7 +1104,7 @@ mod tests {
 
         // This is not ambiguous
         let unambiguous = datetime!(2022-04-02 22:59:59);
-        let resolve = result.clone().resolve(unambiguous.date(), unambiguous.time());
+        let resolve = result.resolve(unambiguous.date(), unambiguous.time());
         assert!(resolve.is_unambiguous());
         assert_eq!(
             resolve.clone().earlier().unwrap(),
@@ -978,7 +1175,7 @@ mod tests {
         // The gap skip would mean that'd forward an hour so 2022-03-27 2:30 AM UTC+1
 
         let local = datetime!(2021-10-31 01:30:00);
-        let resolve = result.clone().resolve(local.date(), local.time());
+        let resolve = result.resolve(local.date(), local.time());
         assert!(resolve.is_ambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2021-10-31 01:30 +01:00));
         assert_eq!(resolve.clone().later().unwrap(), datetime!(2021-10-31 01:30 +00:00));
@@ -986,7 +1183,7 @@ mod tests {
 
         // This is not ambiguous
         let unambiguous = datetime!(2022-03-27 00:00);
-        let resolve = result.clone().resolve(unambiguous.date(), unambiguous.time());
+        let resolve = result.resolve(unambiguous.date(), unambiguous.time());
         assert!(resolve.is_unambiguous());
         assert_eq!(resolve.clone().earlier().unwrap(), datetime!(2022-03-27 00:00 +00:00));
         assert_eq!(resolve.lenient(), datetime!(2022-03-27 00:00 +00:00));
@@ -1012,7 +1209,7 @@ mod tests {
 
         let utc = Utc::now();
         // Unfortunately PosixTimeZone is *not* Copy which makes it awkward...
-        let current = tz.clone().convert_utc(utc);
+        let current = tz.convert_utc(utc);
         assert_eq!(utc, current);
 
         /*
@@ -1028,17 +1225,25 @@ mod tests {
             if hour == 23 {
                 expected = expected - 1.days();
             }
-            let got = tz.clone().convert_utc(start);
+            let got = tz.convert_utc(start);
             assert_eq!(expected.with_timezone(tz.clone()), got);
 
             start = start + 1.hours();
         }
 
         let mut start = DST_END_2021.with_hour(4).unwrap();
-        for hour in [0, 1, 1, 2, 3, 4] {
+        // The second 1:00 is the later occurrence of the ambiguous time, in EST
+        for (hour, later) in [(0, false), (1, false), (1, true), (2, false), (3, false), (4, false)] {
             let expected = start.with_hour(hour).unwrap();
-            let got = tz.clone().convert_utc(start);
-            assert_eq!(expected.with_timezone(tz.clone()), got);
+            let resolution = tz.resolve(expected.date(), expected.time());
+            let expected = if later {
+                resolution.later().unwrap()
+            } else {
+                resolution.lenient()
+            };
+            let got = tz.convert_utc(start);
+            assert_eq!(expected, got);
+            assert_eq!(expected.offset(), got.offset());
 
             start = start + 1.hours();
         }
@@ -1055,6 +1260,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_invalid_dst_rules() {
+        for tz in [
+            "EST5EDT,M3.2.0/2",
+            "EST5EDT,M3.2.0/2,",
+            "EST5EDT4,M3.2.0",
+            "<-03>3<-02>,J60",
+        ] {
+            let result = PosixTimeZone::new(tz);
+            assert!(
+                matches!(result, Err(ParseError::MissingDstEndRule)),
+                "{}: {:?}",
+                tz,
+                result
+            );
+        }
+
+        for tz in [
+            "EST5EDT,M3.2.0/2,M3.2.0/2",
+            "EST5EDT,M3.2.0,M3.2.0/2",
+            "EST5EDT,J60/1,J60/1:00",
+        ] {
+            let result = PosixTimeZone::new(tz);
+            assert!(
+                matches!(result, Err(ParseError::IdenticalDstRules)),
+                "{}: {:?}",
+                tz,
+                result
+            );
+        }
+
+        for tz in ["EST5EDT", "EST5EDT,M3.2.0/2;M11.1.0/2", "EST5EDT,M3.2.0/2,M13.1.0/2"] {
+            let result = PosixTimeZone::new(tz);
+            assert!(
+                matches!(result, Err(ParseError::InvalidPosixTz)),
+                "{}: {:?}",
+                tz,
+                result
+            );
+        }
+
+        // Rules that only differ in time are fine, e.g. permanent DST
+        assert!(PosixTimeZone::new("EST5EDT,J1/0,J365/25").is_ok());
+        assert!(PosixTimeZone::new("EST5EDT,M3.2.0/2,M3.2.0/3").is_ok());
+    }
+
     #[test]
     fn test_display_repr() -> Result<(), ParseError> {
         let posix = PosixTimeZone::new("GMT5")?;
@@ -1065,4 +1316,33 @@ mod tests {
         assert_eq!(posix.to_string(), "EST5EDT,M3.2.0/2,M11.1.0/2");
         Ok(())
     }
+
+    #[test]
+    fn test_transition_info() {
+        let tz = PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2").unwrap();
+        let est = (utc_offset!(-05:00), false, "EST");
+        let edt = (utc_offset!(-04:00), true, "EDT");
+
+        // DST starts on 2021-03-14 at 2AM local time, i.e. 7AM UTC
+        let start = datetime!(2021-03-14 07:00).timestamp();
+        let before = eos::Timestamp::from_seconds(start.as_seconds() - 1);
+        assert_eq!(tz.transition_info(before), est);
+        assert_eq!(tz.transition_info(start), edt);
+
+        // DST ends on 2021-11-07 at 2AM local time, i.e. 6AM UTC
+        let end = datetime!(2021-11-07 06:00).timestamp();
+        let before = eos::Timestamp::from_seconds(end.as_seconds() - 1);
+        assert_eq!(tz.transition_info(before), edt);
+        assert_eq!(tz.transition_info(end), est);
+
+        for ts in [before, start, end] {
+            let (offset, is_dst, abbr) = tz.transition_info(ts);
+            assert_eq!(offset, tz.offset(ts));
+            assert_eq!(Some(abbr), tz.name(ts));
+            assert_eq!(is_dst, offset != tz.std_offset);
+        }
+
+        let utc = PosixTimeZone::new("UTC0").unwrap();
+        assert_eq!(utc.transition_info(start), (utc_offset!(00:00), false, "UTC"));
+    }
 }
@@ -1,5 +1,7 @@
 use std::{
+    borrow::Cow,
     io::{Read, Seek},
+    path::Path,
     sync::Arc,
 };
 
@@ -13,7 +15,7 @@ use crate::{
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct TimeZoneData {
-    id: String,
+    id: Cow<'static, str>,
     transitions: Vec<Transition>,
     ttypes: Vec<TransitionType>,
     posix: Option<PosixTimeZone>,
@@ -27,13 +29,25 @@ struct TimeZoneData {
 pub struct TimeZone(Arc<TimeZoneData>);
 
 #[cfg(target_family = "unix")]
-const TZ_SEARCH_PATHS: [&'static str; 4] = [
+const TZ_SEARCH_PATHS: [&str; 4] = [
     "/usr/share/zoneinfo",
     "/usr/lib/zoneinfo",
     "/usr/share/lib/zoneinfo",
     "/etc/zoneinfo",
 ];
 
+/// Returns the directories that are searched for TZif files in order.
+///
+/// The `TZDIR` environment variable takes priority over the default paths.
+#[cfg(target_family = "unix")]
+fn tz_search_paths() -> impl Iterator<Item = std::path::PathBuf> {
+    let tzdir = std::env::var_os("TZDIR").filter(|dir| !dir.is_empty());
+    tzdir
+        .map(std::path::PathBuf::from)
+        .into_iter()
+        .chain(TZ_SEARCH_PATHS.iter().map(std::path::PathBuf::from))
+}
+
 #[cfg(target_family = "unix")]
 #[inline]
 fn is_valid_path<P: AsRef<std::path::Path>>(path: P) -> bool {
@@ -44,6 +58,24 @@ fn is_valid_path<P: AsRef<std::path::Path>>(path: P) -> bool {
         .all(|x| matches!(x, std::path::Component::Normal(_)))
 }
 
+/// Derives a zone identifier from a TZif file path, e.g. `/usr/share/zoneinfo/Asia/Tokyo`
+/// becomes `Asia/Tokyo`. Paths outside the zoneinfo directories are used as-is.
+fn zone_id_from_path(path: &Path) -> String {
+    #[cfg(target_family = "unix")]
+    {
+        let actual_path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        for p in tz_search_paths() {
+            for candidate in [path, actual_path.as_path()] {
+                if let Some(zone_id) = candidate.strip_prefix(&p).ok().and_then(Path::to_str) {
+                    return zone_id.to_owned();
+                }
+            }
+        }
+    }
+
+    path.to_string_lossy().into_owned()
+}
+
 // Make this an internal macro to always inline the function call
 #[cfg(all(not(feature = "bundled"), target_family = "windows"))]
 macro_rules! __get_impl {
@@ -82,7 +114,9 @@ impl TimeZone {
     /// If a parser error happens then [`ParseError`] is returned.
     ///
     /// Note that the time zone identifier *must* be valid, for example `America/New_York`.
-    pub fn load<R: Read + Seek>(reader: R, id: String) -> Result<Self, ParseError> {
+    /// The identifier can be either an owned [`String`] or a `&'static str`, the latter
+    /// of which is stored without allocating.
+    pub fn load<R: Read + Seek>(reader: R, id: impl Into<Cow<'static, str>>) -> Result<Self, ParseError> {
         let (transitions, ttypes, posix) = parse_tzif(reader)?;
         // A fixed transition is one that has no transition information at all.
         // There are a few assumptions here:
@@ -96,7 +130,7 @@ impl TimeZone {
         // If a type doesn't have a POSIX transition but has 1 transition in the file then it's still fixed.
         let fixed = transitions.len() <= 1 && posix.as_ref().map(eos::TimeZone::is_fixed).unwrap_or(true);
         let data = TimeZoneData {
-            id,
+            id: id.into(),
             transitions,
             ttypes,
             posix,
@@ -105,13 +139,39 @@ impl TimeZone {
         Ok(Self(Arc::new(data)))
     }
 
+    /// Loads a `TimeZone` from the bytes of a TZif file and the given Zone identifier.
+    ///
+    /// This is useful for embedding TZif files into the binary, e.g. with [`include_bytes`].
+    ///
+    /// If a parser error happens then [`ParseError`] is returned.
+    pub fn from_bytes(bytes: &[u8], id: impl Into<Cow<'static, str>>) -> Result<Self, ParseError> {
+        Self::load(std::io::Cursor::new(bytes), id)
+    }
+
+    /// Loads a `TimeZone` from the TZif file at the given path.
+    ///
+    /// The Zone identifier is derived from the path relative to the system provided
+    /// timezone database, e.g. `/usr/share/zoneinfo/Asia/Tokyo` has an identifier of
+    /// `Asia/Tokyo`. See [`TimeZone::locate`] for the directories that are checked.
+    /// If the path is not within one of those directories then the path itself is
+    /// used as the identifier.
+    ///
+    /// If the file could not be found then [`Error::NotFound`] is returned. Other
+    /// I/O errors are returned as [`ParseError::Io`].
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let buf = std::io::BufReader::new(file);
+        Ok(Self::load(buf, zone_id_from_path(path))?)
+    }
+
     /// Loads a `TimeZone` from the internal bundled copy of the TZif files.
     ///
     /// Unlike the [`zone`] macro, this allows querying with a runtime string.
     #[cfg(feature = "bundled")]
     pub fn bundled(zone: &str) -> Result<Self, Error> {
         match eos_tzdata::locate_tzif(zone) {
-            Some(bytes) => Ok(Self::load(std::io::Cursor::new(bytes), zone.to_owned())?),
+            Some(bytes) => Ok(Self::from_bytes(bytes, zone.to_owned())?),
             None => Err(Error::NotFound),
         }
     }
@@ -122,12 +182,17 @@ impl TimeZone {
     /// file that's either bundled or from the system, consider using
     /// [`TimeZone::get`] for cross-platform code.
     ///
-    /// If the timezone could not be located, [`Error`] is returned.
+    /// # Errors
+    ///
+    /// - [`Error::InvalidZonePath`] if the zone is not a plain relative path, e.g. `America/../UTC`.
+    /// - [`Error::NotFound`] if none of the directories contain a file for the zone.
+    /// - [`Error::Parse`] if the first file found is not a valid TZif file.
     ///
     /// # OS-specific behavior
     ///
     /// This searches through the following paths in order until it finds a match:
     ///
+    /// - The directory in the `TZDIR` environment variable, if set
     /// - `/usr/share/zoneinfo`
     /// - `/usr/lib/zoneinfo`
     /// - `/usr/share/lib/zoneinfo`
@@ -141,8 +206,7 @@ impl TimeZone {
             return Err(Error::InvalidZonePath);
         }
 
-        for p in TZ_SEARCH_PATHS {
-            let mut path = std::path::PathBuf::from(p);
+        for mut path in tz_search_paths() {
             path.push(zone);
             match std::fs::File::open(path) {
                 Ok(file) => {
@@ -160,8 +224,8 @@ impl TimeZone {
     #[cfg(feature = "localtime")]
     pub(crate) fn etc_localtime() -> Result<Self, Error> {
         let actual_path = std::fs::canonicalize("/etc/localtime").map_err(|_| Error::InvalidZonePath)?;
-        for p in TZ_SEARCH_PATHS {
-            if let Ok(suffix) = actual_path.strip_prefix(p) {
+        for p in tz_search_paths() {
+            if let Ok(suffix) = actual_path.strip_prefix(&p) {
                 if let Some(zone_id) = suffix.to_str() {
                     let file = std::fs::File::open(&actual_path).map_err(|_| Error::InvalidZonePath)?;
                     let buf = std::io::BufReader::new(file);
@@ -205,7 +269,7 @@ impl TimeZone {
 
         let posix = PosixTimeZone::new(tz)?;
         let inner = TimeZoneData {
-            id: tz.to_owned(),
+            id: Cow::Owned(tz.to_owned()),
             transitions: Vec::new(),
             ttypes: Vec::new(),
             fixed: <PosixTimeZone as eos::TimeZone>::is_fixed(&posix),
@@ -222,6 +286,10 @@ impl TimeZone {
     /// while using the system provided time on Linux using a single
     /// constructor for ease of cross-platform use.
     ///
+    /// Without the `bundled` feature this is equivalent to [`TimeZone::locate`],
+    /// which respects the `TZDIR` environment variable. Windows does not provide
+    /// a TZif database so the `bundled` feature is required there.
+    ///
     /// If the timezone could not be located, [`Error`] is returned.
     ///
     /// # See also
@@ -233,7 +301,63 @@ impl TimeZone {
 
     /// Returns the identifier name.
     pub fn id(&self) -> &str {
-        self.0.id.as_str()
+        &self.0.id
+    }
+
+    /// Returns the most recent transition at or before the given UNIX timestamp,
+    /// along with the UTC offsets in effect before and after it.
+    ///
+    /// This is mainly useful to enumerate the local times around a transition, e.g.
+    /// the missing or ambiguous local times are between the transition shifted by the
+    /// earlier offset and the transition shifted by the later offset.
+    ///
+    /// If there is no such transition, such as with fixed offset timezones, then
+    /// [`None`] is returned.
+    pub fn transition_window(
+        &self,
+        around: eos::Timestamp,
+    ) -> Option<(eos::Timestamp, eos::UtcOffset, eos::UtcOffset)> {
+        use eos::TimeZone;
+
+        let transition = self.previous_transition(around)?;
+        let before = self.offset(eos::Timestamp::from_seconds(transition.as_seconds() - 1));
+        let after = self.offset(transition);
+        Some((transition, before, after))
+    }
+
+    /// Returns the UNIX timestamp of the earliest transition strictly after the given one.
+    ///
+    /// The explicit transitions in the TZif data are checked first, followed by the
+    /// trailing POSIX TZ string for timestamps past the last explicit transition.
+    ///
+    /// If there is no such transition, such as with fixed offset timezones, then
+    /// [`None`] is returned.
+    pub fn next_transition(&self, after: eos::Timestamp) -> Option<eos::Timestamp> {
+        let ts = NaiveTimestamp::from(after);
+        // The first transition is the one extended to the beginning of time
+        let idx = self.0.transitions.partition_point(|t| t.utc_start <= ts).max(1);
+        match self.0.transitions.get(idx) {
+            Some(trans) => Some(eos::Timestamp::from_seconds(trans.utc_start.into_inner())),
+            None => self.0.posix.as_ref().and_then(|posix| posix.next_transition_at(after)),
+        }
+    }
+
+    /// Returns the UNIX timestamp of the latest transition strictly before the given one.
+    ///
+    /// Unlike [`eos::TimeZone::previous_transition`], a transition that happens exactly
+    /// at the given timestamp is skipped. This allows walking backwards through transitions
+    /// by repeatedly calling this method.
+    ///
+    /// If there is no such transition, such as with fixed offset timezones, then
+    /// [`None`] is returned.
+    pub fn prev_transition(&self, before: eos::Timestamp) -> Option<eos::Timestamp> {
+        use eos::TimeZone;
+
+        match self.previous_transition(before)? {
+            transition if transition < before => Some(transition),
+            // Transitions happen on whole seconds so the previous second is strictly before it
+            _ => self.previous_transition(eos::Timestamp::from_seconds(before.as_seconds().saturating_sub(1))),
+        }
     }
 
     pub(crate) fn get_transition(&self, ts: NaiveTimestamp) -> Option<&Transition> {
@@ -282,27 +406,19 @@ impl eos::TimeZone for TimeZone {
         }
     }
 
-    fn convert_utc(self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
+    fn convert_utc(&self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
     where
         Self: Sized,
     {
-        let ts = utc.timestamp();
-        match self.get_transition(ts.into()) {
-            None => match &self.0.posix {
-                None => utc.with_timezone(self.clone()),
-                Some(posix) => {
-                    posix.shift_utc(&mut utc);
-                    utc.with_timezone(self.clone())
-                }
-            },
-            Some(trans) => {
-                utc.shift(trans.offset);
-                utc.with_timezone(self.clone())
-            }
-        }
+        // The local time is built from the offset directly rather than going through
+        // `with_timezone`, since resolving it again would lose which side of an
+        // ambiguous transition the UTC time was on.
+        let offset = self.offset(utc.timestamp());
+        utc.shift(offset);
+        eos::DateTimeResolution::unambiguous(utc.date(), utc.time(), offset, self.clone()).lenient()
     }
 
-    fn resolve(self, date: eos::Date, time: eos::Time) -> eos::DateTimeResolution<Self>
+    fn resolve(&self, date: eos::Date, time: eos::Time) -> eos::DateTimeResolution<Self>
     where
         Self: Sized,
     {
@@ -400,6 +516,52 @@ impl eos::TimeZone for TimeZone {
         eos::DateTimeResolution::unambiguous(date, time, trans.offset, self.clone())
     }
 
+    fn previous_transition(&self, ts: eos::Timestamp) -> Option<eos::Timestamp> {
+        let explicit = match self
+            .0
+            .transitions
+            .binary_search_by_key(&NaiveTimestamp::from(ts), |t| t.utc_start)
+        {
+            Ok(idx) => idx,
+            Err(idx) if idx != self.0.transitions.len() => idx - 1,
+            Err(_) => {
+                // Past the explicit transitions the POSIX TZ string takes over,
+                // though its rules might only apply after the last explicit transition
+                let last = match self.0.transitions.as_slice() {
+                    [_, .., last] => Some(eos::Timestamp::from_seconds(last.utc_start.into_inner())),
+                    _ => None,
+                };
+                let posix = self.0.posix.as_ref().and_then(|posix| posix.previous_transition_at(ts));
+                return posix.max(last);
+            }
+        };
+
+        // The first transition is the one extended to the beginning of time
+        if explicit == 0 {
+            None
+        } else {
+            Some(eos::Timestamp::from_seconds(
+                self.0.transitions[explicit].utc_start.into_inner(),
+            ))
+        }
+    }
+
+    /// Returns `true` if the timestamp falls within the explicit transition data
+    /// or the range described by the trailing POSIX TZ string.
+    ///
+    /// Timestamps before the first transition use the earliest offset extended
+    /// to the beginning of time, while timestamps after the last transition
+    /// without a POSIX TZ string reuse the last offset. Neither of these are covered.
+    fn covers(&self, ts: eos::Timestamp) -> bool {
+        let ts = NaiveTimestamp::from(ts);
+        // The first transition is the one extended to the beginning of time
+        match self.0.transitions.binary_search_by_key(&ts, |trans| trans.utc_start) {
+            Ok(idx) => idx != 0,
+            Err(idx) if idx != self.0.transitions.len() => idx > 1,
+            Err(_) => self.0.posix.is_some(),
+        }
+    }
+
     fn is_fixed(&self) -> bool {
         self.0.fixed
     }
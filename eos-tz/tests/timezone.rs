@@ -27,8 +27,7 @@ const TEST_DATA: [(&str, &[u8]); 12] = [
 fn get_zone(key: &str) -> eos_tz::TimeZone {
     let idx = TEST_DATA.binary_search_by_key(&key, |x| x.0).expect("not found");
     let (_, bytes) = TEST_DATA.get(idx).expect("out of bounds");
-    let cursor = std::io::Cursor::new(bytes);
-    eos_tz::TimeZone::load(cursor, key.to_owned()).expect("parsing failed")
+    eos_tz::TimeZone::from_bytes(bytes, key.to_owned()).expect("parsing failed")
 }
 
 macro_rules! trace_variables {
@@ -477,7 +476,7 @@ fn test_ambiguous_times() {
             // Before the fold is unambiguous
             let dt = transition.anomaly_start() - 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_before.offset);
@@ -487,7 +486,7 @@ fn test_ambiguous_times() {
             // At the fold is ambiguous
             let dt = transition.anomaly_start();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_ambiguous());
                 let (before, after) = resolve.into_pair();
                 assert_eq!(before.offset(), transition.offset_before.offset);
@@ -499,7 +498,7 @@ fn test_ambiguous_times() {
             // During the fold is ambiguous
             let dt = transition.anomaly_start() + 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_ambiguous());
                 let (before, after) = resolve.into_pair();
                 assert_eq!(before.offset(), transition.offset_before.offset);
@@ -511,7 +510,7 @@ fn test_ambiguous_times() {
             // Before the fold ends is ambiguous
             let dt = transition.anomaly_end() - 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_ambiguous());
                 let (before, after) = resolve.into_pair();
                 assert_eq!(before.offset(), transition.offset_before.offset);
@@ -523,7 +522,7 @@ fn test_ambiguous_times() {
             // When the fold ends it's unambiguous
             let dt = transition.anomaly_end();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_after.offset);
@@ -533,7 +532,7 @@ fn test_ambiguous_times() {
             // After the fold ends it's still unambiguous
             let dt = transition.anomaly_end() + 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_after.offset);
@@ -555,7 +554,7 @@ fn test_missing_times() {
             // Before the gap is unambiguous
             let dt = transition.anomaly_start() - 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_before.offset);
@@ -565,7 +564,7 @@ fn test_missing_times() {
             // At the gap is missing
             let dt = transition.anomaly_start();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_missing());
                 let after = resolve.lenient();
                 assert_eq!(after.offset(), transition.offset_after.offset);
@@ -575,7 +574,7 @@ fn test_missing_times() {
             // During the gap is missing
             let dt = transition.anomaly_start() + 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_missing());
                 let after = resolve.lenient();
                 assert_eq!(after.offset(), transition.offset_after.offset);
@@ -585,7 +584,7 @@ fn test_missing_times() {
             // Before the gap ends is missing
             let dt = transition.anomaly_end() - 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_missing());
                 let after = resolve.lenient();
                 assert_eq!(after.offset(), transition.offset_after.offset);
@@ -595,7 +594,7 @@ fn test_missing_times() {
             // When the gap ends it's unambiguous
             let dt = transition.anomaly_end();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_after.offset);
@@ -605,7 +604,7 @@ fn test_missing_times() {
             // After the gap ends it's still unambiguous
             let dt = transition.anomaly_end() + 1.seconds();
             trace_variables!(key, dt, transition, {
-                let resolve = zone.clone().resolve(dt.date(), dt.time());
+                let resolve = zone.resolve(dt.date(), dt.time());
                 assert!(resolve.is_unambiguous());
                 let resolved = resolve.earlier().unwrap();
                 assert_eq!(resolved.offset(), transition.offset_after.offset);
@@ -640,7 +639,7 @@ fn test_europe_prague_ambiguity() {
 
     // Ambiguous
     let local = datetime!(1946-12-01 2:30);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_ambiguous());
     assert_eq!(resolve.clone().earlier().unwrap(), datetime!(1946-12-01 2:30 +01:00));
     assert_eq!(resolve.clone().later().unwrap(), datetime!(1946-12-01 2:30 +00:00));
@@ -648,7 +647,7 @@ fn test_europe_prague_ambiguity() {
 
     // Ambiguous again
     let local = datetime!(1946-10-06 2:30);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_ambiguous());
     assert_eq!(resolve.clone().earlier().unwrap(), datetime!(1946-10-06 2:30 +02:00));
     assert_eq!(resolve.clone().later().unwrap(), datetime!(1946-10-06 2:30 +01:00));
@@ -656,14 +655,14 @@ fn test_europe_prague_ambiguity() {
 
     // Missing
     let local = datetime!(1947-02-23 2:30);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_missing());
     assert!(resolve.clone().earlier().is_err());
     assert!(resolve.clone().later().is_err());
     assert_eq!(resolve.lenient(), datetime!(1947-02-23 3:30 am +01:00));
 
     let local = datetime!(1946-05-06 2:30);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_missing());
     assert!(resolve.clone().earlier().is_err());
     assert!(resolve.clone().later().is_err());
@@ -695,7 +694,7 @@ fn test_america_los_angeles_historical() {
 
     // Ambiguous
     let local = datetime!(1991-10-27 1:30 am);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_ambiguous());
     assert_eq!(resolve.clone().earlier().unwrap(), datetime!(1991-10-27 1:30 am -07:00));
     assert_eq!(resolve.clone().later().unwrap(), datetime!(1991-10-27 1:30 am -08:00));
@@ -703,7 +702,7 @@ fn test_america_los_angeles_historical() {
 
     // This is not ambiguous
     let local = datetime!(1991-10-27 12:30 am);
-    let resolve = zone.clone().resolve(local.date(), local.time());
+    let resolve = zone.resolve(local.date(), local.time());
     assert!(resolve.is_unambiguous());
     assert_eq!(
         resolve.clone().earlier().unwrap(),
@@ -719,3 +718,306 @@ fn test_america_los_angeles_historical() {
     assert!(resolve.clone().later().is_err());
     assert_eq!(resolve.lenient(), datetime!(1991-04-07 03:30 am -07:00));
 }
+
+#[test]
+fn test_covers() {
+    let zone = get_zone("America/Los_Angeles");
+
+    // Before the first transition, LMT is extrapolated to the beginning of time
+    assert!(!zone.covers(datetime!(1800-01-01 00:00).timestamp()));
+    assert!(!zone.covers(datetime!(1883-11-18 19:59).timestamp()));
+    assert!(zone.covers(datetime!(1883-11-18 20:00).timestamp()));
+    assert!(zone.covers(datetime!(1991-10-27 12:30).timestamp()));
+
+    // After the last transition, the POSIX TZ string takes over
+    assert!(zone.covers(datetime!(2400-01-01 00:00).timestamp()));
+    assert!(zone.covers(datetime!(9999-12-31 23:59).timestamp()));
+
+    // Zones without transitions are entirely described by their POSIX TZ string
+    let utc = zone!("UTC");
+    assert!(utc.covers(datetime!(1800-01-01 00:00).timestamp()));
+    assert!(utc.covers(datetime!(2400-01-01 00:00).timestamp()));
+
+    // Timezones without limited data always cover every timestamp
+    assert!(eos::UtcOffset::UTC.covers(datetime!(1800-01-01 00:00).timestamp()));
+}
+
+#[test]
+fn test_from_path() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/Asia/Tokyo");
+    let zone = eos_tz::TimeZone::from_path(path).expect("parsing failed");
+    assert_eq!(zone.id(), path);
+    assert_eq!(
+        zone.offset(datetime!(2022-01-29 10:30).timestamp()),
+        utc_offset!(+09:00)
+    );
+
+    #[cfg(target_family = "unix")]
+    if std::path::Path::new("/usr/share/zoneinfo/Asia/Tokyo").exists() {
+        let zone = eos_tz::TimeZone::from_path("/usr/share/zoneinfo/Asia/Tokyo").expect("parsing failed");
+        assert_eq!(zone.id(), "Asia/Tokyo");
+    }
+
+    let missing = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/Asia/Nowhere");
+    assert!(matches!(
+        eos_tz::TimeZone::from_path(missing),
+        Err(eos_tz::Error::NotFound)
+    ));
+
+    let not_tzif = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/timezone.rs");
+    assert!(matches!(
+        eos_tz::TimeZone::from_path(not_tzif),
+        Err(eos_tz::Error::Parse(eos_tz::ParseError::InvalidMagic))
+    ));
+}
+
+#[test]
+fn test_current_offset_since() {
+    let zone = get_zone("America/Los_Angeles");
+
+    // PDT started on 2021-03-14 at 2:00 AM PST
+    let dt = datetime!(2021-07-04 12:00).in_timezone(zone.clone());
+    assert_eq!(dt.tzname(), Some("PDT"));
+    assert_eq!(dt.current_offset_since(), Some(datetime!(2021-03-14 10:00).timestamp()));
+
+    let dt = datetime!(2021-12-25 12:00).in_timezone(zone.clone());
+    assert_eq!(dt.tzname(), Some("PST"));
+    assert_eq!(dt.current_offset_since(), Some(datetime!(2021-11-07 09:00).timestamp()));
+
+    // Far in the future the POSIX TZ string is used
+    let dt = datetime!(2400-07-04 12:00).in_timezone(zone.clone());
+    let since = dt.current_offset_since().unwrap();
+    assert_eq!(since.to_utc().year(), 2400);
+    assert_eq!(since.to_utc().month(), 3);
+    assert_eq!(zone.offset(since), utc_offset!(-07:00));
+    assert_eq!(
+        zone.offset(eos::Timestamp::from_seconds(since.as_seconds() - 1)),
+        utc_offset!(-08:00)
+    );
+
+    // Before the first transition there is nothing to report
+    let dt = datetime!(1800-01-01 00:00).in_timezone(zone);
+    assert_eq!(dt.current_offset_since(), None);
+    assert_eq!(
+        zone!("UTC").previous_transition(datetime!(2021-07-04 12:00).timestamp()),
+        None
+    );
+}
+
+#[test]
+fn test_resolve_by_reference() {
+    // The same zone is reused for every conversion without being moved
+    let zone = get_zone("America/Los_Angeles");
+
+    let winter = zone.convert_utc(datetime!(2021-01-01 12:00));
+    assert_eq!(winter.offset(), utc_offset!(-08:00));
+    let summer = zone.convert_utc(datetime!(2021-07-01 12:00));
+    assert_eq!(summer.offset(), utc_offset!(-07:00));
+
+    let ambiguous = zone.resolve(eos::date!(2021 - 11 - 07), eos::time!(01:30));
+    assert!(ambiguous.is_ambiguous());
+    let missing = zone.resolve(eos::date!(2021 - 03 - 14), eos::time!(02:30));
+    assert!(missing.is_missing());
+
+    let dt = zone.at(eos::date!(2021 - 06 - 01), eos::time!(09:00));
+    assert_eq!(dt.in_timezone(Utc), datetime!(2021-06-01 16:00));
+    assert!(zone.at_exactly(eos::date!(2021 - 11 - 07), eos::time!(01:30)).is_err());
+
+    let dt = datetime!(2021-06-01 16:00).in_timezone_ref(&zone);
+    assert_eq!(dt.time(), eos::time!(09:00));
+    assert_eq!(dt.timezone(), &zone);
+}
+
+#[test]
+fn test_load_static_id() {
+    let id: &'static str = "Asia/Tokyo";
+    let bytes: &[u8] = include_bytes!("Asia/Tokyo");
+    let tz = eos_tz::TimeZone::from_bytes(bytes, id).unwrap();
+    assert_eq!(tz.id(), id);
+    // A static identifier should be stored as-is rather than copied into a new String
+    assert!(std::ptr::eq(tz.id().as_ptr(), id.as_ptr()));
+
+    let tz = zone!("Asia/Tokyo");
+    assert_eq!(tz.id(), "Asia/Tokyo");
+
+    let owned = eos_tz::TimeZone::from_bytes(bytes, String::from(id)).unwrap();
+    assert_eq!(owned.id(), id);
+    assert_eq!(owned, tz);
+}
+
+#[test]
+fn test_transition_window() {
+    let zone = zone!("America/New_York");
+
+    // Spring forward on 2021-03-14 at 2AM local time
+    let around = datetime!(2021-03-14 12:00).timestamp();
+    let (transition, before, after) = zone.transition_window(around).unwrap();
+    assert_eq!(transition, datetime!(2021-03-14 07:00).timestamp());
+    assert_eq!(before, utc_offset!(-05:00));
+    assert_eq!(after, utc_offset!(-04:00));
+
+    // The missing local times are [2:00, 3:00)
+    let gap_start = transition.to_utc().in_timezone(before);
+    let gap_end = transition.to_utc().in_timezone(after);
+    assert_eq!(gap_start.time(), eos::time!(02:00));
+    assert_eq!(gap_end.time(), eos::time!(03:00));
+    assert!(zone.resolve(gap_start.date(), gap_start.time()).is_missing());
+    assert!(!zone.resolve(gap_end.date(), gap_end.time()).is_missing());
+
+    // The transition itself is included in the window
+    assert_eq!(zone.transition_window(transition).map(|t| t.0), Some(transition));
+
+    // Right before it the previous fall back transition is returned instead
+    let earlier = eos::Timestamp::from_seconds(transition.as_seconds() - 1);
+    let (transition, before, after) = zone.transition_window(earlier).unwrap();
+    assert_eq!(transition, datetime!(2020-11-01 06:00).timestamp());
+    assert_eq!(before, utc_offset!(-04:00));
+    assert_eq!(after, utc_offset!(-05:00));
+
+    assert_eq!(zone!("UTC").transition_window(around), None);
+}
+
+#[test]
+fn test_next_and_prev_transition() {
+    let zone = zone!("America/New_York");
+    let spring = datetime!(2023-03-12 07:00).timestamp();
+    let fall = datetime!(2023-11-05 06:00).timestamp();
+
+    let now = datetime!(2023-01-15 12:00).timestamp();
+    assert_eq!(zone.next_transition(now), Some(spring));
+    assert_eq!(zone.next_transition(spring), Some(fall));
+    assert_eq!(zone.prev_transition(fall), Some(spring));
+    assert_eq!(zone.prev_transition(now), Some(datetime!(2022-11-06 06:00).timestamp()));
+
+    // Both are strict so the transition itself is never returned
+    let before = eos::Timestamp::from_seconds(spring.as_seconds() - 1);
+    let after = eos::Timestamp::new(spring.as_seconds(), 1);
+    assert_eq!(zone.next_transition(before), Some(spring));
+    assert_eq!(zone.prev_transition(after), Some(spring));
+    assert_eq!(zone.prev_transition(spring), zone.prev_transition(before));
+
+    // Far into the future the POSIX TZ string is used
+    let future = datetime!(2040-01-01 00:00).timestamp();
+    assert_eq!(
+        zone.next_transition(future),
+        Some(datetime!(2040-03-11 07:00).timestamp())
+    );
+    assert_eq!(
+        zone.prev_transition(future),
+        Some(datetime!(2039-11-06 06:00).timestamp())
+    );
+
+    // Walking forwards and backwards through the transitions visits the same instants
+    let mut forwards = vec![zone.next_transition(now).unwrap()];
+    for _ in 0..10 {
+        let next = zone.next_transition(*forwards.last().unwrap()).unwrap();
+        assert_ne!(
+            zone.offset(eos::Timestamp::from_seconds(next.as_seconds() - 1)),
+            zone.offset(next)
+        );
+        forwards.push(next);
+    }
+    let mut backwards = vec![*forwards.last().unwrap()];
+    for _ in 0..10 {
+        backwards.push(zone.prev_transition(*backwards.last().unwrap()).unwrap());
+    }
+    backwards.reverse();
+    assert_eq!(forwards, backwards);
+
+    assert_eq!(zone!("UTC").next_transition(now), None);
+    assert_eq!(zone!("UTC").prev_transition(now), None);
+    assert_eq!(get_zone("Asia/Tokyo").next_transition(now), None);
+}
+
+#[test]
+fn test_display_alternate_zone_name() {
+    let zone = zone!("America/New_York");
+    let dt = datetime!(2021-11-07 08:30).in_timezone(zone.clone());
+    assert_eq!(dt.to_string(), "2021-11-07T03:30:00-05:00");
+    assert_eq!(format!("{}", dt), "2021-11-07T03:30:00-05:00");
+    assert_eq!(format!("{:#}", dt), "2021-11-07T03:30:00-05:00 EST");
+
+    let dt = datetime!(2021-07-04 16:00).in_timezone(zone);
+    assert_eq!(format!("{}", dt), "2021-07-04T12:00:00-04:00");
+    assert_eq!(format!("{:#}", dt), "2021-07-04T12:00:00-04:00 EDT");
+
+    assert_eq!(format!("{:#}", DateTime::UNIX_EPOCH), "1970-01-01T00:00:00+00:00 UTC");
+
+    // Fixed offsets have no name so nothing is appended
+    let dt = datetime!(2021-11-07 01:30 -05:00);
+    assert_eq!(format!("{:#}", dt), format!("{}", dt));
+}
+
+#[test]
+fn test_convert_utc_ambiguous() {
+    let zone = zone!("America/New_York");
+
+    // 1:30 AM happened twice on 2021-11-07, first in EDT then in EST
+    let earlier = datetime!(2021-11-07 05:30).in_timezone(zone.clone());
+    let later = datetime!(2021-11-07 06:30).in_timezone(zone.clone());
+    assert_eq!(earlier.time(), eos::time!(01:30));
+    assert_eq!(later.time(), eos::time!(01:30));
+    assert_eq!(earlier.offset(), utc_offset!(-04:00));
+    assert_eq!(later.offset(), utc_offset!(-05:00));
+    assert_eq!(format!("{:#}", earlier), "2021-11-07T01:30:00-04:00 EDT");
+    assert_eq!(format!("{:#}", later), "2021-11-07T01:30:00-05:00 EST");
+    assert_eq!(later.in_timezone(Utc), datetime!(2021-11-07 06:30));
+
+    // Every UTC hour around the transition maps to a distinct instant
+    let mut utc = datetime!(2021-11-07 04:00);
+    for (hour, offset) in [(0, -4), (1, -4), (1, -5), (2, -5), (3, -5)] {
+        let local = utc.in_timezone(zone.clone());
+        assert_eq!(local.hour(), hour);
+        assert_eq!(local.offset().hours(), offset);
+        assert_eq!(local.in_timezone(Utc), utc);
+        utc = utc + 1.hours();
+    }
+
+    // Same for the POSIX rule used past the end of the transition data
+    let tz = eos_tz::PosixTimeZone::new("EST+5EDT,M3.2.0/2,M11.1.0/2").unwrap();
+    let later = datetime!(2021-11-07 06:30).in_timezone(tz);
+    assert_eq!(later.time(), eos::time!(01:30));
+    assert_eq!(later.offset(), utc_offset!(-05:00));
+}
+
+#[test]
+fn test_fold() {
+    let zone = zone!("America/New_York");
+
+    // 1:30 AM happened twice on 2021-11-07, first in EDT then in EST
+    let resolution = zone.resolve(eos::date!(2021 - 11 - 07), eos::time!(01:30));
+    assert!(resolution.is_ambiguous());
+    assert_eq!(resolution.fold(), Some(0));
+
+    let earlier = resolution.clone().with_fold(0).unwrap();
+    let later = resolution.clone().with_fold(1).unwrap();
+    assert_eq!(earlier, datetime!(2021-11-07 05:30));
+    assert_eq!(later, datetime!(2021-11-07 06:30));
+    assert_eq!(earlier.offset(), utc_offset!(-04:00));
+    assert_eq!(later.offset(), utc_offset!(-05:00));
+    assert_eq!(earlier.fold(), Some(0));
+    assert_eq!(later.fold(), Some(1));
+    assert_eq!(resolution.lenient().fold(), Some(0));
+
+    // Converting from UTC keeps track of which occurrence it was
+    assert_eq!(datetime!(2021-11-07 05:30).in_timezone(zone.clone()).fold(), Some(0));
+    assert_eq!(datetime!(2021-11-07 06:30).in_timezone(zone.clone()).fold(), Some(1));
+
+    // The fold restores the same date time
+    for dt in [earlier, later] {
+        let fold = dt.fold().unwrap();
+        let restored = zone.resolve(dt.date(), dt.time()).with_fold(fold).unwrap();
+        assert_eq!(restored, dt);
+        assert_eq!(restored.offset(), dt.offset());
+    }
+
+    // Unambiguous and missing times have no fold
+    let resolution = zone.resolve(eos::date!(2021 - 11 - 07), eos::time!(03:30));
+    assert_eq!(resolution.fold(), None);
+    assert_eq!(resolution.lenient().fold(), None);
+    let resolution = zone.resolve(eos::date!(2021 - 03 - 14), eos::time!(02:30));
+    assert!(resolution.is_missing());
+    assert_eq!(resolution.fold(), None);
+    assert!(resolution.with_fold(1).is_err());
+    assert_eq!(datetime!(2021-11-07 01:30 -05:00).fold(), None);
+}
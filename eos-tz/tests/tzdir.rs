@@ -0,0 +1,34 @@
+#![cfg(target_family = "unix")]
+
+// This is in its own test binary since it modifies the process environment.
+
+use eos::{datetime, utc_offset, TimeZone};
+
+#[test]
+fn test_locate_with_tzdir() {
+    let tzdir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests");
+    std::env::set_var("TZDIR", tzdir);
+
+    let zone = eos_tz::TimeZone::locate("Asia/Tokyo").expect("zone not found");
+    assert_eq!(zone.id(), "Asia/Tokyo");
+    assert_eq!(
+        zone.offset(datetime!(2022-01-29 10:30).timestamp()),
+        utc_offset!(+09:00)
+    );
+
+    // The identifier is relative to TZDIR as well
+    let zone = eos_tz::TimeZone::from_path(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/Europe/London"))
+        .expect("parsing failed");
+    assert_eq!(zone.id(), "Europe/London");
+
+    assert!(matches!(
+        eos_tz::TimeZone::locate("Asia/../Asia/Tokyo"),
+        Err(eos_tz::Error::InvalidZonePath)
+    ));
+    assert!(matches!(
+        eos_tz::TimeZone::locate("Not/A_Zone"),
+        Err(eos_tz::Error::NotFound)
+    ));
+
+    std::env::remove_var("TZDIR");
+}
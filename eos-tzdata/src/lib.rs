@@ -1,3 +1,4 @@
+#[allow(clippy::large_const_arrays)]
 pub mod data;
 
 /// An IANA tzdb zone entry.
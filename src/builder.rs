@@ -1,4 +1,4 @@
-use crate::{Date, DateTime, Error, IsoWeekDate, Time, TimeZone, Weekday};
+use crate::{Date, DateTime, DateTimeResolution, Error, IsoWeekDate, Time, TimeZone, Timestamp, Weekday};
 
 /// A builder to construct a [`Date`], [`Time`], or [`DateTime`] instance.
 ///
@@ -19,9 +19,10 @@ where
     Tz: TimeZone,
 {
     year: Option<i16>,
-    month: u8,
-    day: u8,
+    month: Option<u8>,
+    day: Option<u8>,
     ordinal: Option<u16>,
+    iso_year: Option<i16>,
     iso_week: Option<u8>,
     weekday: Option<Weekday>,
     meridiem: Option<AmPm>,
@@ -29,6 +30,7 @@ where
     minute: u8,
     second: u8,
     nanosecond: u32,
+    timestamp: Option<Timestamp>,
     pub(crate) timezone: Tz,
 }
 
@@ -43,9 +45,10 @@ impl Builder<crate::Utc> {
     pub const fn new() -> Self {
         Self {
             year: None,
-            month: 1,
-            day: 1,
+            month: None,
+            day: None,
             ordinal: None,
+            iso_year: None,
             iso_week: None,
             weekday: None,
             meridiem: None,
@@ -53,11 +56,18 @@ impl Builder<crate::Utc> {
             minute: 0,
             second: 0,
             nanosecond: 0,
+            timestamp: None,
             timezone: crate::Utc,
         }
     }
 }
 
+impl Default for Builder<crate::Utc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Tz> Builder<Tz>
 where
     Tz: TimeZone,
@@ -74,7 +84,7 @@ where
     ///
     /// This does *not* do any bound checking. The final build step does.
     pub fn month(&mut self, month: u8) -> &mut Self {
-        self.month = month;
+        self.month = Some(month);
         self
     }
 
@@ -82,7 +92,7 @@ where
     ///
     /// This does *not* do any bound checking. The final build step does.
     pub fn day(&mut self, day: u8) -> &mut Self {
-        self.day = day;
+        self.day = Some(day);
         self
     }
 
@@ -94,6 +104,17 @@ where
         self
     }
 
+    /// Sets the date to the given ISO week-numbering year.
+    ///
+    /// This differs from [`Self::year`] since the ISO week-numbering year can differ
+    /// from the Gregorian year near the start or end of a year.
+    ///
+    /// This does *not* do any bound checking. The final build step does.
+    pub fn iso_year(&mut self, iso_year: i16) -> &mut Self {
+        self.iso_year = Some(iso_year);
+        self
+    }
+
     /// Sets the date to the given ISO week.
     ///
     /// This does *not* do any bound checking. The final build step does.
@@ -178,6 +199,50 @@ where
         self
     }
 
+    /// Sets the instant in time to the given UNIX timestamp.
+    ///
+    /// When set, every other date and time component is ignored except for the
+    /// nanosecond, which is added to the timestamp. The date and time are instead
+    /// derived from the timestamp in the final timezone.
+    ///
+    /// This does *not* do any bound checking. The final build step does.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, Builder, Timestamp};
+    ///
+    /// let dt = Builder::new().timezone(utc_offset!(+09:00)).timestamp(Timestamp::from_seconds(1641173925)).build()?;
+    /// assert_eq!(dt, datetime!(2022-01-03 10:38:45 +09:00));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    pub fn timestamp(&mut self, timestamp: Timestamp) -> &mut Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Converts the timestamp, if any, into a [`DateTime`] in the given timezone.
+    fn build_from_timestamp<OtherTz>(&self, timezone: &OtherTz) -> Option<Result<DateTime<OtherTz>, Error>>
+    where
+        OtherTz: TimeZone,
+    {
+        let mut timestamp = self.timestamp?;
+        if self.nanosecond != 0 {
+            let nanos = timestamp.nanoseconds as u64 + self.nanosecond as u64;
+            let seconds = timestamp.as_seconds().saturating_add((nanos / 1_000_000_000) as i64);
+            timestamp = Timestamp::new(seconds, (nanos % 1_000_000_000) as u32);
+        }
+        let range = Timestamp::MIN_VALID..=Timestamp::MAX_VALID;
+        if !range.contains(&timestamp.as_seconds()) {
+            return Some(Err(Error::OutOfRange));
+        }
+        // The local date and time could still be out of range after applying the offset
+        let offset = timezone.offset(timestamp);
+        if !range.contains(&(timestamp.as_seconds() + offset.total_seconds() as i64)) {
+            return Some(Err(Error::OutOfRange));
+        }
+        Some(Ok(timezone.convert_utc(timestamp.to_utc())))
+    }
+
     #[cfg(feature = "parsing")]
     pub(crate) fn fix_leap_seconds(&mut self) {
         if self.second == 60 {
@@ -199,6 +264,7 @@ where
             month: self.month,
             day: self.day,
             ordinal: self.ordinal,
+            iso_year: self.iso_year,
             iso_week: self.iso_week,
             weekday: self.weekday,
             meridiem: self.meridiem,
@@ -206,6 +272,7 @@ where
             minute: self.minute,
             second: self.second,
             nanosecond: self.nanosecond,
+            timestamp: self.timestamp,
             timezone,
         }
     }
@@ -218,9 +285,62 @@ where
     /// To build a [`Date`], see [`Self::build_date`]. To build a [`Time`],
     /// see [`Self::build_time`].
     pub fn build(&self) -> Result<DateTime<Tz>, Error> {
+        if let Some(dt) = self.build_from_timestamp(&self.timezone) {
+            return dt;
+        }
         let date = self.build_date()?;
         let time = self.build_time()?;
-        self.timezone.clone().at_exactly(date, time)
+        self.timezone.at_exactly(date, time)
+    }
+
+    /// Builds the final [`DateTime`] with the given components in the given timezone.
+    ///
+    /// Unlike [`Self::build`], the local date and time are resolved leniently rather
+    /// than exactly. If the time falls in a gap, such as when entering DST, then the
+    /// gap is skipped and the time moves forward. If the time is ambiguous, such as when
+    /// leaving DST, then the earlier time is used. This is equivalent to
+    /// [`TimeZone::at`]. If more control is needed then use [`Self::build_at_strict`].
+    ///
+    /// The timezone previously set on the builder is ignored.
+    ///
+    /// If the components represent an invalid date or time then an [`Error`]
+    /// is returned.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, Builder};
+    ///
+    /// let dt = Builder::new().year(2022).month(3).day(14).hour(9).build_at(utc_offset!(-04:00))?;
+    /// assert_eq!(dt, datetime!(2022-03-14 9:00 -04:00));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn build_at<OtherTz>(&self, timezone: OtherTz) -> Result<DateTime<OtherTz>, Error>
+    where
+        OtherTz: TimeZone,
+    {
+        self.build_at_strict(timezone).map(DateTimeResolution::lenient)
+    }
+
+    /// Builds the [`DateTimeResolution`] of the given components in the given timezone.
+    ///
+    /// This allows handling ambiguous or missing times manually rather than leniently
+    /// like [`Self::build_at`]. This is equivalent to [`TimeZone::resolve`].
+    ///
+    /// The timezone previously set on the builder is ignored.
+    ///
+    /// If the components represent an invalid date or time then an [`Error`]
+    /// is returned.
+    pub fn build_at_strict<OtherTz>(&self, timezone: OtherTz) -> Result<DateTimeResolution<OtherTz>, Error>
+    where
+        OtherTz: TimeZone,
+    {
+        if let Some(dt) = self.build_from_timestamp(&timezone) {
+            let dt = dt?;
+            let (date, time, offset) = (dt.date(), dt.time(), dt.offset());
+            return Ok(DateTimeResolution::unambiguous(date, time, offset, timezone));
+        }
+        let date = self.build_date()?;
+        let time = self.build_time()?;
+        Ok(timezone.resolve(date, time))
     }
 
     /// Builds the final [`Date`] with the given components.
@@ -228,21 +348,41 @@ where
     /// A date is built with the following priority:
     ///
     /// 1. If an ordinal and year is given, then calculate it using that.
-    /// 2. If an ISO week and year is given, then calculate using Monday as the weekday.
-    /// 3. If an ISO week, weekday, and year is given then calculate using that.
+    /// 2. If an ISO week and ISO week-numbering year is given, then calculate using that.
+    /// 3. If an ISO week and year is given without a month or day, then calculate using that
+    ///    while treating the year as an ISO week-numbering year.
     /// 4. Calculate the date using the provided values or with their defaults.
     ///
+    /// When calculating using the ISO week, the weekday defaults to Monday if not given.
+    ///
+    /// If a [timestamp](Self::timestamp) is given then the date is derived from it instead.
+    ///
     /// If the components represent an invalid date then an [`Error`]
     /// is returned.
     pub fn build_date(&self) -> Result<Date, Error> {
+        if let Some(dt) = self.build_from_timestamp(&self.timezone) {
+            return dt.map(|dt| dt.date());
+        }
+
+        let iso_year = match self.iso_year {
+            Some(iso_year) => Some(iso_year),
+            None if self.month.is_none() && self.day.is_none() => self.year,
+            None => None,
+        };
+
         if let Some((ordinal, year)) = self.ordinal.zip(self.year) {
             Date::from_ordinal(year, ordinal).ok_or(Error::OutOfRange)
-        } else if let Some((week, year)) = self.iso_week.zip(self.year) {
+        } else if let Some((week, year)) = self.iso_week.zip(iso_year) {
             let weekday = self.weekday.unwrap_or(Weekday::Monday);
             let iso_week = IsoWeekDate::new(year, week, weekday).ok_or(Error::OutOfRange)?;
             Ok(Date::from(iso_week))
         } else {
-            Date::new(self.year.unwrap_or(1970), self.month, self.day).ok_or(Error::OutOfRange)
+            Date::new(
+                self.year.unwrap_or(1970),
+                self.month.unwrap_or(1),
+                self.day.unwrap_or(1),
+            )
+            .ok_or(Error::OutOfRange)
         }
     }
 
@@ -250,26 +390,23 @@ where
     ///
     /// If either [`Self::am`] or [`Self::pm`] are called then the time is assumed to be in
     /// 12-hour clock with a range of `1..=12`. If they're not called then 24-hour time
-    /// is assumed.
+    /// is assumed. The conversion to 24-hour time is done here, so the order in which
+    /// the hour and meridiem are set does not matter. For example, `12 AM` is midnight,
+    /// `12 PM` is noon, and `1 PM` is `13:00`.
+    ///
+    /// If a [timestamp](Self::timestamp) is given then the time is derived from it instead.
     ///
     /// If the components represent an invalid time then an [`Error`]
     /// is returned.
     pub fn build_time(&self) -> Result<Time, Error> {
+        if let Some(dt) = self.build_from_timestamp(&self.timezone) {
+            return dt.map(|dt| dt.time());
+        }
+
         let hour = match self.meridiem {
-            Some(AmPm::Am) => {
-                if self.hour == 12 {
-                    0
-                } else {
-                    self.hour
-                }
-            }
-            Some(AmPm::Pm) => {
-                if self.hour == 12 {
-                    12
-                } else {
-                    self.hour + 12
-                }
-            }
+            Some(_) if !(1..=12).contains(&self.hour) => return Err(Error::OutOfRange),
+            Some(AmPm::Am) => self.hour % 12,
+            Some(AmPm::Pm) => self.hour % 12 + 12,
             None => self.hour,
         };
 
@@ -312,4 +449,43 @@ mod tests {
         assert_eq!(date.day(), 22);
         Ok(())
     }
+
+    #[test]
+    fn test_iso_year_construction() -> Result<(), Error> {
+        // 2008-12-29 is the first day of ISO week 1 of 2009
+        let date = Builder::new().iso_year(2009).iso_week(1).build_date()?;
+        assert_eq!(date, Date::new(2008, 12, 29).unwrap());
+
+        let date = Builder::new()
+            .year(2008)
+            .month(12)
+            .day(29)
+            .iso_year(2009)
+            .iso_week(1)
+            .weekday(Weekday::Monday)
+            .build_date()?;
+        assert_eq!(date, Date::new(2008, 12, 29).unwrap());
+
+        // Without an ISO year the Gregorian month and day take priority
+        let date = Builder::new().year(2008).month(12).day(29).iso_week(1).build_date()?;
+        assert_eq!(date, Date::new(2008, 12, 29).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_meridiem_construction() -> Result<(), Error> {
+        for (hour, am, pm) in [(12, 0, 12), (1, 1, 13), (11, 11, 23)] {
+            assert_eq!(Builder::new().hour(hour).am().build_time()?.hour(), am);
+            assert_eq!(Builder::new().hour(hour).pm().build_time()?.hour(), pm);
+            // The order the meridiem is set in does not matter
+            assert_eq!(Builder::new().pm().hour(hour).build_time()?.hour(), pm);
+        }
+
+        // 12-hour clocks only go from 1 to 12
+        for hour in [0, 13, 23, 255] {
+            assert!(Builder::new().hour(hour).am().build_time().is_err());
+            assert!(Builder::new().hour(hour).pm().build_time().is_err());
+        }
+        Ok(())
+    }
 }
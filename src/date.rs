@@ -5,7 +5,7 @@ use crate::{
     },
     step::Advance,
     utils::{divrem, ensure_in_range},
-    DateTime, Interval, Time, TimeZone, Utc,
+    DateTime, Error, Interval, Time, TimeZone, Utc,
 };
 
 use core::ops::{Add, AddAssign, Sub, SubAssign};
@@ -35,6 +35,29 @@ pub enum Weekday {
     Sunday = 7,
 }
 
+/// Every [`Weekday`] in ISO order, starting from Monday.
+///
+/// The index of each weekday is its [`Weekday::days_from_monday`] value.
+///
+/// ```
+/// use eos::{Weekday, WEEKDAYS};
+///
+/// assert_eq!(WEEKDAYS[0], Weekday::Monday);
+/// assert_eq!(WEEKDAYS[6], Weekday::Sunday);
+/// for weekday in WEEKDAYS {
+///     assert_eq!(WEEKDAYS[weekday.days_from_monday() as usize], weekday);
+/// }
+/// ```
+pub const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
 impl Weekday {
     /// Returns the next weekday.
     ///
@@ -147,6 +170,116 @@ impl Weekday {
             Self::Sunday => 0,
         }
     }
+
+    /// Returns the weekday from a day of the week number starting from Monday.
+    ///
+    /// This is the inverse of [`Weekday::number_from_monday`].
+    ///
+    /// Number  | 1        | 2         | 3           | 4          | 5        | 6          | 7
+    /// --------|----------|-----------|-------------|------------|----------|------------|---------
+    /// Weekday | `Monday` | `Tuesday` | `Wednesday` | `Thursday` | `Friday` | `Saturday` | `Sunday`
+    ///
+    /// If the number is out of range (`1..=7`) then [`None`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn from_number_from_monday(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::Monday),
+            2 => Some(Self::Tuesday),
+            3 => Some(Self::Wednesday),
+            4 => Some(Self::Thursday),
+            5 => Some(Self::Friday),
+            6 => Some(Self::Saturday),
+            7 => Some(Self::Sunday),
+            _ => None,
+        }
+    }
+
+    /// Returns the weekday from a day of the week number starting from Sunday.
+    ///
+    /// This is the inverse of [`Weekday::number_from_sunday`].
+    ///
+    /// Number  | 1       | 2        | 3         | 4           | 5          | 6        | 7
+    /// --------|---------|----------|-----------|-------------|------------|----------|------------
+    /// Weekday | `Sunday`| `Monday` | `Tuesday` | `Wednesday` | `Thursday` | `Friday` | `Saturday`
+    ///
+    /// If the number is out of range (`1..=7`) then [`None`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn from_number_from_sunday(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::Sunday),
+            2 => Some(Self::Monday),
+            3 => Some(Self::Tuesday),
+            4 => Some(Self::Wednesday),
+            5 => Some(Self::Thursday),
+            6 => Some(Self::Friday),
+            7 => Some(Self::Saturday),
+            _ => None,
+        }
+    }
+
+    /// Returns the weekday from the number of days from Monday.
+    ///
+    /// This is the inverse of [`Weekday::days_from_monday`].
+    ///
+    /// Number  | 0        | 1         | 2           | 3          | 4        | 5          | 6
+    /// --------|----------|-----------|-------------|------------|----------|------------|---------
+    /// Weekday | `Monday` | `Tuesday` | `Wednesday` | `Thursday` | `Friday` | `Saturday` | `Sunday`
+    ///
+    /// If the number is out of range (`0..=6`) then [`None`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn from_days_from_monday(n: u8) -> Option<Self> {
+        if n > 6 {
+            None
+        } else {
+            Self::from_number_from_monday(n + 1)
+        }
+    }
+
+    /// Returns the weekday from the number of days from Sunday.
+    ///
+    /// This is the inverse of [`Weekday::days_from_sunday`].
+    ///
+    /// Number  | 0       | 1        | 2         | 3           | 4          | 5        | 6
+    /// --------|---------|----------|-----------|-------------|------------|----------|------------
+    /// Weekday | `Sunday`| `Monday` | `Tuesday` | `Wednesday` | `Thursday` | `Friday` | `Saturday`
+    ///
+    /// If the number is out of range (`0..=6`) then [`None`] is returned.
+    #[inline]
+    #[must_use]
+    pub const fn from_days_from_sunday(n: u8) -> Option<Self> {
+        if n > 6 {
+            None
+        } else {
+            Self::from_number_from_sunday(n + 1)
+        }
+    }
+}
+
+/// Whether a number is even or odd.
+///
+/// This is returned by [`Date::iso_week_parity`] to help with alternating week schedules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Parity {
+    /// The number is divisible by two.
+    Even,
+    /// The number is not divisible by two.
+    Odd,
+}
+
+impl Parity {
+    /// Returns the parity of the given number.
+    #[inline]
+    #[must_use]
+    pub const fn of(n: i64) -> Self {
+        if n % 2 == 0 {
+            Self::Even
+        } else {
+            Self::Odd
+        }
+    }
 }
 
 /// A date in the [ISO 8601 week date system].
@@ -286,6 +419,23 @@ impl Date {
         dt.date()
     }
 
+    /// Returns the [`Interval`] elapsed between this date and today's date in UTC.
+    ///
+    /// This is equivalent to `Date::today_utc() - self`. If this date is in the
+    /// future then the returned interval is negative.
+    ///
+    /// ```no_run
+    /// use eos::date;
+    ///
+    /// let elapsed = date!(2000-01-01).elapsed();
+    /// println!("{} years have passed", elapsed.years());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn elapsed(&self) -> Interval {
+        Interval::between_dates(self, &Self::today_utc())
+    }
+
     /// Creates a new [`Date`] from a given year, month, and day.
     ///
     /// The month must be between `1..=12` and the day must be between `1..=31`.
@@ -305,13 +455,43 @@ impl Date {
     /// # }
     /// # test();
     /// ```
+    ///
+    /// This can also be used in `const` contexts:
+    ///
+    /// ```
+    /// # use eos::Date;
+    /// const HOLIDAYS: [Option<Date>; 2] = [Date::new(2022, 12, 25), Date::new(2022, 2, 29)];
+    /// assert!(HOLIDAYS[0].is_some());
+    /// assert!(HOLIDAYS[1].is_none());
+    /// ```
     #[inline]
-    pub fn new(year: i16, month: u8, day: u8) -> Option<Self> {
+    pub const fn new(year: i16, month: u8, day: u8) -> Option<Self> {
         ensure_in_range!(month, 1 => 12);
         ensure_in_range!(day, 1 => days_in_month(year, month));
         Some(Self { year, month, day })
     }
 
+    /// Creates a new [`Date`] from a given year, month, and day.
+    ///
+    /// This is similar to [`Date::new`] except it returns [`Error::OutOfRange`]
+    /// rather than [`None`] if the date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{Date, Error};
+    /// let date = Date::ymd(2003, 4, 19)?;
+    /// assert_eq!(date.year(), 2003);
+    /// assert_eq!(date.month(), 4);
+    /// assert_eq!(date.day(), 19);
+    /// assert_eq!(Date::ymd(2013, 2, 29), Err(Error::OutOfRange)); // 2013 was not a leap year
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    pub fn ymd(year: i16, month: u8, day: u8) -> Result<Self, Error> {
+        Self::new(year, month, day).ok_or(Error::OutOfRange)
+    }
+
     /// Combines this [`Date`] with a [`Time`] to create a [`DateTime`] in [`Utc`].
     #[inline]
     #[must_use]
@@ -350,6 +530,50 @@ impl Date {
         tz.resolve(self, Time::MIDNIGHT).lenient()
     }
 
+    /// Returns an iterator over every date from this date up to and including `end`.
+    ///
+    /// If `end` is before this date then the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// let start = date!(2022 - 02 - 01);
+    /// let days: Vec<_> = start.range_inclusive(start.last_day_of_month()).collect();
+    /// assert_eq!(days.len(), 28);
+    /// assert_eq!(days.last(), Some(&date!(2022 - 02 - 28)));
+    ///
+    /// assert_eq!(start.range_inclusive(start).count(), 1);
+    /// assert_eq!(start.range_inclusive(date!(2022 - 01 - 01)).count(), 0);
+    /// ```
+    pub fn range_inclusive(self, end: Date) -> crate::iter::Days {
+        // Dates are bound by their i16 year so the next day cannot overflow
+        crate::iter::Days::new(self.days_since_epoch(), end.days_since_epoch() + 1)
+    }
+
+    /// Returns an iterator over every date from this date up to but excluding `end`.
+    ///
+    /// If `end` is before this date then the iterator counts down instead, still
+    /// excluding `end`. The number of dates yielded is the absolute value of
+    /// [`Date::days_since`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// let start = date!(2022 - 02 - 27);
+    /// let days: Vec<_> = start.iter_days_until(date!(2022 - 03 - 02)).collect();
+    /// assert_eq!(days, [date!(2022 - 02 - 27), date!(2022 - 02 - 28), date!(2022 - 03 - 01)]);
+    ///
+    /// let days: Vec<_> = start.iter_days_until(date!(2022 - 02 - 25)).collect();
+    /// assert_eq!(days, [date!(2022 - 02 - 27), date!(2022 - 02 - 26)]);
+    ///
+    /// assert_eq!(start.iter_days_until(start).count(), 0);
+    /// ```
+    pub fn iter_days_until(self, end: Date) -> crate::iter::Days {
+        crate::iter::Days::until(self.days_since_epoch(), end.days_since_epoch())
+    }
+
     pub(crate) fn add_days(&self, days: i32) -> Self {
         if days == 0 {
             return *self;
@@ -449,6 +673,25 @@ impl Date {
         self.month
     }
 
+    /// Returns the calendar quarter.
+    ///
+    /// This value will always be within `1..=4`. Quarters start in January, April,
+    /// July, and October.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-01-15).quarter(), 1);
+    /// assert_eq!(date!(2012-06-30).quarter(), 2);
+    /// assert_eq!(date!(2012-10-01).quarter(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn quarter(&self) -> u8 {
+        (self.month - 1) / 3 + 1
+    }
+
     /// Returns the day.
     ///
     /// This value will always be within `1..=31`.
@@ -521,6 +764,33 @@ impl Date {
         self.days_since_epoch() - other.days_since_epoch()
     }
 
+    /// Returns the number of completed years since the given date.
+    ///
+    /// This is useful for computing ages. A year is only completed once the anniversary
+    /// of `earlier` has been reached. If `earlier` is a leap day then the anniversary in
+    /// a non-leap year is considered to be February 28th, matching the behaviour of
+    /// adding years to a date.
+    ///
+    /// If `earlier` is after this date then the result is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// let birthday = date!(1990 - 06 - 15);
+    /// assert_eq!(date!(2022 - 06 - 14).years_since(birthday), 31);
+    /// assert_eq!(date!(2022 - 06 - 15).years_since(birthday), 32);
+    ///
+    /// let leap = date!(2000 - 02 - 29);
+    /// assert_eq!(date!(2001 - 02 - 27).years_since(leap), 0);
+    /// assert_eq!(date!(2001 - 02 - 28).years_since(leap), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn years_since(&self, earlier: Date) -> i32 {
+        self.at(Time::MIDNIGHT).years_since(&earlier.at(Time::MIDNIGHT))
+    }
+
     /// Returns the weekday.
     ///
     /// # Examples
@@ -550,6 +820,61 @@ impl Date {
         }
     }
 
+    /// Returns `true` if this date falls on a Saturday or Sunday.
+    ///
+    /// For regions with a different weekend, see [`Date::is_weekend_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert!(date!(2021-12-25).is_weekend());
+    /// assert!(date!(2021-12-26).is_weekend());
+    /// assert!(!date!(2021-12-24).is_weekend());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_weekend(&self) -> bool {
+        self.is_weekend_with(&[Weekday::Saturday, Weekday::Sunday])
+    }
+
+    /// Returns `true` if this date falls on a Monday through Friday.
+    ///
+    /// This is the opposite of [`Date::is_weekend`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert!(date!(2021-12-24).is_weekday());
+    /// assert!(!date!(2021-12-25).is_weekday());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_weekday(&self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Returns `true` if this date falls on one of the given weekend days.
+    ///
+    /// This is useful for regions where the weekend is not Saturday and Sunday,
+    /// such as a Friday and Saturday weekend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{date, Weekday};
+    /// let weekend = [Weekday::Friday, Weekday::Saturday];
+    /// assert!(date!(2021-12-24).is_weekend_with(&weekend));
+    /// assert!(date!(2021-12-25).is_weekend_with(&weekend));
+    /// assert!(!date!(2021-12-26).is_weekend_with(&weekend));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_weekend_with(&self, weekend: &[Weekday]) -> bool {
+        weekend.contains(&self.weekday())
+    }
+
     /// Returns a [`Date`] moved to the next step of the given value.
     ///
     /// # Examples
@@ -672,6 +997,140 @@ impl Date {
         }
     }
 
+    /// Returns whether the ISO week number of this date is even or odd.
+    ///
+    /// This is useful for "every other week" schedules. Note that since an ISO year
+    /// can have 53 weeks, the parity does not always alternate across ISO years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{date, Parity};
+    /// assert_eq!(date!(2022 - 01 - 03).iso_week_parity(), Parity::Odd);
+    /// assert_eq!(date!(2022 - 01 - 10).iso_week_parity(), Parity::Even);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn iso_week_parity(&self) -> Parity {
+        Parity::of(self.iso_week().week() as i64)
+    }
+
+    /// Returns `true` if the ISO week number of this date is even.
+    ///
+    /// See [`Date::iso_week_parity`] for more information.
+    #[inline]
+    #[must_use]
+    pub const fn is_even_iso_week(&self) -> bool {
+        matches!(self.iso_week_parity(), Parity::Even)
+    }
+
+    /// Returns a new [`Date`] that points to the first day of the current month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-29).first_day_of_month(), date!(2020-02-01));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn first_day_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// Returns a new [`Date`] that points to the last day of the current month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-14).last_day_of_month(), date!(2020-02-29));
+    /// assert_eq!(date!(2021-02-14).last_day_of_month(), date!(2021-02-28));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn last_day_of_month(&self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..*self
+        }
+    }
+
+    /// Returns a new [`Date`] that points to the first day of the current year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-29).first_day_of_year(), date!(2020-01-01));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn first_day_of_year(&self) -> Self {
+        Self {
+            year: self.year,
+            month: 1,
+            day: 1,
+        }
+    }
+
+    /// Returns a new [`Date`] that points to the last day of the current year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-29).last_day_of_year(), date!(2020-12-31));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn last_day_of_year(&self) -> Self {
+        Self {
+            year: self.year,
+            month: 12,
+            day: 31,
+        }
+    }
+
+    /// Returns a new [`Date`] that points to the first day of the current quarter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-29).first_day_of_quarter(), date!(2020-01-01));
+    /// assert_eq!(date!(2021-11-15).first_day_of_quarter(), date!(2021-10-01));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn first_day_of_quarter(&self) -> Self {
+        Self {
+            year: self.year,
+            month: (self.quarter() - 1) * 3 + 1,
+            day: 1,
+        }
+    }
+
+    /// Returns a new [`Date`] that points to the last day of the current quarter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020-02-29).last_day_of_quarter(), date!(2020-03-31));
+    /// assert_eq!(date!(2021-04-01).last_day_of_quarter(), date!(2021-06-30));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn last_day_of_quarter(&self) -> Self {
+        let month = self.quarter() * 3;
+        Self {
+            year: self.year,
+            month,
+            day: days_in_month(self.year, month),
+        }
+    }
+
     /// Returns a new [`Date] that points to the given year.
     ///
     /// If the year causes the day to go out of bounds, then [`None`]
@@ -719,6 +1178,26 @@ impl Date {
         Some(self)
     }
 
+    /// Returns a new [`Date`] that points to the given ISO week, keeping the same
+    /// ISO year and weekday.
+    ///
+    /// If the week is out of bounds for the ISO year then [`None`] is returned.
+    /// Most ISO years have 52 weeks, while some have 53.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2020 - 01 - 01).with_iso_week(53), Some(date!(2020 - 12 - 30)));
+    /// assert_eq!(date!(2021 - 01 - 01).with_iso_week(1), Some(date!(2020 - 01 - 03)));
+    /// assert_eq!(date!(2021 - 06 - 01).with_iso_week(53), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_iso_week(self, week: u8) -> Option<Self> {
+        let iso = self.iso_week();
+        Self::from_iso_week(iso.year(), week, iso.weekday())
+    }
+
     /// Creates a date from the given year and ordinal date.
     ///
     /// If the ordinal is out of bounds (`1..=366`) then [`None`] is returned.
@@ -737,14 +1216,59 @@ impl Date {
     /// # Ok::<_, eos::Error>(())
     /// ```
     pub fn from_ordinal(year: i16, ordinal: u16) -> Option<Self> {
-        ensure_in_range!(ordinal, 1 => 366);
+        Self::try_from_ordinal(year, ordinal).ok()
+    }
+
+    /// Creates a date from the given year and ordinal date, reporting why it's invalid.
+    ///
+    /// This is the same as [`Date::from_ordinal`] except the error distinguishes between
+    /// the possible failures.
+    ///
+    /// # Errors
+    ///
+    /// If the ordinal is out of bounds (`1..=366`) then [`Error::OrdinalOutOfRange`] is returned.
+    /// If the ordinal is 366 and the year is not a leap year then [`Error::NotLeapYear`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{Date, Error, date};
+    /// assert_eq!(Date::try_from_ordinal(2012, 366), Ok(date!(2012-12-31)));
+    /// assert_eq!(Date::try_from_ordinal(2013, 366), Err(Error::NotLeapYear(2013)));
+    /// assert_eq!(Date::try_from_ordinal(2013, 0), Err(Error::OrdinalOutOfRange(0)));
+    /// assert_eq!(Date::try_from_ordinal(2013, 367), Err(Error::OrdinalOutOfRange(367)));
+    /// ```
+    pub fn try_from_ordinal(year: i16, ordinal: u16) -> Result<Self, Error> {
+        if !(1..=366).contains(&ordinal) {
+            return Err(Error::OrdinalOutOfRange(ordinal));
+        }
         if ordinal == 366 && !is_leap_year(year) {
-            return None;
+            return Err(Error::NotLeapYear(year));
         }
 
         let epoch = date_to_epoch_days(year, 1, 1) - 1 + ordinal as i32;
         let (year, month, day) = date_from_epoch_days(epoch);
-        Some(Self { year, month, day })
+        Ok(Self { year, month, day })
+    }
+
+    /// Creates a date from the given ISO week-numbering year, week, and weekday.
+    ///
+    /// If the week is out of bounds for the given year then [`None`] is returned.
+    /// Most years have 52 weeks, while some have 53. This is a shortcut for
+    /// [`IsoWeekDate::new`] followed by a conversion into [`Date`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{Date, Weekday, date};
+    /// assert_eq!(Date::from_iso_week(2009, 1, Weekday::Monday), Some(date!(2008-12-29)));
+    /// assert_eq!(Date::from_iso_week(2020, 53, Weekday::Friday), Some(date!(2021-1-1)));
+    /// assert_eq!(Date::from_iso_week(2021, 53, Weekday::Friday), None);
+    /// ```
+    #[must_use]
+    pub fn from_iso_week(year: i16, week: u8, weekday: Weekday) -> Option<Self> {
+        IsoWeekDate::new(year, week, weekday).map(Self::from)
     }
 }
 
@@ -827,9 +1351,22 @@ impl FromIsoFormat for Date {
     /// - `±YYYYY-Www` (e.g. `2012-W10`)
     /// - `±YYYYY-Www-D` (e.g. `2012-W10-1`)
     /// - `±YYYYY-DDD` (e.g. `2021-048`)
+    /// - `YYYYMMDD` (e.g. `20120213`)
+    /// - `YYYYWww` (e.g. `2012W10`)
+    /// - `YYYYWwwD` (e.g. `2012W101`)
+    /// - `YYYYDDD` (e.g. `2021048`)
+    ///
+    /// The separator-less basic formats only support four digit years and cannot be
+    /// mixed with the extended formats, e.g. `2012-0213` is an error.
+    ///
+    /// Any trailing characters result in [`ParseError::UnexpectedChar`].
     fn from_iso_format(s: &str) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
-        parser.parse_date()
+        let date = parser.parse_date()?;
+        match parser.advance() {
+            Some(c) => Err(ParseError::UnexpectedChar(c as char)),
+            None => Ok(date),
+        }
     }
 }
 
@@ -864,6 +1401,8 @@ impl FromIsoFormat for IsoWeekDate {
     ///
     /// - `±YYYYY-Www` (e.g. `2012-W10`)
     /// - `±YYYYY-Www-D` (e.g. `2012-W10-1`)
+    ///
+    /// Any trailing characters result in [`ParseError::UnexpectedChar`].
     fn from_iso_format(s: &str) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
         let year = parser.parse_year()?;
@@ -887,6 +1426,9 @@ impl FromIsoFormat for IsoWeekDate {
             },
             None => Weekday::Monday,
         };
+        if let Some(c) = parser.advance() {
+            return Err(ParseError::UnexpectedChar(c as char));
+        }
         Ok(Self { year, week, weekday })
     }
 }
@@ -897,6 +1439,140 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_ymd() -> Result<(), Error> {
+        assert_eq!(Date::ymd(2003, 4, 19)?, date!(2003 - 04 - 19));
+        assert_eq!(Date::ymd(2012, 2, 29)?, date!(2012 - 02 - 29));
+        assert_eq!(Date::ymd(i16::MIN, 1, 1)?, Date::MIN);
+        assert_eq!(Date::ymd(i16::MAX, 12, 31)?, Date::MAX);
+
+        assert_eq!(Date::ymd(2013, 2, 29), Err(Error::OutOfRange));
+        assert_eq!(Date::ymd(2013, 0, 1), Err(Error::OutOfRange));
+        assert_eq!(Date::ymd(2013, 13, 1), Err(Error::OutOfRange));
+        assert_eq!(Date::ymd(2013, 4, 0), Err(Error::OutOfRange));
+        assert_eq!(Date::ymd(2013, 4, 31), Err(Error::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_iso_week() {
+        // 2015 and 2020 have 53 weeks, 2019 and 2021 only have 52
+        for year in [2015, 2020] {
+            for weekday in WEEKDAYS {
+                let date = Date::from_iso_week(year, 53, weekday).unwrap();
+                assert_eq!(date.iso_week(), IsoWeekDate::new(year, 53, weekday).unwrap());
+            }
+        }
+
+        for year in [2019, 2021] {
+            assert!(Date::from_iso_week(year, 52, Weekday::Sunday).is_some());
+            assert!(Date::from_iso_week(year, 53, Weekday::Monday).is_none());
+        }
+
+        assert_eq!(
+            Date::from_iso_week(2020, 53, Weekday::Sunday),
+            Some(date!(2021 - 01 - 03))
+        );
+        assert_eq!(
+            Date::from_iso_week(2021, 1, Weekday::Monday),
+            Some(date!(2021 - 01 - 04))
+        );
+        assert_eq!(Date::from_iso_week(2021, 0, Weekday::Monday), None);
+        assert_eq!(Date::from_iso_week(2021, 54, Weekday::Monday), None);
+    }
+
+    #[test]
+    fn test_month_and_year_boundaries() {
+        for (date, last) in [
+            (date!(2020 - 02 - 10), date!(2020 - 02 - 29)),
+            (date!(2021 - 02 - 10), date!(2021 - 02 - 28)),
+            (date!(2000 - 02 - 29), date!(2000 - 02 - 29)),
+            (date!(1900 - 02 - 01), date!(1900 - 02 - 28)),
+            (date!(2021 - 04 - 30), date!(2021 - 04 - 30)),
+            (date!(2021 - 12 - 01), date!(2021 - 12 - 31)),
+        ] {
+            assert_eq!(date.first_day_of_month(), date.with_day(1).unwrap());
+            assert_eq!(date.last_day_of_month(), last);
+            assert_eq!(date.first_day_of_year(), Date::new(date.year(), 1, 1).unwrap());
+            assert_eq!(date.last_day_of_year(), Date::new(date.year(), 12, 31).unwrap());
+        }
+
+        assert_eq!(Date::MIN.first_day_of_year(), Date::MIN);
+        assert_eq!(Date::MAX.last_day_of_year(), Date::MAX);
+    }
+
+    #[test]
+    fn test_quarters() {
+        let quarters = [
+            (1, date!(2021 - 01 - 01), date!(2021 - 03 - 31)),
+            (2, date!(2021 - 04 - 01), date!(2021 - 06 - 30)),
+            (3, date!(2021 - 07 - 01), date!(2021 - 09 - 30)),
+            (4, date!(2021 - 10 - 01), date!(2021 - 12 - 31)),
+        ];
+
+        for month in 1..=12 {
+            let (quarter, first, last) = quarters[(month as usize - 1) / 3];
+            for day in [1, 15, days_in_month(2021, month)] {
+                let date = Date::new(2021, month, day).unwrap();
+                assert_eq!(date.quarter(), quarter);
+                assert_eq!(date.first_day_of_quarter(), first);
+                assert_eq!(date.last_day_of_quarter(), last);
+                assert!(first <= date && date <= last);
+            }
+        }
+
+        // Leap years only affect Q1
+        let leap = date!(2020 - 02 - 29);
+        assert_eq!(leap.quarter(), 1);
+        assert_eq!(leap.first_day_of_quarter(), date!(2020 - 01 - 01));
+        assert_eq!(leap.last_day_of_quarter(), date!(2020 - 03 - 31));
+        assert_eq!(date!(2020 - 03 - 31).first_day_of_quarter(), date!(2020 - 01 - 01));
+
+        assert_eq!(Date::MIN.first_day_of_quarter(), Date::MIN);
+        assert_eq!(Date::MAX.last_day_of_quarter(), Date::MAX);
+    }
+
+    #[test]
+    fn test_weekday_from_numbers() {
+        for weekday in WEEKDAYS {
+            assert_eq!(
+                Weekday::from_number_from_monday(weekday.number_from_monday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_number_from_sunday(weekday.number_from_sunday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_days_from_monday(weekday.days_from_monday()),
+                Some(weekday)
+            );
+            assert_eq!(
+                Weekday::from_days_from_sunday(weekday.days_from_sunday()),
+                Some(weekday)
+            );
+        }
+
+        assert_eq!(Weekday::from_number_from_monday(1), Some(Weekday::Monday));
+        assert_eq!(Weekday::from_number_from_monday(7), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_number_from_sunday(1), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_number_from_sunday(7), Some(Weekday::Saturday));
+        assert_eq!(Weekday::from_days_from_monday(0), Some(Weekday::Monday));
+        assert_eq!(Weekday::from_days_from_monday(6), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_days_from_sunday(0), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_days_from_sunday(6), Some(Weekday::Saturday));
+
+        for n in core::iter::once(0).chain(8..=u8::MAX) {
+            assert_eq!(Weekday::from_number_from_monday(n), None);
+            assert_eq!(Weekday::from_number_from_sunday(n), None);
+        }
+
+        for n in 7..=u8::MAX {
+            assert_eq!(Weekday::from_days_from_monday(n), None);
+            assert_eq!(Weekday::from_days_from_sunday(n), None);
+        }
+    }
+
     #[test]
     fn test_iso_week() {
         assert_eq!(
@@ -916,4 +1592,249 @@ mod tests {
             date!(2008 - 12 - 29)
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_elapsed() {
+        let today = Date::today_utc();
+        assert_eq!(today.elapsed(), Interval::ZERO);
+
+        // The date could change between calls if this runs right at midnight
+        let elapsed = today.add_days(-10).elapsed();
+        assert!(elapsed == Interval::from_days(10) || elapsed == Interval::from_days(11));
+
+        let past = today.add_months(-14);
+        assert_eq!(past.elapsed().total_months(), 14);
+
+        // Future dates return a negative interval
+        let elapsed = today.add_days(3).elapsed();
+        assert!(elapsed == Interval::from_days(-3) || elapsed == Interval::from_days(-2));
+        assert!(today.add_months(2).elapsed() < Interval::ZERO);
+    }
+
+    #[test]
+    fn test_weekdays_const() {
+        assert_eq!(WEEKDAYS.len(), 7);
+        assert_eq!(WEEKDAYS[0], Weekday::Monday);
+        for (index, weekday) in WEEKDAYS.into_iter().enumerate() {
+            assert_eq!(weekday.days_from_monday() as usize, index);
+            assert_eq!(weekday.number_from_monday() as usize, index + 1);
+            assert_eq!(WEEKDAYS[(index + 1) % 7], weekday.next());
+            assert_eq!(WEEKDAYS[(index + 6) % 7], weekday.prev());
+        }
+
+        // Every weekday is present exactly once
+        let mut date = date!(2022 - 01 - 03);
+        for weekday in WEEKDAYS {
+            assert_eq!(date.weekday(), weekday);
+            date = date.add_days(1);
+        }
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        // 2022-01-03 is a Monday
+        let monday = date!(2022 - 01 - 03);
+        let days = [0, 1, 2, 3, 4, 5, 6].map(|n| monday.add_days(n));
+        let weekend = days.map(|d| d.is_weekend());
+        assert_eq!(weekend, [false, false, false, false, false, true, true]);
+        for date in &days {
+            assert_eq!(date.is_weekday(), !date.is_weekend());
+        }
+
+        let friday_saturday = [Weekday::Friday, Weekday::Saturday];
+        let weekend = days.map(|d| d.is_weekend_with(&friday_saturday));
+        assert_eq!(weekend, [false, false, false, false, true, true, false]);
+
+        assert!(days.iter().all(|d| !d.is_weekend_with(&[])));
+    }
+
+    #[test]
+    fn test_years_since() {
+        let birthday = date!(1990 - 06 - 15);
+        assert_eq!(birthday.years_since(birthday), 0);
+        assert_eq!(date!(1991 - 06 - 14).years_since(birthday), 0);
+        assert_eq!(date!(1991 - 06 - 15).years_since(birthday), 1);
+        assert_eq!(date!(2022 - 01 - 01).years_since(birthday), 31);
+        assert_eq!(date!(1989 - 06 - 15).years_since(birthday), -1);
+        assert_eq!(date!(1989 - 06 - 16).years_since(birthday), 0);
+
+        // Leap day anniversaries are February 28th in non-leap years
+        let leap = date!(2000 - 02 - 29);
+        assert_eq!(date!(2001 - 02 - 27).years_since(leap), 0);
+        assert_eq!(date!(2001 - 02 - 28).years_since(leap), 1);
+        assert_eq!(date!(2001 - 03 - 01).years_since(leap), 1);
+        assert_eq!(date!(2003 - 02 - 28).years_since(leap), 3);
+        assert_eq!(date!(2004 - 02 - 28).years_since(leap), 3);
+        assert_eq!(date!(2004 - 02 - 29).years_since(leap), 4);
+        assert_eq!(date!(2100 - 02 - 28).years_since(leap), 100);
+
+        // Evaluating a leap day against an ordinary birthday
+        assert_eq!(date!(2004 - 02 - 29).years_since(date!(2003 - 03 - 01)), 0);
+        assert_eq!(date!(2004 - 02 - 29).years_since(date!(2003 - 02 - 28)), 1);
+
+        // The full range does not fit in an i16
+        assert_eq!(Date::MAX.years_since(Date::MIN), 65535);
+        assert_eq!(Date::MIN.years_since(Date::MAX), -65535);
+        assert_eq!(
+            Interval::between_dates(&Date::MIN, &Date::MAX).total_months(),
+            65535 * 12 + 11
+        );
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        let start = date!(2021 - 12 - 30);
+        let end = date!(2022 - 01 - 02);
+        let mut range = start.range_inclusive(end);
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.next(), Some(start));
+        assert_eq!(range.next_back(), Some(end));
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.next(), Some(date!(2021 - 12 - 31)));
+        assert_eq!(range.next_back(), Some(date!(2022 - 01 - 01)));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        // The last day of a leap month is yielded
+        let february = date!(2020 - 02 - 01);
+        let mut days = february.range_inclusive(february.last_day_of_month());
+        assert_eq!(days.nth(28), Some(date!(2020 - 02 - 29)));
+        assert_eq!(days.next(), None);
+
+        assert_eq!(start.range_inclusive(start).len(), 1);
+        assert_eq!(start.range_inclusive(start).next(), Some(start));
+        assert_eq!(end.range_inclusive(start).len(), 0);
+        assert_eq!(end.range_inclusive(start).next(), None);
+        assert_eq!(end.range_inclusive(start).next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_days_until() {
+        let start = date!(2021 - 12 - 30);
+        let end = date!(2022 - 01 - 02);
+
+        let mut days = start.iter_days_until(end);
+        assert_eq!(days.len(), start.days_since(end).unsigned_abs() as usize);
+        assert_eq!(days.next(), Some(start));
+        assert_eq!(days.next_back(), Some(date!(2022 - 01 - 01)));
+        assert_eq!(days.len(), 1);
+        assert_eq!(days.next(), Some(date!(2021 - 12 - 31)));
+        assert_eq!(days.next(), None);
+        assert_eq!(days.next_back(), None);
+
+        // Counting down still excludes the end
+        let mut days = end.iter_days_until(start);
+        assert_eq!(days.len(), 3);
+        assert_eq!(days.next(), Some(end));
+        assert_eq!(days.next_back(), Some(date!(2021 - 12 - 31)));
+        assert_eq!(days.next(), Some(date!(2022 - 01 - 01)));
+        assert_eq!(days.next(), None);
+        assert_eq!(days.next_back(), None);
+
+        let reversed: Vec<_> = end.iter_days_until(start).rev().collect();
+        let forward: Vec<_> = date!(2021 - 12 - 31).iter_days_until(date!(2022 - 01 - 03)).collect();
+        assert_eq!(reversed, forward);
+
+        assert_eq!(start.iter_days_until(start).len(), 0);
+        assert_eq!(start.iter_days_until(start).next(), None);
+    }
+
+    #[test]
+    fn test_iso_week_parity() {
+        // Consecutive weeks alternate, regardless of the weekday within the week
+        let mut date = date!(2022 - 01 - 03);
+        let mut expected = Parity::Odd;
+        for _ in 0..52 {
+            for day in date.iter_days_until(date.add_days(7)) {
+                assert_eq!(day.iso_week_parity(), expected, "{day}");
+                assert_eq!(day.is_even_iso_week(), expected == Parity::Even);
+            }
+            date = date.add_days(7);
+            expected = match expected {
+                Parity::Even => Parity::Odd,
+                Parity::Odd => Parity::Even,
+            };
+        }
+
+        // 2020 has 53 ISO weeks so the parity repeats into the next ISO year
+        assert_eq!(date!(2020 - 12 - 28).iso_week().week(), 53);
+        assert_eq!(date!(2020 - 12 - 28).iso_week_parity(), Parity::Odd);
+        assert_eq!(date!(2021 - 01 - 04).iso_week_parity(), Parity::Odd);
+
+        assert_eq!(Parity::of(-3), Parity::Odd);
+        assert_eq!(Parity::of(0), Parity::Even);
+    }
+
+    #[test]
+    fn test_with_iso_week() {
+        // 2020 is a 53 week ISO year starting on 2019-12-30
+        let first = date!(2019 - 12 - 30);
+        assert_eq!(first.iso_week(), IsoWeekDate::new(2020, 1, Weekday::Monday).unwrap());
+        let last = first.with_iso_week(53).unwrap();
+        assert_eq!(last, date!(2020 - 12 - 28));
+        assert_eq!(last.iso_week(), IsoWeekDate::new(2020, 53, Weekday::Monday).unwrap());
+        assert_eq!(last.with_iso_week(1), Some(first));
+
+        // The ISO year is kept even if the Gregorian year differs
+        let sunday = date!(2021 - 01 - 03);
+        assert_eq!(sunday.with_iso_week(1), Some(date!(2020 - 01 - 05)));
+        assert_eq!(sunday.with_iso_week(sunday.iso_week().week()), Some(sunday));
+
+        // 2021 only has 52 weeks
+        assert_eq!(date!(2021 - 01 - 04).with_iso_week(53), None);
+        assert_eq!(date!(2021 - 01 - 04).with_iso_week(52), Some(date!(2021 - 12 - 27)));
+        assert_eq!(first.with_iso_week(0), None);
+        assert_eq!(first.with_iso_week(54), None);
+
+        let dt = crate::datetime!(2020-01-01 12:30 +09:00);
+        let moved = dt.with_iso_week(53).unwrap();
+        assert_eq!(moved.date(), date!(2020 - 12 - 30));
+        assert_eq!(moved.time(), dt.time());
+        assert_eq!(moved.offset(), dt.offset());
+        assert_eq!(dt.with_iso_week(54), None);
+    }
+
+    #[test]
+    fn test_try_from_ordinal() {
+        assert_eq!(Date::try_from_ordinal(2020, 1), Ok(date!(2020 - 01 - 01)));
+        assert_eq!(Date::try_from_ordinal(2020, 366), Ok(date!(2020 - 12 - 31)));
+        assert_eq!(Date::try_from_ordinal(2021, 365), Ok(date!(2021 - 12 - 31)));
+
+        assert_eq!(Date::try_from_ordinal(2021, 366), Err(Error::NotLeapYear(2021)));
+        assert_eq!(Date::try_from_ordinal(1900, 366), Err(Error::NotLeapYear(1900)));
+        assert_eq!(Date::try_from_ordinal(2020, 0), Err(Error::OrdinalOutOfRange(0)));
+        assert_eq!(Date::try_from_ordinal(2020, 367), Err(Error::OrdinalOutOfRange(367)));
+        // The range is checked before the leap year
+        assert_eq!(Date::try_from_ordinal(2021, 400), Err(Error::OrdinalOutOfRange(400)));
+
+        for ordinal in [0, 1, 59, 60, 365, 366, 367] {
+            for year in [2020, 2021] {
+                assert_eq!(
+                    Date::from_ordinal(year, ordinal),
+                    Date::try_from_ordinal(year, ordinal).ok()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_const() {
+        const DATES: [Option<Date>; 4] = [
+            Date::new(2022, 1, 31),
+            Date::new(2020, 2, 29),
+            Date::new(2021, 2, 29),
+            Date::new(2021, 13, 1),
+        ];
+        const FIRST: Date = match Date::new(2022, 1, 31) {
+            Some(date) => date,
+            None => panic!("invalid date"),
+        };
+
+        assert_eq!(DATES[0], Some(FIRST));
+        assert_eq!(FIRST.day(), 31);
+        assert_eq!(DATES[1].map(|d| d.day()), Some(29));
+        assert_eq!(DATES[2], None);
+        assert_eq!(DATES[3], None);
+    }
 }
@@ -3,13 +3,14 @@ use crate::{
     timestamp::Timestamp,
     timezone::{Utc, UtcOffset},
     utils::divmod,
-    Date, Interval, IsoWeekDate, Time, TimeZone, Weekday,
+    Date, Error, Interval, IsoWeekDate, Parity, Time, TimeZone, Weekday,
 };
 
 use core::time::Duration;
 use core::{
     cmp::Ordering,
     fmt::Write,
+    hash::{Hash, Hasher},
     ops::{Add, Sub},
 };
 
@@ -25,15 +26,29 @@ use crate::fmt::{IsoFormatPrecision, ToIsoFormat};
 #[cfg(feature = "parsing")]
 use crate::fmt::{FromIsoFormat, ParseError, Parser};
 
+#[cfg(feature = "parsing")]
+use alloc::vec::Vec;
+
 /// An ISO 8601 combined date and time component.
 ///
 /// Unlike their individual components, [`DateTime`] have a timezone associated with them.
 /// For convenience, the methods of [`Time`] and [`Date`] are flattened and inherent methods
 /// of the struct. This means that methods such as [`second`] or [`month`] work as expected.
 ///
+/// Equality, ordering, and hashing are based on the instant in time rather than the
+/// local date and time. For example, `2022-01-01 03:00 +03:00` is equal to, and hashes the
+/// same as, `2022-01-01 00:00 UTC`. To compare the local date and time instead, see
+/// [`DateTime::cmp_without_tz`].
+///
+/// Leap seconds, i.e. times with a nanosecond component of at least one billion such as
+/// `23:59:60`, follow the UNIX time convention: the instant is the same as the second that
+/// follows it. For example, `2016-12-31 23:59:60` is equal to `2017-01-01 00:00:00` and
+/// `23:59:60.5` is equal to `00:00:00.5` of the following day. This matches how converting
+/// between timezones treats them. [`DateTime::cmp_without_tz`] still tells them apart.
+///
 /// [`second`]: DateTime::second
 /// [`month`]: DateTime::month
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub struct DateTime<Tz = Utc>
 where
     Tz: TimeZone,
@@ -84,6 +99,135 @@ impl DateTime<Utc> {
         }
     }
 
+    /// Parses a [`DateTime`] from an [HTTP date] string.
+    ///
+    /// The preferred IMF-fixdate format is accepted along with the two obsolete
+    /// formats that HTTP recipients are required to accept:
+    ///
+    /// - `Sun, 06 Nov 1994 08:49:37 GMT` (IMF-fixdate)
+    /// - `Sunday, 06-Nov-94 08:49:37 GMT` (RFC 850)
+    /// - `Sun Nov  6 08:49:37 1994` (ANSI C's `asctime`)
+    ///
+    /// The two digit years of the RFC 850 format are interpreted as `1970` to `2069`.
+    /// Like the other parsing functions, the weekday is not checked against the date.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime};
+    ///
+    /// let expected = datetime!(1994-11-06 08:49:37);
+    /// assert_eq!(DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT")?, expected);
+    /// assert_eq!(DateTime::from_http_date("Sunday, 06-Nov-94 08:49:37 GMT")?, expected);
+    /// assert_eq!(DateTime::from_http_date("Sun Nov  6 08:49:37 1994")?, expected);
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    ///
+    /// [HTTP date]: https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.1.1
+    #[cfg(feature = "parsing")]
+    pub fn from_http_date(s: &str) -> Result<Self, ParseError> {
+        use crate::fmt::{FormatSpec, FormatSpecKind};
+
+        fn parse_time(builder: &mut crate::Builder<UtcOffset>, parser: &mut Parser) -> Result<(), ParseError> {
+            builder.hour(parser.parse_two_digits()?);
+            parser.expect(b':')?;
+            builder.minute(parser.parse_two_digits()?);
+            parser.expect(b':')?;
+            let second = parser.parse_two_digits()?;
+            if second > 60 {
+                return Err(ParseError::OutOfBounds);
+            }
+            builder.second(second);
+            Ok(())
+        }
+
+        let mut parser = Parser::new(s);
+        let mut builder = crate::Builder::new().timezone(UtcOffset::UTC);
+        match s.as_bytes().get(3) {
+            // Sun, 06 Nov 1994 08:49:37 GMT
+            Some(b',') => {
+                FormatSpec::new(FormatSpecKind::AbbreviatedWeekday).parse_into(&mut builder, &mut parser)?;
+                parser.expect_str(b", ")?;
+                builder.day(parser.parse_two_digits()?);
+                parser.expect(b' ')?;
+                FormatSpec::new(FormatSpecKind::AbbreviatedMonth).parse_into(&mut builder, &mut parser)?;
+                parser.expect(b' ')?;
+                // The year is always exactly four digits
+                builder.year(parser.parse_two_digits()? as i16 * 100 + parser.parse_two_digits()? as i16);
+                parser.expect(b' ')?;
+                parse_time(&mut builder, &mut parser)?;
+                parser.expect_str(b" GMT")?;
+            }
+            // Sun Nov  6 08:49:37 1994
+            Some(b' ') => {
+                FormatSpec::new(FormatSpecKind::AbbreviatedWeekday).parse_into(&mut builder, &mut parser)?;
+                parser.expect(b' ')?;
+                FormatSpec::new(FormatSpecKind::AbbreviatedMonth).parse_into(&mut builder, &mut parser)?;
+                parser.expect(b' ')?;
+                // Single digit days are padded with a space
+                if parser.advance_if_equal(b' ').is_some() {
+                    builder.day(parser.parse_digit()?);
+                } else {
+                    builder.day(parser.parse_two_digits()?);
+                }
+                parser.expect(b' ')?;
+                parse_time(&mut builder, &mut parser)?;
+                parser.expect(b' ')?;
+                builder.year(parser.parse_two_digits()? as i16 * 100 + parser.parse_two_digits()? as i16);
+            }
+            // Sunday, 06-Nov-94 08:49:37 GMT
+            _ => {
+                FormatSpec::new(FormatSpecKind::FullWeekday).parse_into(&mut builder, &mut parser)?;
+                parser.expect_str(b", ")?;
+                builder.day(parser.parse_two_digits()?);
+                parser.expect(b'-')?;
+                FormatSpec::new(FormatSpecKind::AbbreviatedMonth).parse_into(&mut builder, &mut parser)?;
+                parser.expect(b'-')?;
+                let year = parser.parse_two_digits()? as i16;
+                builder.year(if year >= 70 { 1900 + year } else { 2000 + year });
+                parser.expect(b' ')?;
+                parse_time(&mut builder, &mut parser)?;
+                parser.expect_str(b" GMT")?;
+            }
+        }
+
+        if let Some(c) = parser.advance() {
+            return Err(ParseError::UnexpectedChar(c as char));
+        }
+
+        builder.fix_leap_seconds();
+        builder
+            .build()
+            .map(|dt| dt.with_timezone(Utc))
+            .map_err(|_| ParseError::OutOfBounds)
+    }
+
+    /// Formats this datetime as an [HTTP date], e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// This is the IMF-fixdate format used by HTTP headers such as `Date` and `Last-Modified`.
+    /// The fractional seconds are ignored. To format a datetime in another timezone,
+    /// convert it with [`DateTime::in_timezone`] first.
+    ///
+    /// The format requires a four digit year, so if the year is outside of `0..=9999`
+    /// then [`Error::OutOfRange`] is returned.
+    ///
+    /// ```
+    /// use eos::{datetime, Error};
+    ///
+    /// let dt = datetime!(1994-11-06 08:49:37);
+    /// assert_eq!(dt.to_http_date()?.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// assert!(dt.with_year(10000).unwrap().to_http_date().is_err());
+    /// # Ok::<_, Error>(())
+    /// ```
+    ///
+    /// [HTTP date]: https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.1.1
+    #[cfg(feature = "formatting")]
+    pub fn to_http_date(&self) -> Result<crate::fmt::HttpDateFormatter<'_>, Error> {
+        if (0..=9999).contains(&self.year()) {
+            Ok(crate::fmt::HttpDateFormatter { dt: self })
+        } else {
+            Err(Error::OutOfRange)
+        }
+    }
+
     /// Shifts the [`DateTime`] by the given [`UtcOffset`].
     ///
     /// Since this function does the operation in-place, this does not
@@ -184,12 +328,98 @@ impl DateTime<UtcOffset> {
     where
         T: AsRef<str>,
         S: AsRef<[crate::fmt::FormatSpec<'a>]>,
+    {
+        Self::parse_from_spec_iter(s, spec.as_ref().iter().copied().map(Ok))
+    }
+
+    /// Parses a [`DateTime`] by trying multiple slices of [`crate::fmt::FormatSpec`] in order.
+    ///
+    /// The first successful parse is returned. If every spec fails then the error
+    /// from the last spec is returned. If no specs are given then
+    /// [`ParseError::UnsupportedSpecifier`] is returned.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, fmt::format_spec};
+    ///
+    /// let specs: [&[_]; 2] = [
+    ///     &format_spec!("%Y-%m-%dT%H:%M:%S%o"),
+    ///     &format_spec!("%a, %d %b %Y %H:%M:%S %z"),
+    /// ];
+    /// let expected = datetime!(2022-01-28 10:15:30 -05:00);
+    /// assert_eq!(DateTime::parse_any("2022-01-28T10:15:30-05:00", &specs)?, expected);
+    /// assert_eq!(DateTime::parse_any("Fri, 28 Jan 2022 10:15:30 -0500", &specs)?, expected);
+    /// assert!(DateTime::parse_any("January 28th 2022", &specs).is_err());
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn parse_any<'a>(s: &str, specs: &[&[crate::fmt::FormatSpec<'a>]]) -> Result<Self, ParseError> {
+        let mut result = Err(ParseError::UnsupportedSpecifier);
+        for spec in specs {
+            result = Self::parse_from_spec(s, spec);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Parses many [`DateTime`] from an iterator of strings with a given slice of [`crate::fmt::FormatSpec`].
+    ///
+    /// Every string is parsed using [`DateTime::parse_from_spec`]. The successfully parsed
+    /// datetimes are returned in the first vector in order. The failures are returned in
+    /// the second vector alongside the index of the string that failed to parse.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, fmt::{format_spec, ParseError}};
+    ///
+    /// let input = ["2022-09-14 13:00", "2022-13-14 13:00", "2022-09-15 14:30"];
+    /// let (parsed, errors) = DateTime::parse_many(input, format_spec!("%Y-%m-%d %H:%M"));
+    /// assert_eq!(parsed, [datetime!(2022-09-14 13:00 +00:00), datetime!(2022-09-15 14:30 +00:00)]);
+    /// assert_eq!(errors, [(1, ParseError::OutOfBounds)]);
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn parse_many<'a, 'b, I, S>(iter: I, spec: S) -> (Vec<Self>, Vec<(usize, ParseError)>)
+    where
+        I: IntoIterator<Item = &'b str>,
+        S: AsRef<[crate::fmt::FormatSpec<'a>]>,
     {
         let spec = spec.as_ref();
+        let mut parsed = Vec::new();
+        let mut errors = Vec::new();
+        for (index, s) in iter.into_iter().enumerate() {
+            match Self::parse_from_spec(s, spec) {
+                Ok(dt) => parsed.push(dt),
+                Err(e) => errors.push((index, e)),
+            }
+        }
+        (parsed, errors)
+    }
+
+    /// Parses a [`DateTime`] with an iterator of [`crate::fmt::FormatSpec`].
+    ///
+    /// This is mainly meant to be used with [`crate::fmt::parse_spec_iter`] to avoid
+    /// allocating when the format string is only known at runtime. If the iterator
+    /// returns an error then [`ParseError::UnsupportedSpecifier`] is returned.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, fmt::parse_spec_iter};
+    ///
+    /// let format = String::from("%Y-%m-%d %H:%M");
+    /// let dt = DateTime::parse_from_spec_iter("2022-09-14 13:00", parse_spec_iter(&format));
+    /// assert_eq!(dt?, datetime!(2022-09-14 13:00 +00:00));
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn parse_from_spec_iter<'a, T, I>(s: T, spec: I) -> Result<Self, ParseError>
+    where
+        T: AsRef<str>,
+        I: IntoIterator<Item = Result<crate::fmt::FormatSpec<'a>, crate::fmt::Error>>,
+    {
         let mut parser = Parser::new(s.as_ref());
         let mut builder = crate::Builder::new().timezone(UtcOffset::UTC);
         for s in spec {
-            s.parse_into(&mut builder, &mut parser)?;
+            s.map_err(|_| ParseError::UnsupportedSpecifier)?
+                .parse_into(&mut builder, &mut parser)?;
         }
         builder.fix_leap_seconds();
         builder.build().map_err(|_| ParseError::OutOfBounds)
@@ -259,6 +489,52 @@ where
         Some(timezone.resolve(date, time).lenient())
     }
 
+    /// Creates a [`DateTime`] by combining a local date and time within the given timezone.
+    ///
+    /// The local date and time are resolved leniently. If the local time is ambiguous, then the
+    /// earlier time is used. If the local time is missing, i.e. it was skipped due to a
+    /// transition, then it is shifted forward by the length of the gap.
+    ///
+    /// This is equivalent to `timezone.resolve(date, time).lenient()`. If more control over the
+    /// resolution is needed then [`TimeZone::resolve`] should be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time, utc_offset, DateTime, Utc};
+    ///
+    /// let dt = DateTime::from_local(date!(2022-03-14), time!(10:30), Utc);
+    /// assert_eq!(dt, datetime!(2022-03-14 10:30));
+    ///
+    /// let dt = DateTime::from_local(date!(2022-03-14), time!(10:30), utc_offset!(-04:00));
+    /// assert_eq!(dt, datetime!(2022-03-14 10:30 -04:00));
+    /// assert_eq!(dt, datetime!(2022-03-14 14:30));
+    /// ```
+    pub fn from_local(date: Date, time: Time, timezone: Tz) -> Self {
+        timezone.resolve(date, time).lenient()
+    }
+
+    /// Creates a [`DateTime`] by combining a local date and time within the given timezone,
+    /// requiring the local time to refer to a single instant.
+    ///
+    /// This is the strict counterpart to [`DateTime::from_local`]. If the local time is
+    /// ambiguous then [`Error::AmbiguousDateTime`] is returned. If the local time is missing,
+    /// i.e. it was skipped due to a transition, then [`Error::SkippedDateTime`] is returned.
+    ///
+    /// This is equivalent to `timezone.resolve(date, time).exact()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time, utc_offset, DateTime};
+    ///
+    /// let dt = DateTime::from_local_unambiguous(date!(2022-03-14), time!(10:30), utc_offset!(-04:00));
+    /// assert_eq!(dt, Ok(datetime!(2022-03-14 10:30 -04:00)));
+    /// ```
+    pub fn from_local_unambiguous(date: Date, time: Time, timezone: Tz) -> Result<Self, Error> {
+        timezone.resolve(date, time).exact()
+    }
+
     /// Creates a [`DateTime`] from a POSIX timestamp in seconds, a nanosecond component, and a timezone.
     ///
     /// ```
@@ -281,6 +557,64 @@ where
         timezone.convert_utc(timestamp.to_utc())
     }
 
+    /// Creates a [`DateTime`] from the number of milliseconds since the UNIX epoch and a timezone.
+    ///
+    /// This is commonly used when interacting with JavaScript. Negative values represent
+    /// instants before the UNIX epoch.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, DateTime, Utc};
+    /// assert_eq!(DateTime::from_timestamp_millis(0, Utc), DateTime::UNIX_EPOCH);
+    /// assert_eq!(
+    ///     DateTime::from_timestamp_millis(1641173925123, Utc),
+    ///     datetime!(2022-01-03 1:38:45).with_millisecond(123).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     DateTime::from_timestamp_millis(-1500, utc_offset!(-05:00)),
+    ///     datetime!(1969-12-31 18:59:58 -05:00).with_millisecond(500).unwrap()
+    /// );
+    /// ```
+    pub fn from_timestamp_millis(millis: i64, timezone: Tz) -> Self {
+        Self::from_timestamp(Timestamp::from_milliseconds(millis), timezone)
+    }
+
+    /// Returns the current date and time in the given timezone.
+    ///
+    /// This is equivalent to `DateTime::utc_now().in_timezone(tz)`.
+    ///
+    /// ```no_run
+    /// use eos::{utc_offset, DateTime};
+    ///
+    /// let now = DateTime::now_in(utc_offset!(+09:00));
+    /// println!("{}", now);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn now_in(tz: Tz) -> Self {
+        DateTime::utc_now().in_timezone(tz)
+    }
+
+    /// Returns the [`Interval`] elapsed between this datetime and the current time.
+    ///
+    /// The current time is retrieved in the same timezone as this datetime. This is
+    /// equivalent to `DateTime::now_in(tz) - self`. If this datetime is in the future
+    /// then the returned interval is negative.
+    ///
+    /// ```no_run
+    /// use eos::{ext::IntervalLiteral, DateTime, Interval};
+    ///
+    /// let stored = DateTime::utc_now();
+    /// // ...
+    /// if stored.elapsed() > 5.minutes() {
+    ///     println!("cache expired");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn elapsed(&self) -> Interval {
+        Interval::between(self, &Self::now_in(self.timezone.clone()))
+    }
+
     /// Creates a [`DateTime`] representing the current day at midnight.
     #[cfg(feature = "std")]
     #[must_use]
@@ -299,18 +633,44 @@ where
         crate::fmt::DateTimeFormatter::new(self, spec)
     }
 
+    /// Formats this datetime into a [`String`] using a format string known at runtime.
+    ///
+    /// This is a shortcut for calling [`crate::fmt::parse_spec`] followed by
+    /// [`DateTime::format`]. If the format string is invalid then the error from
+    /// parsing it is returned.
+    ///
+    /// Check the [`crate::fmt`] module for more documentation.
+    ///
+    /// ```
+    /// use eos::{datetime, fmt::Error};
+    ///
+    /// let dt = datetime!(2022-09-14 13:00);
+    /// assert_eq!(dt.to_string_with("%Y-%m-%d %H:%M")?, "2022-09-14 13:00");
+    /// assert_eq!(dt.to_string_with("%L"), Err(Error::UnknownSpecifier(b'L')));
+    /// # Ok::<_, eos::fmt::Error>(())
+    /// ```
+    #[cfg(feature = "formatting")]
+    pub fn to_string_with(&self, format: &str) -> Result<String, crate::fmt::Error> {
+        let spec = crate::fmt::parse_spec(format)?;
+        Ok(self.format(spec).to_string())
+    }
+
     /// Formats this datetime using [RFC 3339] formatting rules.
     ///
     /// This is mostly the same as ISO-8601 except a space is used
     /// instead of a `T` for the separator between the date and time.
     /// Microsecond precision is used for the fractional component
     /// rather than nanoseconds. Likewise, the seconds component of
-    /// the UTC offset is always ignored.
+    /// the UTC offset is always ignored. For nanosecond precision, see
+    /// [`crate::fmt::Rfc3339Formatter::with_nanoseconds`].
     ///
     /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
     #[cfg(feature = "formatting")]
     pub fn to_rfc3339(&self) -> crate::fmt::Rfc3339Formatter<'_, Tz> {
-        crate::fmt::Rfc3339Formatter { dt: self }
+        crate::fmt::Rfc3339Formatter {
+            dt: self,
+            nanoseconds: false,
+        }
     }
 
     /// Returns the time component.
@@ -332,6 +692,28 @@ where
         self
     }
 
+    /// Returns a new [`DateTime`] with the time component of the interval added to the time,
+    /// wrapping around within the day without changing the date.
+    ///
+    /// This is the same as adding the interval to the [`Time`]. The months and days of the
+    /// interval are ignored. Unlike the `Add` implementation, overflowing past midnight
+    /// does not carry over into the date. Similar to [`DateTime::with_time`], this does not
+    /// do timezone conversion.
+    ///
+    /// ```
+    /// use eos::{datetime, ext::IntervalLiteral};
+    ///
+    /// let dt = datetime!(2021-01-01 23:00);
+    /// assert_eq!(dt.wrapping_add_time(2.hours()), datetime!(2021-01-01 01:00));
+    /// assert_eq!(dt + 2.hours(), datetime!(2021-01-02 01:00));
+    /// assert_eq!(dt.wrapping_add_time(1.days() + 30.minutes()), datetime!(2021-01-01 23:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wrapping_add_time(self, interval: Interval) -> Self {
+        let time = self.time + interval;
+        self.with_time(time)
+    }
+
     /// Returns a new [`DateTime`] with the newly specified [`Date`].
     #[must_use = "this returns the result of the operation, without modifying the original"]
     pub fn with_date(mut self, date: Date) -> Self {
@@ -360,6 +742,36 @@ where
         self.timezone.name(self.timestamp())
     }
 
+    /// Returns the [*fold*] of this date time if its local time is ambiguous.
+    ///
+    /// A fold of `0` means this is the earlier occurrence of the local time, before the
+    /// clocks went back, while a fold of `1` means this is the later occurrence. If the
+    /// local time only occurs once in the timezone then [`None`] is returned.
+    ///
+    /// Storing the fold along with the local date and time allows restoring the exact
+    /// date time later using [`crate::DateTimeResolution::with_fold`].
+    ///
+    /// [*fold*]: https://www.python.org/dev/peps/pep-0495/#terminology
+    #[must_use]
+    pub fn fold(&self) -> Option<u8> {
+        let resolution = self.timezone.resolve(self.date, self.time);
+        if resolution.is_ambiguous() {
+            Some(u8::from(self.offset != *resolution.earlier_offset()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the UNIX timestamp of when the current UTC offset took effect.
+    ///
+    /// This is the most recent transition of the timezone at or before this datetime.
+    /// If the timezone has no transitions, such as fixed offset timezones, then [`None`]
+    /// is returned. See [`TimeZone::previous_transition`] for more information.
+    #[must_use]
+    pub fn current_offset_since(&self) -> Option<Timestamp> {
+        self.timezone.previous_transition(self.timestamp())
+    }
+
     /// Compares two datetime instances that do not share a timezone.
     ///
     /// Due to [a limitation][bad-ord] with the Rust [`Ord`] trait, this cannot be implemented
@@ -372,46 +784,8 @@ where
     where
         OtherTz: TimeZone,
     {
-        if self.offset == other.offset {
-            return (self.date, self.time).cmp(&(other.date, other.time));
-        }
-
-        // Get how many days the two dates differ by and also whether
-        // they are the same "moment" after accounting for their UTC offsets
-        let (days, same) = {
-            // Initially this is just a difference in days via epoch
-            // If the UTC offsets at that date resolve to the same one, then they're on the same
-            // date and the calculation is correct
-            let days = self.date().days_since_epoch() - other.date().days_since_epoch();
-            if self.offset == other.offset {
-                (days, true)
-            } else {
-                // If they're not, then we need to take into consideration the time component.
-                // A time component is essentially constrained to 24 hours
-                // So the difference in seconds between two of them won't go too much over
-                // 24 hours, we can use this to our advantage because by the same virtue
-                // offsets are also only within 24 hour bounds, making this a rather simple
-                // second-wise addition
-                let delta_offsets = other.offset.total_seconds() - self.offset.total_seconds();
-                let seconds = self.time.total_seconds() - other.time.total_seconds() + delta_offsets;
-
-                let (d, s) = divmod!(seconds, 86_400);
-                (
-                    days + d,
-                    s == 0 && self.time().nanosecond() == other.time().nanosecond(),
-                )
-            }
-        };
-
-        // If the number of days we differ by is negative then lhs is less than rhs by virtue
-
-        if days < 0 {
-            Ordering::Less
-        } else if days == 0 && same {
-            Ordering::Equal
-        } else {
-            Ordering::Greater
-        }
+        // Leap seconds are folded into the next second by the timestamp
+        self.timestamp().cmp(&other.timestamp())
     }
 
     /// Compares two datetime instances without caring about their timezone information.
@@ -429,6 +803,57 @@ where
         self.time.cmp(&other.time)
     }
 
+    /// Restricts this datetime to the given bounds, which may be in different timezones.
+    ///
+    /// The bounds are compared with [`DateTime::cmp_cross_timezone`], meaning that this
+    /// compares the instants in time rather than the wall-clock date and time. If this
+    /// datetime is outside the bounds then the bound is converted to this datetime's
+    /// timezone using [`DateTime::in_timezone`]. Otherwise, `self` is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is later than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset};
+    ///
+    /// // 13:00 to 21:00 UTC
+    /// let min = datetime!(2022-03-14 09:00 -04:00);
+    /// let max = datetime!(2022-03-15 06:00 +09:00);
+    ///
+    /// assert_eq!(datetime!(2022-03-14 15:00).clamp_between(min, max), datetime!(2022-03-14 15:00));
+    ///
+    /// let clamped = datetime!(2022-03-14 12:00).clamp_between(min, max);
+    /// assert_eq!(clamped, datetime!(2022-03-14 13:00));
+    /// assert_eq!(clamped.offset(), utc_offset!(00:00));
+    ///
+    /// let clamped = datetime!(2022-03-14 23:00 -01:00).clamp_between(min, max);
+    /// assert_eq!(clamped, datetime!(2022-03-14 20:00 -01:00));
+    /// assert_eq!(clamped.offset(), utc_offset!(-01:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn clamp_between<A, B>(self, min: DateTime<A>, max: DateTime<B>) -> Self
+    where
+        A: TimeZone,
+        B: TimeZone,
+    {
+        assert!(
+            min.cmp_cross_timezone(&max) != Ordering::Greater,
+            "min must not be later than max"
+        );
+        if self.cmp_cross_timezone(&min) == Ordering::Less {
+            let timezone = self.timezone;
+            min.in_timezone(timezone)
+        } else if self.cmp_cross_timezone(&max) == Ordering::Greater {
+            let timezone = self.timezone;
+            max.in_timezone(timezone)
+        } else {
+            self
+        }
+    }
+
     /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
     ///
     /// # Panics
@@ -477,6 +902,35 @@ where
         self.checked_duration_since(earlier).unwrap_or_default()
     }
 
+    /// Returns the amount of time elapsed since this datetime as a [`Duration`].
+    ///
+    /// This is equivalent to `DateTime::utc_now().saturating_duration_since(self)`, similar
+    /// to [`std::time::Instant::elapsed`]. If this datetime is in the future then
+    /// [`Duration::ZERO`] is returned. To get a signed [`Interval`] instead, see
+    /// [`DateTime::elapsed`].
+    ///
+    /// Note that unlike [`std::time::Instant`], the system clock is not monotonic so
+    /// this is not suitable for precise measurements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::DateTime;
+    /// # use std::time::Duration;
+    ///
+    /// let start = DateTime::utc_now();
+    /// let elapsed = start.elapsed_duration();
+    /// assert!(elapsed < Duration::from_secs(60));
+    ///
+    /// let future = start + Duration::from_secs(3600);
+    /// assert_eq!(future.elapsed_duration(), Duration::ZERO);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn elapsed_duration(&self) -> Duration {
+        DateTime::utc_now().saturating_duration_since(self)
+    }
+
     /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
     /// If `earlier` is later than `self` then [`None`] is returned.
     ///
@@ -490,18 +944,21 @@ where
     /// let later = datetime!(2022-01-01 13:10);
     /// assert_eq!(later.checked_duration_since(&earlier), Some(Duration::from_secs(3600)));
     /// assert_eq!(earlier.checked_duration_since(&later), None);
+    ///
+    /// // Spans longer than 68 years do not overflow
+    /// let epoch = datetime!(1900-01-01 00:00);
+    /// assert_eq!(later.checked_duration_since(&epoch), Some(Duration::from_secs(3_850_031_400)));
     /// ```
     #[must_use]
     pub fn checked_duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Option<Duration>
     where
         OtherTz: TimeZone,
     {
-        let days = self.days_since_epoch() - earlier.days_since_epoch();
-        let mut total_seconds = days * 86400 + self.time.total_seconds() - earlier.time.total_seconds()
-            + earlier.offset.total_seconds()
-            - self.offset.total_seconds();
-        let (secs, nanos) = divmod!(self.nanosecond() as i64 - earlier.nanosecond() as i64, 1_000_000_000);
-        total_seconds += secs as i32;
+        // This is done using 64-bit timestamps since the number of seconds
+        // between two dates can easily overflow a 32-bit integer
+        let (this, other) = (self.timestamp(), earlier.timestamp());
+        let (secs, nanos) = divmod!(this.nanoseconds as i64 - other.nanoseconds as i64, 1_000_000_000);
+        let total_seconds = this.as_seconds() - other.as_seconds() + secs;
         if total_seconds < 0 {
             None
         } else {
@@ -568,6 +1025,15 @@ where
     ///     println!("{}", dt);
     /// }
     /// ```
+    ///
+    /// Getting the same day every month, clamped to the end of shorter months:
+    ///
+    /// ```ignore
+    /// let start = datetime!(2024-01-31 09:00);
+    /// for dt in start.every(Interval::ZERO).monthly().into_iter().take(12) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
     pub fn every(self, interval: Interval) -> crate::iter::Every<Tz> {
         crate::iter::Every::new(self, interval)
     }
@@ -599,6 +1065,29 @@ where
         timezone.convert_utc(self.into_utc())
     }
 
+    /// Returns a new [`DateTime`] with the newly specified [`TimeZone`] by reference,
+    /// adjusting the date and time components to point to the same internal UTC
+    /// time but in the given timezone's local time.
+    ///
+    /// This is the same as [`DateTime::in_timezone`] except the timezone is borrowed
+    /// and only cloned into the resulting datetime. This is useful for timezones that
+    /// are not [`Copy`] and are reused for multiple conversions.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset};
+    ///
+    /// let tz = utc_offset!(-05:00);
+    /// let dt = datetime!(2022-01-01 12:00).in_timezone_ref(&tz);
+    /// assert_eq!(dt, datetime!(2022-01-01 07:00 -05:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn in_timezone_ref<OtherTz>(self, timezone: &OtherTz) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        timezone.convert_utc(self.into_utc())
+    }
+
     /// Returns a new [`DateTime`] with the timezone component changed.
     /// This attempts to retain the local date and time as much as possible.
     /// The local date time is only changed if the date time cannot represented
@@ -614,6 +1103,27 @@ where
         timezone.resolve(self.date, self.time).lenient()
     }
 
+    /// Returns a new [`DateTime`] with the timezone component changed by reference.
+    /// This attempts to retain the local date and time as much as possible.
+    ///
+    /// This is the same as [`DateTime::with_timezone`] except the timezone is borrowed
+    /// and only cloned into the resulting datetime.
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset};
+    ///
+    /// let tz = utc_offset!(-05:00);
+    /// let dt = datetime!(2022-01-01 12:00).with_timezone_ref(&tz);
+    /// assert_eq!(dt, datetime!(2022-01-01 12:00 -05:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_timezone_ref<OtherTz>(self, timezone: &OtherTz) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        timezone.resolve(self.date, self.time).lenient()
+    }
+
     /// Returns a new [`DateTime`] with the newly specified [`TimeZone`],
     /// adjusting the date and time components to point to the same internal UTC
     /// time but in the given timezone's local time.
@@ -628,9 +1138,21 @@ where
     }
 
     /// Returns the UNIX timestamp.
+    ///
+    /// Like UNIX time, a leap second is counted as the second that follows it. For example,
+    /// `2016-12-31 23:59:60` has the same timestamp as `2017-01-01 00:00:00`.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let leap = datetime!(2016-12-31 23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    /// let next = datetime!(2017-01-01 00:00:00).with_nanosecond(500_000_000).unwrap();
+    /// assert_eq!(leap.timestamp(), next.timestamp());
+    /// assert_eq!(leap.timestamp().as_seconds(), 1483228800);
+    /// ```
     #[must_use]
     pub fn timestamp(&self) -> Timestamp {
-        let seconds = self.days_since_epoch() as i64 * 86400
+        let mut seconds = self.days_since_epoch() as i64 * 86400
             + self.hour() as i64 * 3600
             + self.minute() as i64 * 60
             + self.second() as i64
@@ -638,7 +1160,30 @@ where
             // e.g. UTC-5 means we need to add +5 to get back to UTC.
             - self.offset.total_seconds() as i64;
 
-        Timestamp::new(seconds, self.nanosecond())
+        let mut nanoseconds = self.nanosecond();
+        if nanoseconds >= 1_000_000_000 {
+            seconds += 1;
+            nanoseconds -= 1_000_000_000;
+        }
+        Timestamp::new(seconds, nanoseconds)
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch.
+    ///
+    /// This is commonly used when interacting with JavaScript. Any sub-millisecond
+    /// precision is truncated.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(1970-01-01 00:00:01).timestamp_millis(), 1000);
+    /// assert_eq!(datetime!(2022-01-02 20:38:45 -5:00).timestamp_millis(), 1641173925000);
+    /// assert_eq!(datetime!(1969-12-31 23:59:59).with_millisecond(500).unwrap().timestamp_millis(), -500);
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> i64 {
+        let ts = self.timestamp();
+        ts.as_seconds() * 1_000 + (ts.nanoseconds / 1_000_000) as i64
     }
 
     pub(crate) fn add_months(mut self, months: i32) -> Self {
@@ -688,6 +1233,24 @@ where
         self.date.month()
     }
 
+    /// Returns the calendar quarter.
+    ///
+    /// This value will always be within `1..=4`. Quarters start in January, April,
+    /// July, and October.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let date = datetime!(2012-08-15 00:00);
+    /// assert_eq!(date.quarter(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn quarter(&self) -> u8 {
+        self.date.quarter()
+    }
+
     /// Returns the day.
     ///
     /// This value will always be within `1..=31`.
@@ -755,6 +1318,60 @@ where
         self.date.weekday()
     }
 
+    /// Returns the number of completed years since the given datetime.
+    ///
+    /// This is the number of whole years in the [`Interval`] between the two datetimes,
+    /// so the time of day is taken into consideration when the anniversary is reached.
+    /// Like [`Date::years_since`], if `earlier` is on a leap day then the anniversary in
+    /// a non-leap year is considered to be February 28th.
+    ///
+    /// If `earlier` is after this datetime then the result is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let birth = datetime!(2000-02-29 12:00);
+    /// assert_eq!(datetime!(2001-02-28 11:59).years_since(&birth), 0);
+    /// assert_eq!(datetime!(2001-02-28 12:00).years_since(&birth), 1);
+    /// assert_eq!(datetime!(2004-02-29 12:00).years_since(&birth), 4);
+    /// ```
+    #[must_use]
+    pub fn years_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> i32
+    where
+        OtherTz: TimeZone,
+    {
+        // Interval::years is not used since it truncates to an i16
+        Interval::between(earlier, self).total_months() / 12
+    }
+
+    /// Returns `true` if this datetime falls on a Saturday or Sunday in its local time.
+    ///
+    /// See [`Date::is_weekend`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn is_weekend(&self) -> bool {
+        self.date.is_weekend()
+    }
+
+    /// Returns `true` if this datetime falls on a Monday through Friday in its local time.
+    ///
+    /// See [`Date::is_weekday`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn is_weekday(&self) -> bool {
+        self.date.is_weekday()
+    }
+
+    /// Returns `true` if this datetime falls on one of the given weekend days in its local time.
+    ///
+    /// See [`Date::is_weekend_with`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn is_weekend_with(&self, weekend: &[Weekday]) -> bool {
+        self.date.is_weekend_with(weekend)
+    }
+
     /// Returns a [`DateTime`] moved to the next step of the given value.
     ///
     /// # Examples
@@ -882,6 +1499,201 @@ where
         self.date.iso_week()
     }
 
+    /// Returns whether the ISO week number of this datetime is even or odd.
+    ///
+    /// See [`Date::iso_week_parity`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn iso_week_parity(&self) -> Parity {
+        self.date.iso_week_parity()
+    }
+
+    /// Returns `true` if the ISO week number of this datetime is even.
+    ///
+    /// This is useful for alternating week schedules, e.g. something that only
+    /// happens on odd ISO weeks.
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// assert!(!datetime!(2022-01-03 09:00).is_even_iso_week());
+    /// assert!(datetime!(2022-01-10 09:00).is_even_iso_week());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_even_iso_week(&self) -> bool {
+        self.date.is_even_iso_week()
+    }
+
+    /// Resolves the local date and time again after they were changed in place.
+    ///
+    /// Fixed timezones keep their offset while others might need a new one, e.g. if the
+    /// date moved across a DST transition.
+    fn re_resolve(self) -> Self {
+        if self.timezone.is_fixed() {
+            self
+        } else {
+            self.timezone.resolve(self.date, self.time).lenient()
+        }
+    }
+
+    /// Returns a new [`DateTime`] pointing to the first day of the current quarter at midnight.
+    ///
+    /// Quarters start in January, April, July, and October. The resulting datetime
+    /// is resolved again within the timezone.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2020-02-29 10:30).start_of_quarter(), datetime!(2020-01-01 00:00));
+    /// assert_eq!(datetime!(2021-11-15 23:59).start_of_quarter(), datetime!(2021-10-01 00:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn start_of_quarter(mut self) -> Self {
+        self.date = self.date.first_day_of_quarter();
+        self.time = Time::MIDNIGHT;
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] pointing to the last day of the current quarter
+    /// at the end of the day.
+    ///
+    /// Quarters end in March, June, September, and December. The end of the day is
+    /// [`Time::MAX`]. The resulting datetime is resolved again within the timezone.
+    ///
+    /// ```
+    /// use eos::{datetime, Time};
+    ///
+    /// let end = datetime!(2020-02-29 10:30).end_of_quarter();
+    /// assert_eq!(end.date(), datetime!(2020-03-31 00:00).date());
+    /// assert_eq!(end.time(), Time::MAX);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_quarter(mut self) -> Self {
+        self.date = self.date.last_day_of_quarter();
+        self.time = Time::MAX;
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] pointing to the first day of the current month.
+    ///
+    /// The time is preserved. The resulting datetime is resolved again within the timezone
+    /// in case the local time does not exist on that day.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2020-02-29 10:30).first_day_of_month(), datetime!(2020-02-01 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn first_day_of_month(mut self) -> Self {
+        self.date = self.date.first_day_of_month();
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] pointing to the last day of the current month.
+    ///
+    /// The time is preserved. The resulting datetime is resolved again within the timezone
+    /// in case the local time does not exist on that day.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2021-02-14 10:30).last_day_of_month(), datetime!(2021-02-28 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn last_day_of_month(mut self) -> Self {
+        self.date = self.date.last_day_of_month();
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] pointing to the first day of the current year.
+    ///
+    /// The time is preserved. The resulting datetime is resolved again within the timezone
+    /// in case the local time does not exist on that day.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2020-02-29 10:30).first_day_of_year(), datetime!(2020-01-01 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn first_day_of_year(mut self) -> Self {
+        self.date = self.date.first_day_of_year();
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] pointing to the last day of the current year.
+    ///
+    /// The time is preserved. The resulting datetime is resolved again within the timezone
+    /// in case the local time does not exist on that day.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2020-02-29 10:30).last_day_of_year(), datetime!(2020-12-31 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn last_day_of_year(mut self) -> Self {
+        self.date = self.date.last_day_of_year();
+        self.re_resolve()
+    }
+
+    /// Adds the given [`Interval`] and reports whether the UTC offset changed as a result.
+    ///
+    /// This behaves the same as the `+` operator. The returned boolean is `true` if the
+    /// resulting datetime has a different UTC offset than this one, i.e. a DST transition
+    /// was crossed. This is useful for warning about recurring events that land across
+    /// a transition. Fixed offset timezones never report a change.
+    ///
+    /// ```
+    /// use eos::{datetime, ext::IntervalLiteral};
+    ///
+    /// let (dt, crossed) = datetime!(2021-03-13 12:00).add_reporting_dst(1.days());
+    /// assert_eq!(dt, datetime!(2021-03-14 12:00));
+    /// assert!(!crossed);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_reporting_dst(self, interval: Interval) -> (Self, bool) {
+        let offset = self.offset;
+        let result = self + interval;
+        let crossed = result.offset != offset;
+        (result, crossed)
+    }
+
+    /// Returns a new [`DateTime`] with the same wall-clock time on the next day.
+    ///
+    /// Unlike adding 24 hours of elapsed time, the resulting datetime is resolved
+    /// again within the timezone. Therefore if a DST transition happens in between
+    /// the instant differs by 23 or 25 hours while the local time is preserved. If
+    /// the local time does not exist on the next day, then it is resolved leniently.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2021-12-31 10:30).tomorrow(), datetime!(2022-01-01 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn tomorrow(mut self) -> Self {
+        self.date = self.date.add_days(1);
+        self.re_resolve()
+    }
+
+    /// Returns a new [`DateTime`] with the same wall-clock time on the previous day.
+    ///
+    /// See [`DateTime::tomorrow`] for more information on how DST transitions
+    /// are handled.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2022-01-01 10:30).yesterday(), datetime!(2021-12-31 10:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn yesterday(mut self) -> Self {
+        self.date = self.date.add_days(-1);
+        self.re_resolve()
+    }
+
     /// Returns a new [`DateTime`] with the date pointing to the given year.
     ///
     /// If the year causes the day to go out of bounds, then [`None`]
@@ -928,6 +1740,17 @@ where
         Some(self)
     }
 
+    /// Returns a new [`DateTime`] that points to the given ISO week, keeping the same
+    /// ISO year, weekday, and time.
+    ///
+    /// If the week is out of bounds for the ISO year then [`None`] is returned.
+    /// See [`Date::with_iso_week`] for more information.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_iso_week(mut self, week: u8) -> Option<Self> {
+        self.date = self.date.with_iso_week(week)?;
+        Some(self)
+    }
+
     /// Returns the hour.
     ///
     /// This value will always be within `0..24`.
@@ -1049,12 +1872,22 @@ where
     }
 }
 
+/// Displays the [`DateTime`] in ISO-8601 format.
+///
+/// The alternate form (`{:#}`) additionally appends the name of the time zone, if it
+/// has one, separated by a space, e.g. `2021-11-07T01:30:00-05:00 EST`.
 impl<Tz> core::fmt::Display for DateTime<Tz>
 where
     Tz: TimeZone,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}T{}{}", self.date, self.time, self.offset)
+        write!(f, "{}T{}{}", self.date, self.time, self.offset)?;
+        if f.alternate() {
+            if let Some(name) = self.tzname() {
+                write!(f, " {}", name)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -1067,7 +1900,8 @@ where
         let mut buffer = String::with_capacity(40);
         write!(&mut buffer, "{}", &self.date).expect("unexpected error when writing string");
         buffer.push('T');
-        crate::time::fmt_iso_time(&mut buffer, &self.time, precision).expect("unexpected error when writing string");
+        crate::time::fmt_iso_time(&mut buffer, &self.time, precision, '.')
+            .expect("unexpected error when writing string");
         write!(&mut buffer, "{}", self.offset).expect("unexpected error when writing string");
         buffer
     }
@@ -1091,57 +1925,26 @@ impl FromIsoFormat for DateTime<UtcOffset> {
     /// - `±HH:MM:SS` (e.g. `+12:23:45`).
     /// - `Z` (represents UTC)
     ///
+    /// This is the same syntax accepted by [`UtcOffset`]'s [`FromIsoFormat`] implementation.
+    ///
     /// Note that strict ISO-8601 compliance would forbid the seconds component and would
     /// make the `:` optional. This function does not currently accept such syntax.
+    ///
+    /// Any trailing characters result in [`ParseError::UnexpectedChar`].
     fn from_iso_format(s: &str) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
         let date = parser.parse_date()?;
         parser.expect(b'T')?;
         let time = parser.parse_time()?;
-        let offset = if let None | Some(b'Z') = parser.peek() {
-            UtcOffset::UTC
-        } else {
-            let negative = parser.parse_required_sign()?;
-            let hours = parser.parse_two_digits()? as i8;
-            if hours > 23 {
-                return Err(ParseError::OutOfBounds);
-            }
-
-            let (minutes, seconds) = match parser.advance_if_equal(b':') {
-                Some(_) => {
-                    let minute = parser.parse_two_digits()? as i8;
-                    if minute > 59 {
-                        return Err(ParseError::OutOfBounds);
-                    }
-                    match parser.advance_if_equal(b':') {
-                        Some(_) => {
-                            let second = parser.parse_two_digits()? as i8;
-                            if second > 59 {
-                                return Err(ParseError::OutOfBounds);
-                            }
-                            (minute, second)
-                        }
-                        None => (minute, 0),
-                    }
-                }
-                None => (0, 0),
-            };
-
-            if negative {
-                UtcOffset {
-                    hours: -hours,
-                    minutes: -minutes,
-                    seconds: -seconds,
-                }
-            } else {
-                UtcOffset {
-                    hours,
-                    minutes,
-                    seconds,
-                }
-            }
+        let offset = match parser.peek() {
+            None => UtcOffset::UTC,
+            Some(_) => parser.parse_utc_offset()?,
         };
 
+        if let Some(c) = parser.advance() {
+            return Err(ParseError::UnexpectedChar(c as char));
+        }
+
         Ok(Self {
             date,
             time,
@@ -1223,6 +2026,16 @@ where
     }
 }
 
+// This must be consistent with PartialEq, which compares the instant in time
+impl<Tz> Hash for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp().hash(state);
+    }
+}
+
 // Rust does not allow Ord<Rhs> for some reason
 // see: https://github.com/rust-lang/rfcs/issues/2511
 impl<Tz> Ord for DateTime<Tz>
@@ -1318,7 +2131,7 @@ where
 #[allow(clippy::eq_op)]
 mod tests {
     use super::*;
-    use crate::{datetime, utc_offset};
+    use crate::{datetime, gregorian::days_in_month, utc_offset};
 
     #[test]
     fn test_regular_comparisons() {
@@ -1375,8 +2188,280 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_millis() {
+        let cases = [
+            (0, datetime!(1970-01-01 00:00)),
+            (1, datetime!(1970-01-01 00:00).with_millisecond(1).unwrap()),
+            (-1, datetime!(1969-12-31 23:59:59).with_millisecond(999).unwrap()),
+            (-1000, datetime!(1969-12-31 23:59:59)),
+            (-1001, datetime!(1969-12-31 23:59:58).with_millisecond(999).unwrap()),
+            (-86_400_000, datetime!(1969-12-31 00:00)),
+            (-14_182_940_000, datetime!(1969-07-20 20:17:40)),
+            (
+                1_641_173_925_123,
+                datetime!(2022-01-03 1:38:45).with_millisecond(123).unwrap(),
+            ),
+        ];
+
+        for (millis, expected) in cases {
+            assert_eq!(DateTime::from_timestamp_millis(millis, Utc), expected);
+            assert_eq!(expected.timestamp_millis(), millis);
+
+            let offset = utc_offset!(-05:30);
+            let local = DateTime::from_timestamp_millis(millis, offset);
+            assert_eq!(local, expected);
+            assert_eq!(local.offset(), offset);
+            assert_eq!(local.timestamp_millis(), millis);
+        }
+
+        // Sub-millisecond precision is truncated
+        let dt = datetime!(2022-01-03 1:38:45 +02:00)
+            .with_nanosecond(123_999_999)
+            .unwrap();
+        assert_eq!(dt.timestamp_millis(), 1_641_166_725_123);
+        let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(999_999).unwrap();
+        assert_eq!(dt.timestamp_millis(), -1000);
+    }
+
     #[test]
     fn test_tzname() {
         assert_eq!(DateTime::UNIX_EPOCH.tzname(), Some("UTC"));
     }
+
+    #[test]
+    fn test_quarter_boundaries() {
+        let dt = datetime!(2020-02-29 13:45:10);
+        assert_eq!(dt.start_of_quarter(), datetime!(2020-01-01 00:00));
+        let end = dt.end_of_quarter();
+        assert_eq!(end.date(), datetime!(2020-03-31 00:00).date());
+        assert_eq!(end.time(), Time::MAX);
+
+        let dt = datetime!(2020-01-01 00:00);
+        assert_eq!(dt.start_of_quarter(), dt);
+        assert_eq!(datetime!(2020-03-31 23:59:59).end_of_quarter().date(), end.date());
+
+        for (month, start, end) in [(5, 4, 6), (7, 7, 9), (12, 10, 12)] {
+            let dt = datetime!(2021-01-15 12:00 +05:00).with_month(month).unwrap();
+            let quarter_start = dt.start_of_quarter();
+            assert_eq!(quarter_start.month(), start);
+            assert_eq!(quarter_start.day(), 1);
+            assert_eq!(quarter_start.time(), Time::MIDNIGHT);
+            assert_eq!(quarter_start.offset(), utc_offset!(+05:00));
+
+            let quarter_end = dt.end_of_quarter();
+            assert_eq!(quarter_end.month(), end);
+            assert_eq!(quarter_end.day(), days_in_month(2021, end));
+            assert_eq!(quarter_end.time(), Time::MAX);
+        }
+    }
+
+    #[test]
+    fn test_from_local() {
+        let local = datetime!(2021-11-07 1:30 am);
+        let (date, time) = (local.date(), local.time());
+
+        let dt = DateTime::from_local(date, time, Utc);
+        assert_eq!(dt, datetime!(2021-11-07 1:30 am));
+        assert_eq!(dt.offset(), UtcOffset::UTC);
+
+        let offset = utc_offset!(+09:00);
+        let dt = DateTime::from_local(date, time, offset);
+        assert_eq!(dt, datetime!(2021-11-07 1:30 am +09:00));
+        assert_eq!(dt.date(), date);
+        assert_eq!(dt.time(), time);
+        assert_eq!(dt.offset(), offset);
+        assert_eq!(dt, datetime!(2021-11-06 4:30 pm));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash_consistent_with_eq() {
+        use std::collections::HashSet;
+
+        let local = datetime!(2022-01-01 03:00 +03:00);
+        let utc = datetime!(2022-01-01 00:00).with_timezone(UtcOffset::UTC);
+        assert_eq!(local, utc);
+
+        let mut set = HashSet::new();
+        set.insert(local);
+        set.insert(utc);
+        set.insert(datetime!(2021-12-31 19:00 -05:00));
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&datetime!(2022-01-01 05:30 +05:30)));
+
+        set.insert(utc.with_nanosecond(1).unwrap());
+        set.insert(datetime!(2022-01-01 03:00).with_timezone(UtcOffset::UTC));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_now_in() {
+        let before = DateTime::utc_now();
+        let now = DateTime::now_in(Utc);
+        let after = DateTime::utc_now();
+        assert!(before <= now && now <= after);
+        assert!(now.duration_since(&before) < Duration::from_secs(1));
+
+        let offset = utc_offset!(+09:00);
+        let now = DateTime::now_in(offset);
+        assert_eq!(now.offset(), offset);
+        assert!(now.duration_since(&before) < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_duration_since_large_spans() {
+        let earlier = datetime!(1900-01-01 00:00);
+        let later = datetime!(2100-01-01 00:00).with_nanosecond(500_000_000).unwrap();
+        let expected = Duration::new(73_049 * 86_400, 500_000_000);
+        assert_eq!(later.duration_since(&earlier), expected);
+        assert_eq!(earlier.checked_duration_since(&later), None);
+        assert_eq!(earlier.saturating_duration_since(&later), Duration::ZERO);
+
+        // Offsets are taken into account and nanoseconds borrow from the seconds
+        let earlier = datetime!(1950-06-01 12:00:00 +09:00)
+            .with_nanosecond(750_000_000)
+            .unwrap();
+        let later = datetime!(2050-06-01 03:00:01).with_nanosecond(250_000_000).unwrap();
+        let expected = Duration::new(36_525 * 86_400, 500_000_000);
+        assert_eq!(later.duration_since(&earlier), expected);
+        assert_eq!(later.checked_duration_since(&later), Some(Duration::ZERO));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_elapsed() {
+        use crate::ext::IntervalLiteral;
+
+        let past = DateTime::utc_now() - 2.hours();
+        let elapsed = past.elapsed();
+        assert!(elapsed >= 2.hours() && elapsed < 2.hours() + 1.minutes());
+
+        let offset = utc_offset!(-05:00);
+        let past = DateTime::now_in(offset) - 3.days();
+        let elapsed = past.elapsed();
+        assert!(elapsed >= 3.days() && elapsed < 3.days() + 1.minutes());
+
+        // Future datetimes return a negative interval
+        let future = DateTime::utc_now() + 2.hours();
+        let elapsed = future.elapsed();
+        assert!(elapsed < Interval::ZERO);
+        assert!(elapsed <= -(2.hours()) + 1.minutes() && elapsed >= -(2.hours()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_elapsed_duration() {
+        let past = DateTime::utc_now() - Duration::from_secs(3600);
+        let elapsed = past.elapsed_duration();
+        assert!(elapsed >= Duration::from_secs(3600));
+        assert!(elapsed < Duration::from_secs(3660));
+
+        let past = past.in_timezone(utc_offset!(-05:00));
+        assert!(past.elapsed_duration() >= Duration::from_secs(3600));
+
+        let future = DateTime::utc_now() + Duration::from_secs(3600);
+        assert_eq!(future.elapsed_duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        // 2022-01-07 is a Friday
+        let friday = datetime!(2022-01-07 23:00);
+        assert!(friday.is_weekday());
+        assert!(!friday.is_weekend());
+        assert!(friday.is_weekend_with(&[Weekday::Friday, Weekday::Saturday]));
+
+        // The local date is used rather than the UTC date
+        let saturday = friday.in_timezone(utc_offset!(+02:00));
+        assert!(saturday.is_weekend());
+        assert!(!saturday.is_weekday());
+    }
+
+    #[test]
+    fn test_years_since() {
+        let birth = datetime!(2000-02-29 12:00);
+        assert_eq!(datetime!(2001-02-28 11:59).years_since(&birth), 0);
+        assert_eq!(datetime!(2001-02-28 12:00).years_since(&birth), 1);
+        assert_eq!(datetime!(2004-02-29 11:00).years_since(&birth), 3);
+        assert_eq!(datetime!(2004-02-29 12:00).years_since(&birth), 4);
+        assert_eq!(birth.years_since(&datetime!(2004-02-29 12:00)), -4);
+
+        // The instants are compared across timezones
+        let birth = datetime!(2000-02-29 23:00 -05:00);
+        assert_eq!(datetime!(2001-03-01 03:59).years_since(&birth), 0);
+        assert_eq!(datetime!(2001-03-01 04:00).years_since(&birth), 1);
+
+        let min = Date::MIN.at(Time::MIN);
+        let max = Date::MAX.at(Time::MAX);
+        assert_eq!(max.years_since(&min), 65535);
+        assert_eq!(min.years_since(&max), -65535);
+    }
+
+    #[test]
+    fn test_leap_second_comparisons() {
+        let before = datetime!(2016-12-31 23:59:59).with_nanosecond(999_999_999).unwrap();
+        let leap = datetime!(2016-12-31 23:59:59).with_nanosecond(1_000_000_000).unwrap();
+        let leap_half = datetime!(2016-12-31 23:59:59).with_nanosecond(1_500_000_000).unwrap();
+        let next = datetime!(2017-01-01 00:00:00);
+        let next_half = next.with_nanosecond(500_000_000).unwrap();
+
+        // A leap second is the same instant as the following second
+        assert!(before < leap);
+        assert_eq!(leap, next);
+        assert_eq!(leap_half, next_half);
+        assert!(leap < leap_half);
+        assert!(leap_half > next);
+        assert_eq!(leap.timestamp(), next.timestamp());
+        assert_eq!(leap_half.timestamp(), next_half.timestamp());
+        assert_eq!(leap.timestamp().as_seconds(), 1483228800);
+
+        // The local date and time still tell them apart
+        assert_eq!(leap.cmp_without_tz(&next), Ordering::Less);
+
+        // This is consistent with converting between timezones
+        let offset = utc_offset!(+09:00);
+        assert_eq!(leap.in_timezone(offset), leap);
+        assert_eq!(leap_half.in_timezone(offset), next_half);
+        let tokyo_leap = datetime!(2017-01-01 08:59:59 +09:00)
+            .with_nanosecond(1_000_000_000)
+            .unwrap();
+        assert_eq!(tokyo_leap, next);
+        assert_eq!(tokyo_leap.cmp(&leap.with_timezone(offset)), Ordering::Greater);
+        assert_eq!(tokyo_leap, leap);
+    }
+
+    #[test]
+    fn test_wrapping_add_time() {
+        use crate::ext::IntervalLiteral;
+
+        let dt = datetime!(2021-01-01 23:00);
+        assert_eq!(dt.wrapping_add_time(2.hours()), datetime!(2021-01-01 01:00));
+        assert_eq!(dt.wrapping_add_time((-24).hours()), dt);
+        assert_eq!(
+            dt.wrapping_add_time((-23).hours() - 30.minutes()),
+            datetime!(2021-01-01 23:30)
+        );
+        assert_eq!(
+            datetime!(2021-01-01 01:00).wrapping_add_time((-2).hours()),
+            datetime!(2021-01-01 23:00)
+        );
+
+        // Calendar components are ignored
+        assert_eq!(
+            dt.wrapping_add_time(1.months() + 3.days() + 1.hours()),
+            datetime!(2021-01-01 00:00)
+        );
+
+        // This matches the Time arithmetic
+        let interval = 49.hours() + 15.minutes();
+        assert_eq!(dt.wrapping_add_time(interval).time(), dt.time() + interval);
+        assert_eq!(dt.wrapping_add_time(interval).date(), dt.date());
+
+        let dt = datetime!(2021-01-01 23:00 -05:00);
+        let wrapped = dt.wrapping_add_time(2.hours());
+        assert_eq!(wrapped, datetime!(2021-01-01 01:00 -05:00));
+        assert_eq!(wrapped.offset(), dt.offset());
+    }
 }
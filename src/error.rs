@@ -19,6 +19,10 @@ pub enum Error {
     ///
     /// [`DateTime`]: crate::DateTime
     AmbiguousDateTime(Date, Time),
+    /// The ordinal day is outside of `1..=366`.
+    OrdinalOutOfRange(u16),
+    /// The ordinal day is 366 but the year is not a leap year.
+    NotLeapYear(i16),
 }
 
 impl core::fmt::Display for Error {
@@ -28,6 +32,8 @@ impl core::fmt::Display for Error {
             Error::NoSystemTime => f.write_str("could not fetch system time or timezone"),
             Error::SkippedDateTime(date, time) => write!(f, "{}T{} was skipped", date, time),
             Error::AmbiguousDateTime(date, time) => write!(f, "{}T{} is ambiguous", date, time),
+            Error::OrdinalOutOfRange(ordinal) => write!(f, "ordinal day {} is out of range", ordinal),
+            Error::NotLeapYear(year) => write!(f, "year {} is not a leap year", year),
         }
     }
 }
@@ -0,0 +1,288 @@
+//! Support for 52-53 week fiscal calendars.
+//!
+//! Retail and manufacturing businesses commonly use a fiscal year that always ends on the
+//! same weekday rather than on a fixed date. This keeps every fiscal year made out of
+//! whole weeks, 52 in most years and 53 every 5 or 6 years. The year is then split into
+//! 12 periods, grouped into quarters of 13 weeks following a pattern such as 4-4-5.
+//!
+//! See [`FiscalCalendar`] for more information.
+
+use crate::{
+    gregorian::{date_to_epoch_days, days_in_month, weekday_difference, weekday_from_days, DAYS_IN_ERA, ERA_DURATION},
+    Date, Weekday,
+};
+
+/// The number of weeks in each period of a quarter.
+///
+/// In a 53 week year the extra week is added to the last period of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FiscalPattern {
+    /// Periods of 4, 4, and 5 weeks.
+    FourFourFive,
+    /// Periods of 4, 5, and 4 weeks.
+    FourFiveFour,
+    /// Periods of 5, 4, and 4 weeks.
+    FiveFourFour,
+}
+
+impl FiscalPattern {
+    const fn weeks(self) -> [u8; 3] {
+        match self {
+            Self::FourFourFive => [4, 4, 5],
+            Self::FourFiveFour => [4, 5, 4],
+            Self::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// A 52-53 week fiscal calendar, also known as a 4-4-5 calendar.
+///
+/// A fiscal year ends on the last given weekday of the month before the starting month.
+/// Alternatively, with [`FiscalCalendar::nearest_end`], it ends on the given weekday nearest
+/// to the end of that month. Fiscal years are named after the calendar year they end in.
+/// For example, a fiscal year starting in September 2022 and ending in August or early
+/// September 2023 is fiscal year 2023.
+///
+/// ```
+/// use eos::{date, fiscal::FiscalCalendar, Weekday};
+///
+/// // Fiscal years that end on the Sunday nearest to August 31st
+/// let calendar = FiscalCalendar::new(9, Weekday::Sunday).unwrap().nearest_end();
+///
+/// // Fiscal year 2023 ran from 2022-08-29 to 2023-09-03
+/// assert_eq!(calendar.period(date!(2022-08-28)), (2022, 12, 52));
+/// assert_eq!(calendar.period(date!(2022-08-29)), (2023, 1, 1));
+/// assert_eq!(calendar.period(date!(2022-11-27)), (2023, 3, 13));
+/// assert_eq!(calendar.period(date!(2023-09-03)), (2023, 12, 53));
+/// assert_eq!(calendar.period(date!(2023-09-04)), (2024, 1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: u8,
+    weekday: Weekday,
+    nearest: bool,
+    pattern: FiscalPattern,
+}
+
+impl FiscalCalendar {
+    /// Creates a new [`FiscalCalendar`] starting in the given month and ending on the given weekday.
+    ///
+    /// The fiscal year ends on the last `weekday` of the month before `start_month`. The
+    /// periods follow the [`FiscalPattern::FourFourFive`] pattern by default.
+    ///
+    /// If the month is out of bounds (`1..=12`) then [`None`] is returned.
+    pub const fn new(start_month: u8, weekday: Weekday) -> Option<Self> {
+        if start_month < 1 || start_month > 12 {
+            return None;
+        }
+
+        Some(Self {
+            start_month,
+            weekday,
+            nearest: false,
+            pattern: FiscalPattern::FourFourFive,
+        })
+    }
+
+    /// Makes the fiscal year end on the weekday nearest to the end of the month
+    /// rather than the last weekday of the month.
+    ///
+    /// This means the fiscal year can end up to 3 days into the starting month.
+    #[must_use]
+    pub const fn nearest_end(mut self) -> Self {
+        self.nearest = true;
+        self
+    }
+
+    /// Sets the pattern of weeks used for the periods.
+    #[must_use]
+    pub const fn with_pattern(mut self, pattern: FiscalPattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Returns the month the fiscal year starts in.
+    #[inline]
+    #[must_use]
+    pub const fn start_month(&self) -> u8 {
+        self.start_month
+    }
+
+    /// Returns the weekday the fiscal year ends on.
+    #[inline]
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the pattern of weeks used for the periods.
+    #[inline]
+    #[must_use]
+    pub const fn pattern(&self) -> FiscalPattern {
+        self.pattern
+    }
+
+    /// Returns the last day of the given fiscal year as days since the UNIX epoch.
+    const fn year_end_epoch(&self, year: i32) -> i32 {
+        // The Gregorian calendar repeats every 400 years, weekdays included, so
+        // years just outside the supported range can be shifted into it.
+        if year > i16::MAX as i32 {
+            return self.year_end_epoch(year - ERA_DURATION) + DAYS_IN_ERA;
+        }
+        if year < i16::MIN as i32 {
+            return self.year_end_epoch(year + ERA_DURATION) - DAYS_IN_ERA;
+        }
+
+        let year = year as i16;
+        let month = if self.start_month == 1 {
+            12
+        } else {
+            self.start_month - 1
+        };
+        let last = date_to_epoch_days(year, month, days_in_month(year, month));
+        let diff = weekday_difference(weekday_from_days(last), self.weekday.days_from_sunday()) as i32;
+        if self.nearest && diff > 3 {
+            last - diff + 7
+        } else {
+            last - diff
+        }
+    }
+
+    /// Returns the fiscal year, period, and week of the given date.
+    ///
+    /// The period is within `1..=12` and the week is the week of the fiscal year
+    /// within `1..=53`. In a 53 week year the extra week belongs to the 12th period.
+    ///
+    /// The fiscal year is an `i32` since dates at the ends of the supported range
+    /// can belong to a fiscal year outside of it. For a [`DateTime`](crate::DateTime),
+    /// pass its [`date`](crate::DateTime::date).
+    #[must_use]
+    pub fn period(&self, date: Date) -> (i32, u8, u8) {
+        let epoch = date.days_since_epoch();
+        let mut year = date.year() as i32;
+        if self.start_month != 1 && date.month() >= self.start_month {
+            year += 1;
+        }
+
+        // The candidate can be off by one due to the weekday alignment near the boundaries
+        if epoch > self.year_end_epoch(year) {
+            year += 1;
+        } else if epoch <= self.year_end_epoch(year - 1) {
+            year -= 1;
+        }
+
+        let start = self.year_end_epoch(year - 1) + 1;
+        let week = ((epoch - start) / 7 + 1) as u8;
+
+        let mut period = 1;
+        let mut weeks = 0;
+        for period_weeks in self.pattern.weeks().iter().cycle().take(11) {
+            weeks += period_weeks;
+            if week <= weeks {
+                break;
+            }
+            period += 1;
+        }
+        (year, period, week)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date;
+
+    #[test]
+    fn test_four_four_five() {
+        // Fiscal year 2023 that ends on the Sunday nearest to August 31st
+        // has 53 weeks, starting on 2022-08-29 and ending on 2023-09-03
+        let calendar = FiscalCalendar::new(9, Weekday::Sunday).unwrap().nearest_end();
+        let periods = [
+            (date!(2022 - 08 - 29), date!(2022 - 09 - 25)),
+            (date!(2022 - 09 - 26), date!(2022 - 10 - 23)),
+            (date!(2022 - 10 - 24), date!(2022 - 11 - 27)),
+            (date!(2022 - 11 - 28), date!(2022 - 12 - 25)),
+            (date!(2022 - 12 - 26), date!(2023 - 01 - 22)),
+            (date!(2023 - 01 - 23), date!(2023 - 02 - 26)),
+            (date!(2023 - 02 - 27), date!(2023 - 03 - 26)),
+            (date!(2023 - 03 - 27), date!(2023 - 04 - 23)),
+            (date!(2023 - 04 - 24), date!(2023 - 05 - 28)),
+            (date!(2023 - 05 - 29), date!(2023 - 06 - 25)),
+            (date!(2023 - 06 - 26), date!(2023 - 07 - 23)),
+            (date!(2023 - 07 - 24), date!(2023 - 09 - 03)),
+        ];
+
+        let mut week = 1;
+        for (period, (start, end)) in periods.into_iter().enumerate() {
+            let period = period as u8 + 1;
+            let mut date = start;
+            while date <= end {
+                assert_eq!(calendar.period(date), (2023, period, week), "{}", date);
+                if date.weekday() == Weekday::Sunday {
+                    week += 1;
+                }
+                date = date.add_days(1);
+            }
+        }
+        assert_eq!(week, 54);
+
+        // The surrounding years have 52 weeks
+        assert_eq!(calendar.period(date!(2022 - 08 - 28)), (2022, 12, 52));
+        assert_eq!(calendar.period(date!(2023 - 09 - 04)), (2024, 1, 1));
+        assert_eq!(calendar.period(date!(2024 - 09 - 01)), (2024, 12, 52));
+        assert_eq!(calendar.period(date!(2024 - 09 - 02)), (2025, 1, 1));
+    }
+
+    #[test]
+    fn test_patterns() {
+        // Fiscal years that end on the Saturday nearest to January 31st, e.g. the NRF calendar
+        let calendar = FiscalCalendar::new(2, Weekday::Saturday).unwrap().nearest_end();
+        assert_eq!(calendar.period(date!(2023 - 01 - 28)), (2023, 12, 52));
+        assert_eq!(calendar.period(date!(2023 - 01 - 29)), (2024, 1, 1));
+        assert_eq!(calendar.period(date!(2024 - 02 - 03)), (2024, 12, 53));
+        assert_eq!(calendar.period(date!(2024 - 02 - 04)), (2025, 1, 1));
+
+        // Weeks 5 and 9 start a new period depending on the pattern
+        let week5 = date!(2023 - 02 - 26);
+        let week9 = date!(2023 - 03 - 26);
+        for (pattern, period5, period9) in [
+            (FiscalPattern::FourFourFive, 2, 3),
+            (FiscalPattern::FourFiveFour, 2, 2),
+            (FiscalPattern::FiveFourFour, 1, 2),
+        ] {
+            let calendar = calendar.with_pattern(pattern);
+            assert_eq!(calendar.pattern(), pattern);
+            assert_eq!(calendar.period(week5), (2024, period5, 5));
+            assert_eq!(calendar.period(week9), (2024, period9, 9));
+        }
+    }
+
+    #[test]
+    fn test_last_weekday() {
+        // Fiscal years that end on the last Saturday of December
+        let calendar = FiscalCalendar::new(1, Weekday::Saturday).unwrap();
+        assert_eq!(calendar.period(date!(2021 - 12 - 25)), (2021, 12, 52));
+        assert_eq!(calendar.period(date!(2021 - 12 - 26)), (2022, 1, 1));
+        assert_eq!(calendar.period(date!(2022 - 01 - 01)), (2022, 1, 1));
+        assert_eq!(calendar.period(date!(2022 - 12 - 31)), (2022, 12, 53));
+        assert_eq!(calendar.period(date!(2023 - 01 - 01)), (2023, 1, 1));
+        assert_eq!(calendar.period(date!(2023 - 12 - 30)), (2023, 12, 52));
+        assert_eq!(calendar.period(date!(2023 - 12 - 31)), (2024, 1, 1));
+
+        assert!(FiscalCalendar::new(0, Weekday::Saturday).is_none());
+        assert!(FiscalCalendar::new(13, Weekday::Saturday).is_none());
+    }
+
+    #[test]
+    fn test_extreme_dates() {
+        let calendar = FiscalCalendar::new(1, Weekday::Saturday).unwrap();
+        let (year, _, week) = calendar.period(Date::MAX);
+        assert!(year == i16::MAX as i32 || year == i16::MAX as i32 + 1);
+        assert!((1..=53).contains(&week));
+
+        let (year, _, week) = calendar.period(Date::MIN);
+        assert!(year == i16::MIN as i32 || year == i16::MIN as i32 + 1);
+        assert!((1..=53).contains(&week));
+    }
+}
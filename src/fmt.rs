@@ -22,7 +22,8 @@
 //! library.
 //!
 //! RFC 3339 formatting is only provided by [`DateTime`] using [`DateTime::to_rfc3339`]
-//! and [`DateTime::from_rfc3339`].
+//! and [`DateTime::from_rfc3339`]. Likewise, HTTP dates are provided using
+//! [`DateTime::to_http_date`] and [`DateTime::from_http_date`].
 //!
 //! # Format
 //!
@@ -70,7 +71,11 @@
 //! |   `%f`    | Nanoseconds as a zero-padded number.[^1][^3]                    | 0000000, 0000001, ..., 999999999 |
 //! |   `%z`    | UTC offset as `±HHMM[SS]` or empty.                             | +0000, -0500, +102340, ...       |
 //! |   `%o`    | UTC offset as `±HH:MM[:SS]` or empty.                           | +00:00, -05:00, +10:23:40, ...   |
+//! |   `%J`    | Same as `%z` except UTC is written as `Z`.                      | Z, -0500, +102340, ...           |
 //! |   `%Z`    | Timezone name or empty.[^4]                                     | UTC, EST, ...                    |
+//! |   `%s`    | Seconds since the UNIX epoch.[^7]                               | -1, 0, 1641173925, ...           |
+//! |   `%F`    | Same as `%Y-%m-%d`.[^1]                                         | 2022-01-09, ...                  |
+//! |   `%T`    | Same as `%H:%M:%S`.[^1]                                         | 10:30:00, ...                    |
 //! |   `%%`    | The literal `%` character.                                      | %                                |
 //!
 //! ### Modifiers
@@ -92,11 +97,20 @@
 //! [^1]: Supports modifiers. During parsing, modifiers are ignored and zero-padding is optional.
 //! [^2]: This is leap second aware so `60` is possible.
 //! [^3]: This is since the last whole second. This means the value will never be higher than `999_999_999`.
-//!       Anything above that value is rolled over to the seconds value.
+//!       Anything above that value is rolled over to the seconds value. When parsing, a leading
+//!       `.` or `,` separator is consumed if present, so `%S%f` accepts both `05.25` and `05,25`.
 //!
-//! [^4]: Unsupported when parsing. Usage will return a [`ParseError`].
+//! [^4]: When parsing, only names that map to a single fixed offset are supported. These are
+//!       `UTC`, `GMT`, `UT`, and the single letter military timezones `A` to `Z` except `J`.
+//!       Names are case-sensitive and any other name, such as `EST`, returns
+//!       [`ParseError::UnsupportedSpecifier`].
 //! [^5]: This is only used in calculating during parsing if used together.
 //! [^6]: If provided with a year then this will be used for calculations.
+//! [^7]: This is only supported when formatting a [`DateTime`]. When parsing, this sets the
+//!       instant in time and every other date and time specifier is ignored except for `%f`,
+//!       which is used as the fractional seconds, e.g. `%s.%f`. Similar to formatting, the fraction
+//!       is always forward in time so `-2.75` is three quarters of a second after `-2`. The UTC offset is
+//!       still used to determine the local date and time.
 //!
 //! [strftime]: https://en.cppreference.com/w/cpp/chrono/c/strftime
 //! [`java.time`]: https://docs.oracle.com/javase/8/docs/api/java/time/package-summary.html
@@ -105,12 +119,11 @@ use crate::{Date, DateTime, Time, TimeZone, Weekday};
 use alloc::{string::String, vec::Vec};
 use core::fmt::Write;
 
+#[cfg(feature = "parsing")]
+use crate::UtcOffset;
 #[cfg(feature = "parsing")]
 use core::{iter::Peekable, str::Bytes};
 
-#[cfg(feature = "std")]
-use crate::utils::divmod;
-
 /// The error type that occurs during parsing a string.
 ///
 /// For example, this is given as a result of a failure in the [`FromIsoFormat`] trait.
@@ -193,6 +206,8 @@ pub enum IsoFormatPrecision {
     Minute,
     /// Display up to the second, leaving the fractional seconds omitted.
     Second,
+    /// Display fractional seconds up to decisecond (tenths of a second) precision.
+    Decisecond,
     /// Display fractional seconds up to millisecond precision.
     Millisecond,
     /// Display fractional seconds up to microsecond precision.
@@ -224,41 +239,40 @@ pub trait ToIsoFormat {
     fn to_iso_format(&self) -> String;
 }
 
-// This requires std due to f64::div_euclid and f64::rem_euclid not being available
-#[cfg(all(feature = "formatting", feature = "std"))]
-impl ToIsoFormat for std::time::Duration {
+#[cfg(feature = "formatting")]
+impl ToIsoFormat for core::time::Duration {
     fn to_iso_format_with_precision(&self, _precision: IsoFormatPrecision) -> String {
         self.to_iso_format()
     }
 
+    /// Converts to an ISO-8601 format string such as `PTnHnMn.nS`.
+    ///
+    /// The duration is normalized so that the minutes and seconds are always
+    /// below 60, e.g. 61 seconds is `PT1M1S`. Units that are zero are omitted
+    /// unless the entire duration is zero, in which case `PT0S` is returned.
+    /// Fractional seconds are written with up to 9 digits of precision.
     fn to_iso_format(&self) -> String {
-        let mut buffer = String::new();
-        let total_secs = self.as_secs_f64();
-        if total_secs < 60.0 {
-            // Simple case with (just) fractional seconds
-            write!(&mut buffer, "PT{}S", self.as_secs_f64()).expect("unexpected error when writing to string");
-        } else {
-            let (hours, seconds) = divmod!(total_secs, 3600.0);
-            let (minutes, seconds) = divmod!(seconds, 60.0);
-            buffer.push('P');
-            buffer.push('T');
-            if hours > 0.0 {
-                buffer
-                    .write_fmt(format_args!("{}H", hours))
-                    .expect("unexpected error when writing to string");
-            }
+        let mut buffer = String::from("PT");
+        let total_secs = self.as_secs();
+        let hours = total_secs / 3600;
+        let minutes = (total_secs % 3600) / 60;
+        let seconds = total_secs % 60;
+        let nanos = self.subsec_nanos();
+
+        if hours > 0 {
+            write!(&mut buffer, "{}H", hours).expect("unexpected error when writing to string");
+        }
 
-            if minutes > 0.0 {
-                buffer
-                    .write_fmt(format_args!("{}M", minutes))
-                    .expect("unexpected error when writing to string");
-            }
+        if minutes > 0 {
+            write!(&mut buffer, "{}M", minutes).expect("unexpected error when writing to string");
+        }
 
-            if seconds > 0.0 {
-                buffer
-                    .write_fmt(format_args!("{}S", hours))
-                    .expect("unexpected error when writing to string");
-            }
+        if nanos != 0 {
+            let (nanos, width) = crate::utils::trim_trailing_zeroes(nanos as u64, 9);
+            write!(&mut buffer, "{}.{:0width$}S", seconds, nanos, width = width)
+                .expect("unexpected error when writing to string");
+        } else if seconds > 0 || (hours == 0 && minutes == 0) {
+            write!(&mut buffer, "{}S", seconds).expect("unexpected error when writing to string");
         }
         buffer
     }
@@ -266,6 +280,7 @@ impl ToIsoFormat for std::time::Duration {
 
 /// A parser to parse date time strings.
 #[cfg(feature = "parsing")]
+#[derive(Clone)]
 pub(crate) struct Parser<'a> {
     bytes: Peekable<Bytes<'a>>,
 }
@@ -357,6 +372,37 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses an arbitrary number of digits, returning the number being represented.
+    ///
+    /// This also handles the optional sign. If the number is too large to fit in an
+    /// i64 then it errors out. If no numbers are given then this will also error.
+    pub(crate) fn parse_i64(&mut self) -> Result<i64, ParseError> {
+        let negative = self.parse_sign();
+        let mut read_any: bool = false;
+        let mut n: i64 = 0;
+        while let Some(c) = self.advance_if(u8::is_ascii_digit) {
+            // Accumulating as a negative number allows i64::MIN to be parsed
+            let digit = (c - b'0') as i64;
+            n = n
+                .checked_mul(10)
+                .and_then(|n| {
+                    if negative {
+                        n.checked_sub(digit)
+                    } else {
+                        n.checked_add(digit)
+                    }
+                })
+                .ok_or(ParseError::OutOfBounds)?;
+            read_any = true;
+        }
+
+        if read_any {
+            Ok(n)
+        } else {
+            Err(ParseError::UnexpectedNonDigit)
+        }
+    }
+
     /// Parses up to 9 digits, returning the number being represented.
     ///
     /// If the number is too large to fit in an u32 then it errors out.
@@ -505,13 +551,13 @@ impl<'a> Parser<'a> {
 
         let year = digits[0] as i32 * 1000 + digits[1] as i32 * 100 + digits[2] as i32 * 10 + digits[3] as i32;
         let year = if let Some(b) = self.advance_if(u8::is_ascii_digit) {
-            let new_year = year * 10 + (b - b'0') as i32;
-            i16::try_from(new_year)?
+            year * 10 + (b - b'0') as i32
         } else {
-            year as i16
+            year
         };
 
-        Ok(if negative { -year } else { year })
+        // The sign is applied before narrowing since -32768 has no positive counterpart
+        Ok(i16::try_from(if negative { -year } else { year })?)
     }
 
     /// Parses a two digit unit (e.g. `02`) into their integer representation.
@@ -566,6 +612,42 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Returns whether the upcoming date is in the ISO 8601 basic format, i.e. without separators.
+    ///
+    /// The basic format is only supported for unsigned four digit years. This is detected
+    /// by looking ahead for either a `W` or a sixth digit after the year.
+    fn is_basic_date_format(&self) -> bool {
+        let mut bytes = self.bytes.clone();
+        if !bytes.by_ref().take(4).all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+        match bytes.next() {
+            Some(b'W') => true,
+            Some(b) if b.is_ascii_digit() => matches!(bytes.next(), Some(b) if b.is_ascii_digit()),
+            _ => false,
+        }
+    }
+
+    /// Converts an ISO week date to a [`Date`] after bound checking the week.
+    ///
+    /// The weekday is a zero-based offset from Monday.
+    fn date_from_iso_week(year: i16, week: u8, weekday: u8) -> Result<Date, ParseError> {
+        if week == 0 || week > crate::gregorian::iso_weeks_in_year(year) {
+            return Err(ParseError::OutOfBounds);
+        }
+        let epoch = crate::gregorian::iso_week_start_epoch_from_year(year) + (week as i32 - 1) * 7 + weekday as i32;
+        let (year, month, day) = crate::gregorian::date_from_epoch_days(epoch);
+        Ok(Date { year, month, day })
+    }
+
+    /// Parses an ISO weekday number from 1 to 7, returning the zero-based offset from Monday.
+    fn parse_iso_weekday(&mut self) -> Result<u8, ParseError> {
+        match self.parse_digit()? {
+            n @ 1..=7 => Ok(n - 1),
+            _ => Err(ParseError::OutOfBounds),
+        }
+    }
+
     /// Parses the supported date formats.
     ///
     /// Right now these are:
@@ -575,27 +657,29 @@ impl<'a> Parser<'a> {
     /// - `±YYYYY-Www` (e.g. `2012-W10`)
     /// - `±YYYYY-Www-D` (e.g. `2012-W10-1`)
     /// - `±YYYYY-DDD` (e.g. `2021-048`)
+    /// - `YYYYMMDD` (e.g. `20120213`)
+    /// - `YYYYWww` (e.g. `2012W10`)
+    /// - `YYYYWwwD` (e.g. `2012W101`)
+    /// - `YYYYDDD` (e.g. `2021048`)
+    ///
+    /// The separator-less basic formats only support four digit years. Mixing the two
+    /// formats, such as `2012-0213`, is not allowed.
     pub(crate) fn parse_date(&mut self) -> Result<Date, ParseError> {
+        if self.is_basic_date_format() {
+            return self.parse_basic_date();
+        }
+
         let year = self.parse_year()?;
         self.expect(b'-')?;
         match self.advance_if_equal(b'W') {
             Some(_) => {
                 // week date parsing, i.e. 2012-W10-1
                 let week = self.parse_two_digits()?;
-                if week == 0 || week > crate::gregorian::iso_weeks_in_year(year) {
-                    return Err(ParseError::OutOfBounds);
-                }
                 let weekday = match self.advance_if_equal(b'-') {
-                    Some(_) => match self.parse_digit()? {
-                        n @ 1..=7 => n - 1,
-                        _ => return Err(ParseError::OutOfBounds),
-                    },
+                    Some(_) => self.parse_iso_weekday()?,
                     None => 0,
                 };
-                let epoch =
-                    crate::gregorian::iso_week_start_epoch_from_year(year) + (week as i32 - 1) * 7 + weekday as i32;
-                let (year, month, day) = crate::gregorian::date_from_epoch_days(epoch);
-                Ok(Date { year, month, day })
+                Self::date_from_iso_week(year, week, weekday)
             }
             None => {
                 match self.parse_month_or_ordinal()? {
@@ -622,6 +706,46 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the ISO 8601 basic date formats, i.e. `YYYYMMDD`, `YYYYWww`, `YYYYWwwD`, and `YYYYDDD`.
+    fn parse_basic_date(&mut self) -> Result<Date, ParseError> {
+        let year = self.parse_two_digits()? as i16 * 100 + self.parse_two_digits()? as i16;
+        match self.advance_if_equal(b'W') {
+            Some(_) => {
+                // week date parsing, i.e. 2012W101
+                let week = self.parse_two_digits()?;
+                let weekday = match self.peek() {
+                    Some(b) if b.is_ascii_digit() => self.parse_iso_weekday()?,
+                    _ => 0,
+                };
+                Self::date_from_iso_week(year, week, weekday)
+            }
+            None => {
+                let digits = self.parse_two_digits()?;
+                let b = self.parse_digit()?;
+                match self.advance_if(u8::is_ascii_digit) {
+                    // YYYYMMDD
+                    Some(c) => {
+                        let day = b * 10 + (c - b'0');
+                        if digits == 0 || digits > 12 || day == 0 || day > crate::gregorian::days_in_month(year, digits)
+                        {
+                            return Err(ParseError::OutOfBounds);
+                        }
+                        Ok(Date {
+                            year,
+                            month: digits,
+                            day,
+                        })
+                    }
+                    // YYYYDDD
+                    None => {
+                        let ordinal = digits as u16 * 10 + b as u16;
+                        Date::from_ordinal(year, ordinal).ok_or(ParseError::OutOfBounds)
+                    }
+                }
+            }
+        }
+    }
+
     /// Parses the supported time formats.
     ///
     /// Right now these are:
@@ -663,6 +787,60 @@ impl<'a> Parser<'a> {
             })
         }
     }
+
+    /// Parses the supported UTC offset formats.
+    ///
+    /// Right now these are:
+    ///
+    /// - `±HH` (e.g. `+12`)
+    /// - `±HH:MM` (e.g. `+12:23`)
+    /// - `±HH:MM:SS` (e.g. `+12:23:45`)
+    /// - `Z` (represents UTC)
+    pub(crate) fn parse_utc_offset(&mut self) -> Result<UtcOffset, ParseError> {
+        if self.advance_if_equal(b'Z').is_some() {
+            return Ok(UtcOffset::UTC);
+        }
+
+        let negative = self.parse_required_sign()?;
+        let hours = self.parse_two_digits()? as i8;
+        if hours > 23 {
+            return Err(ParseError::OutOfBounds);
+        }
+
+        let (minutes, seconds) = match self.advance_if_equal(b':') {
+            Some(_) => {
+                let minute = self.parse_two_digits()? as i8;
+                if minute > 59 {
+                    return Err(ParseError::OutOfBounds);
+                }
+                match self.advance_if_equal(b':') {
+                    Some(_) => {
+                        let second = self.parse_two_digits()? as i8;
+                        if second > 59 {
+                            return Err(ParseError::OutOfBounds);
+                        }
+                        (minute, second)
+                    }
+                    None => (minute, 0),
+                }
+            }
+            None => (0, 0),
+        };
+
+        Ok(if negative {
+            UtcOffset {
+                hours: -hours,
+                minutes: -minutes,
+                seconds: -seconds,
+            }
+        } else {
+            UtcOffset {
+                hours,
+                minutes,
+                seconds,
+            }
+        })
+    }
 }
 
 /// A handle for how a fragment should be formatted.
@@ -717,6 +895,49 @@ impl<'a> FormatSpec<'a> {
         }
     }
 
+    /// Returns a new [`FormatSpec`] with a raw string that is optional when parsing.
+    ///
+    /// When parsing, the string is consumed if the input starts with it and is skipped
+    /// otherwise. When formatting, the string is always written. This allows a single
+    /// specification to tolerate small variations in the input such as an optional
+    /// `T` separator or a trailing `Z`.
+    ///
+    /// ```
+    /// use eos::{datetime, fmt::{FormatSpec, FormatSpecKind}, DateTime};
+    ///
+    /// let spec = [
+    ///     FormatSpec::new(FormatSpecKind::Year),
+    ///     FormatSpec::raw("-"),
+    ///     FormatSpec::new(FormatSpecKind::Month),
+    ///     FormatSpec::raw("-"),
+    ///     FormatSpec::new(FormatSpecKind::Day),
+    ///     FormatSpec::raw(" "),
+    ///     FormatSpec::new(FormatSpecKind::Hour),
+    ///     FormatSpec::raw(":"),
+    ///     FormatSpec::new(FormatSpecKind::Minute),
+    ///     FormatSpec::optional(" UTC"),
+    /// ];
+    ///
+    /// let expected = datetime!(2022-01-09 10:30);
+    /// assert_eq!(DateTime::parse_from_spec("2022-01-09 10:30 UTC", &spec)?, expected);
+    /// assert_eq!(DateTime::parse_from_spec("2022-01-09 10:30", &spec)?, expected);
+    /// assert_eq!(expected.format(&spec).to_string(), "2022-01-09 10:30 UTC");
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    pub const fn optional(s: &'a str) -> Self {
+        Self {
+            kind: FormatSpecKind::Optional(s),
+            padding: FormatSpecPadding::Empty,
+        }
+    }
+
+    /// Converts this [`FormatSpec`] into an [`OwnedFormatSpec`] containing only itself.
+    ///
+    /// If this is a raw string then it is copied.
+    pub fn into_owned(self) -> OwnedFormatSpec {
+        OwnedFormatSpec::new(&[self])
+    }
+
     #[cfg(feature = "parsing")]
     pub(crate) fn parse_into(
         &self,
@@ -727,6 +948,13 @@ impl<'a> FormatSpec<'a> {
             FormatSpecKind::Raw(x) => {
                 parser.expect_str(x.as_bytes())?;
             }
+            FormatSpecKind::Optional(x) => {
+                // Only consume the string if all of it matches
+                let mut lookahead = parser.clone();
+                if lookahead.expect_str(x.as_bytes()).is_ok() {
+                    *parser = lookahead;
+                }
+            }
             FormatSpecKind::AbbreviatedWeekday => {
                 // Mon, Tue, Wed, Thu, Fri, Sat, Sun
                 match parser.advance() {
@@ -976,10 +1204,15 @@ impl<'a> FormatSpec<'a> {
                 let month = parser.parse_u16::<2>()? as u8;
                 builder.month(month);
             }
-            FormatSpecKind::Year | FormatSpecKind::SignedYear | FormatSpecKind::IsoWeekYear => {
+            FormatSpecKind::Year | FormatSpecKind::SignedYear => {
                 let negative = parser.parse_sign();
-                let year = i16::try_from(parser.parse_u16::<5>()?)?;
-                builder.year(if negative { -year } else { year });
+                let year = parser.parse_u16::<5>()? as i32;
+                builder.year(i16::try_from(if negative { -year } else { year })?);
+            }
+            FormatSpecKind::IsoWeekYear => {
+                let negative = parser.parse_sign();
+                let year = parser.parse_u16::<5>()? as i32;
+                builder.iso_year(i16::try_from(if negative { -year } else { year })?);
             }
             FormatSpecKind::IsoWeek => {
                 let iso_week = parser.parse_u16::<2>()? as u8;
@@ -1012,10 +1245,18 @@ impl<'a> FormatSpec<'a> {
                 builder.minute(minute);
             }
             FormatSpecKind::Second => {
+                // 60 is a leap second, which the builder moves into the nanoseconds
+                // after every specifier is parsed, similar to Parser::parse_time
                 let second = parser.parse_u16::<2>()? as u8;
+                if second > 60 {
+                    return Err(ParseError::OutOfBounds);
+                }
                 builder.second(second);
             }
             FormatSpecKind::Nanosecond => {
+                // The fractional separator is optional since it may have been
+                // consumed by a preceding literal already, e.g. `%S.%f`
+                parser.advance_if(|c| matches!(c, b'.' | b','));
                 let nanos = parser.parse_nanoseconds()?;
                 builder.nanosecond(nanos);
             }
@@ -1037,7 +1278,10 @@ impl<'a> FormatSpec<'a> {
                     builder.timezone = offset;
                 }
             }
-            FormatSpecKind::UtcOffsetBrief => {
+            FormatSpecKind::UtcOffsetBriefZ if parser.advance_if_equal(b'Z').is_some() => {
+                builder.timezone = crate::UtcOffset::UTC;
+            }
+            FormatSpecKind::UtcOffsetBrief | FormatSpecKind::UtcOffsetBriefZ => {
                 // [+-]HHMM[SS]?
                 let negative = parser.parse_required_sign()?;
                 let hour = parser.parse_two_digits()? as i8;
@@ -1053,7 +1297,37 @@ impl<'a> FormatSpec<'a> {
                     builder.timezone = offset;
                 }
             }
-            FormatSpecKind::ZoneName => return Err(ParseError::UnsupportedSpecifier),
+            FormatSpecKind::ZoneName => {
+                // Only names that unambiguously map to a fixed offset are supported.
+                // Anything longer than 3 characters is not one of them.
+                let mut name = [0u8; 3];
+                let mut len = 0;
+                while let Some(c) = parser.advance_if(u8::is_ascii_alphabetic) {
+                    if len == name.len() {
+                        return Err(ParseError::UnsupportedSpecifier);
+                    }
+                    name[len] = c;
+                    len += 1;
+                }
+
+                let hours = match &name[..len] {
+                    b"UTC" | b"GMT" | b"UT" | b"Z" => 0,
+                    // Military timezones, J is local time and therefore unsupported
+                    [c @ b'A'..=b'I'] => (c - b'A' + 1) as i8,
+                    [c @ b'K'..=b'M'] => (c - b'K' + 10) as i8,
+                    [c @ b'N'..=b'Y'] => -((c - b'N' + 1) as i8),
+                    [] => match parser.peek() {
+                        Some(c) => return Err(ParseError::UnexpectedChar(c as char)),
+                        None => return Err(ParseError::UnexpectedEnd),
+                    },
+                    _ => return Err(ParseError::UnsupportedSpecifier),
+                };
+                builder.timezone = crate::UtcOffset::from_hms(hours, 0, 0).ok_or(ParseError::OutOfBounds)?;
+            }
+            FormatSpecKind::Timestamp => {
+                let seconds = parser.parse_i64()?;
+                builder.timestamp(crate::Timestamp::from_seconds(seconds));
+            }
             FormatSpecKind::Escape => {
                 parser.expect(b'%')?;
             }
@@ -1100,6 +1374,8 @@ enum FormatSpecPadding {
 pub enum FormatSpecKind<'a> {
     /// A raw string
     Raw(&'a str),
+    /// A raw string that is optional when parsing
+    Optional(&'a str),
     /// An abbreviated weekday name (`%a`)
     AbbreviatedWeekday,
     /// A full weekday name (`%A`)
@@ -1142,8 +1418,12 @@ pub enum FormatSpecKind<'a> {
     UtcOffset,
     /// A shortened UTC offset (`%z`)
     UtcOffsetBrief,
+    /// A shortened UTC offset that uses `Z` for UTC (`%J`)
+    UtcOffsetBriefZ,
     /// The timezone name (`%Z`)
     ZoneName,
+    /// The number of seconds since the UNIX epoch (`%s`)
+    Timestamp,
     /// A literal `%` character (`%%`)
     Escape,
 }
@@ -1171,16 +1451,68 @@ fn parse_directive(directive: u8) -> Result<FormatSpecKind<'static>, Error> {
         b'f' => Ok(FormatSpecKind::Nanosecond),
         b'o' => Ok(FormatSpecKind::UtcOffset),
         b'z' => Ok(FormatSpecKind::UtcOffsetBrief),
+        b'J' => Ok(FormatSpecKind::UtcOffsetBriefZ),
         b'Z' => Ok(FormatSpecKind::ZoneName),
+        b's' => Ok(FormatSpecKind::Timestamp),
         b'%' => Ok(FormatSpecKind::Escape),
         b'_' | b'#' => Err(Error::SpecifierNotFound),
         _ => Err(Error::UnknownSpecifier(directive)),
     }
 }
 
+/// Creates the specs for a compound specifier made up of three directives split by a separator.
+const fn compound_spec(
+    kinds: [FormatSpecKind<'static>; 3],
+    separator: &'static str,
+    padding: FormatSpecPadding,
+) -> [FormatSpec<'static>; 5] {
+    let [first, second, third] = kinds;
+    [
+        FormatSpec { kind: first, padding },
+        FormatSpec::raw(separator),
+        FormatSpec { kind: second, padding },
+        FormatSpec::raw(separator),
+        FormatSpec { kind: third, padding },
+    ]
+}
+
+const DATE_KINDS: [FormatSpecKind<'static>; 3] = [FormatSpecKind::Year, FormatSpecKind::Month, FormatSpecKind::Day];
+const TIME_KINDS: [FormatSpecKind<'static>; 3] = [FormatSpecKind::Hour, FormatSpecKind::Minute, FormatSpecKind::Second];
+
+// Indexed by the padding modifier, see `compound_directive`
+static DATE_SPECS: [[FormatSpec<'static>; 5]; 3] = [
+    compound_spec(DATE_KINDS, "-", FormatSpecPadding::Zero),
+    compound_spec(DATE_KINDS, "-", FormatSpecPadding::Empty),
+    compound_spec(DATE_KINDS, "-", FormatSpecPadding::Space),
+];
+static TIME_SPECS: [[FormatSpec<'static>; 5]; 3] = [
+    compound_spec(TIME_KINDS, ":", FormatSpecPadding::Zero),
+    compound_spec(TIME_KINDS, ":", FormatSpecPadding::Empty),
+    compound_spec(TIME_KINDS, ":", FormatSpecPadding::Space),
+];
+
+/// Expands a compound specifier such as `%F` into its equivalent sequence of specs,
+/// with the padding modifier applied to every directive.
+fn compound_directive(directive: u8, padding: FormatSpecPadding) -> Option<&'static [FormatSpec<'static>]> {
+    let specs = match directive {
+        b'F' => &DATE_SPECS,
+        b'T' => &TIME_SPECS,
+        _ => return None,
+    };
+    let index = match padding {
+        FormatSpecPadding::Zero => 0,
+        FormatSpecPadding::Empty => 1,
+        FormatSpecPadding::Space => 2,
+    };
+    Some(&specs[index])
+}
+
+#[derive(Debug, Clone)]
 struct FormatSpecParser<'a> {
     data: &'a [u8],
     inside_directive: bool,
+    // The remaining specs of an expanded compound specifier
+    pending: &'static [FormatSpec<'static>],
 }
 
 impl<'a> FormatSpecParser<'a> {
@@ -1188,6 +1520,7 @@ impl<'a> FormatSpecParser<'a> {
         Self {
             data: s.as_bytes(),
             inside_directive: s.as_bytes().first() == Some(&b'%'),
+            pending: &[],
         }
     }
 }
@@ -1196,40 +1529,27 @@ impl<'a> Iterator for FormatSpecParser<'a> {
     type Item = Result<FormatSpec<'a>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let [spec, rest @ ..] = self.pending {
+            self.pending = rest;
+            return Some(Ok(*spec));
+        }
+
         if self.inside_directive {
             self.inside_directive = false;
-            match self.data {
-                [b'%', b'#', directive, rest @ ..] => {
-                    self.data = rest;
-                    match parse_directive(*directive) {
-                        Ok(kind) => Some(Ok(FormatSpec {
-                            kind,
-                            padding: FormatSpecPadding::Empty,
-                        })),
-                        Err(e) => Some(Err(e)),
-                    }
-                }
-                [b'%', b'_', directive, rest @ ..] => {
-                    self.data = rest;
-                    match parse_directive(*directive) {
-                        Ok(kind) => Some(Ok(FormatSpec {
-                            kind,
-                            padding: FormatSpecPadding::Space,
-                        })),
-                        Err(e) => Some(Err(e)),
-                    }
-                }
-                [b'%', directive, rest @ ..] => {
-                    self.data = rest;
-                    match parse_directive(*directive) {
-                        Ok(kind) => Some(Ok(FormatSpec {
-                            kind,
-                            padding: FormatSpecPadding::Zero,
-                        })),
-                        Err(e) => Some(Err(e)),
-                    }
-                }
-                _ => Some(Err(Error::SpecifierNotFound)),
+            let (directive, padding, rest) = match self.data {
+                [b'%', b'#', directive, rest @ ..] => (*directive, FormatSpecPadding::Empty, rest),
+                [b'%', b'_', directive, rest @ ..] => (*directive, FormatSpecPadding::Space, rest),
+                [b'%', directive, rest @ ..] => (*directive, FormatSpecPadding::Zero, rest),
+                _ => return Some(Err(Error::SpecifierNotFound)),
+            };
+            self.data = rest;
+            if let Some([spec, pending @ ..]) = compound_directive(directive, padding) {
+                self.pending = pending;
+                return Some(Ok(*spec));
+            }
+            match parse_directive(directive) {
+                Ok(kind) => Some(Ok(FormatSpec { kind, padding })),
+                Err(e) => Some(Err(e)),
             }
         } else if self.data.is_empty() {
             None
@@ -1264,6 +1584,149 @@ pub fn parse_spec(s: &str) -> Result<Vec<FormatSpec<'_>>, Error> {
     FormatSpecParser::new(s).collect()
 }
 
+/// An iterator over the [`FormatSpec`] of a format string.
+///
+/// This is returned by [`parse_spec_iter`]. Every raw fragment borrows from
+/// the original format string, so no allocation is done.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FormatSpecIter<'a>(FormatSpecParser<'a>);
+
+impl<'a> Iterator for FormatSpecIter<'a> {
+    type Item = Result<FormatSpec<'a>, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl core::iter::FusedIterator for FormatSpecIter<'_> {}
+
+/// Lazily parses a format string into an iterator of [`FormatSpec`].
+///
+/// Unlike [`parse_spec`], this does not allocate. This is useful in hot loops
+/// where the format string is only used once, such as when passed to
+/// [`DateTime::parse_from_spec_iter`].
+///
+/// ```
+/// use eos::fmt::{parse_spec, parse_spec_iter};
+///
+/// let specs: Result<Vec<_>, _> = parse_spec_iter("%Y-%m-%d").collect();
+/// assert_eq!(specs, parse_spec("%Y-%m-%d"));
+/// assert!(parse_spec_iter("%Y-%L").any(|spec| spec.is_err()));
+/// ```
+pub fn parse_spec_iter(s: &str) -> FormatSpecIter<'_> {
+    FormatSpecIter(FormatSpecParser::new(s))
+}
+
+/// An owned sequence of [`FormatSpec`].
+///
+/// A slice of [`FormatSpec`] borrows the string it was parsed from. This type instead
+/// copies every raw fragment into an internal buffer, allowing it to be stored without
+/// keeping the original format string alive. This is useful for caching format strings
+/// that are only known at runtime.
+///
+/// A reference to this type can be passed to the various `format` functions.
+///
+/// ```
+/// use eos::{date, fmt::OwnedFormatSpec};
+///
+/// let spec = {
+///     let input = String::from("%Y-%m-%d");
+///     OwnedFormatSpec::parse(&input)?
+/// };
+/// assert_eq!(date!(2021-01-30).format(&spec).to_string(), "2021-01-30");
+/// # Ok::<_, eos::fmt::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct OwnedFormatSpec {
+    // The raw fragments in here borrow from `buffer`. The 'static lifetime
+    // must never be exposed outside of this type.
+    //
+    // The fields are dropped in declaration order so `specs` is dropped before
+    // the `buffer` it borrows from.
+    specs: Vec<FormatSpec<'static>>,
+    // This is a `String` rather than a `Box<str>` since moving a `Box` asserts
+    // unique ownership of its contents, which would conflict with the borrows above.
+    buffer: String,
+}
+
+impl OwnedFormatSpec {
+    /// Creates a new [`OwnedFormatSpec`] by copying the given slice of [`FormatSpec`].
+    pub fn new(specs: &[FormatSpec<'_>]) -> Self {
+        let mut buffer = String::new();
+        for spec in specs {
+            if let FormatSpecKind::Raw(raw) | FormatSpecKind::Optional(raw) = spec.kind {
+                buffer.push_str(raw);
+            }
+        }
+
+        let mut owned = Vec::with_capacity(specs.len());
+        let mut offset = 0;
+        for spec in specs {
+            let spec = match spec.kind {
+                FormatSpecKind::Raw(raw) | FormatSpecKind::Optional(raw) => {
+                    let end = offset + raw.len();
+                    let raw = &buffer[offset..end];
+                    offset = end;
+                    let kind = match spec.kind {
+                        FormatSpecKind::Raw(_) => FormatSpecKind::Raw(raw),
+                        _ => FormatSpecKind::Optional(raw),
+                    };
+                    FormatSpec {
+                        kind,
+                        padding: spec.padding,
+                    }
+                }
+                _ => *spec,
+            };
+            // SAFETY: The only borrowed data remaining in the spec points into `buffer`.
+            // Moving a `String` only moves its pointer, length, and capacity while the bytes
+            // stay at the same heap address, so moving `Self` never invalidates the slices.
+            // The buffer is never mutated after this point so it can't reallocate, and it is
+            // only dropped after `specs`. The 'static lifetime is shortened back to the
+            // lifetime of `&self` in every accessor, so no slice can outlive the buffer.
+            owned.push(unsafe { core::mem::transmute::<FormatSpec<'_>, FormatSpec<'static>>(spec) });
+        }
+
+        Self { specs: owned, buffer }
+    }
+
+    /// Parses a format string into an [`OwnedFormatSpec`].
+    ///
+    /// This is the owned equivalent of [`parse_spec`].
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let specs = parse_spec(s)?;
+        Ok(Self::new(&specs))
+    }
+
+    /// Returns the underlying slice of [`FormatSpec`].
+    #[inline]
+    pub fn as_slice(&self) -> &[FormatSpec<'_>] {
+        &self.specs
+    }
+}
+
+impl Clone for OwnedFormatSpec {
+    fn clone(&self) -> Self {
+        // The derived implementation would borrow from the original buffer
+        Self::new(&self.specs)
+    }
+}
+
+impl<'a> AsRef<[FormatSpec<'a>]> for &'a OwnedFormatSpec {
+    fn as_ref(&self) -> &[FormatSpec<'a>] {
+        &self.specs
+    }
+}
+
+impl<'a> From<&[FormatSpec<'a>]> for OwnedFormatSpec {
+    fn from(specs: &[FormatSpec<'a>]) -> Self {
+        Self::new(specs)
+    }
+}
+
 /// Parses and validates format string at compile time.
 #[doc(inline)]
 #[cfg(feature = "macros")]
@@ -1382,6 +1845,43 @@ where
     Tz: TimeZone,
 {
     pub(crate) dt: &'a DateTime<Tz>,
+    pub(crate) nanoseconds: bool,
+}
+
+#[cfg(feature = "formatting")]
+impl<'a, Tz> Rfc3339Formatter<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    /// Formats the fractional seconds with up to nanosecond precision.
+    ///
+    /// By default the fractional seconds are formatted with microsecond precision.
+    /// With this toggle, up to 9 digits are used with the trailing zeroes trimmed,
+    /// similar to Go's `time.RFC3339Nano`. If there are no fractional seconds then
+    /// they're omitted entirely.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let dt = datetime!(2001-02-03 04:05:01).with_nanosecond(123_456_789).unwrap();
+    /// assert_eq!(dt.to_rfc3339().to_string(), "2001-02-03 04:05:01.123456+00:00");
+    /// assert_eq!(dt.to_rfc3339().with_nanoseconds().to_string(), "2001-02-03 04:05:01.123456789+00:00");
+    /// ```
+    pub fn with_nanoseconds(mut self) -> Self {
+        self.nanoseconds = true;
+        self
+    }
+}
+
+/// Formats a [`DateTime`] into the [HTTP date] format, also known as IMF-fixdate.
+///
+/// This is returned by [`DateTime::to_http_date`], which ensures the year fits in four digits.
+///
+/// [HTTP date]: https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.1.1
+#[cfg(feature = "formatting")]
+#[must_use]
+pub struct HttpDateFormatter<'a> {
+    pub(crate) dt: &'a DateTime<crate::Utc>,
 }
 
 #[cfg(feature = "formatting")]
@@ -1469,6 +1969,26 @@ where
     }
 }
 
+/// Converts a 24-hour clock hour into a 12-hour clock hour, e.g. 0 is 12 and 13 is 1.
+#[inline]
+#[cfg(feature = "formatting")]
+const fn hour12(hour: u8) -> u8 {
+    match hour % 12 {
+        0 => 12,
+        h => h,
+    }
+}
+
+/// Pads a year, such as `%Y` or `%G`, given a specified padding.
+#[inline]
+#[cfg(feature = "formatting")]
+fn pad_year(f: &mut core::fmt::Formatter<'_>, year: i16, spec: FormatSpecPadding) -> core::fmt::Result {
+    // This one's a bit special since the padding depends on whether
+    // it's 4 or 5 digits
+    let padding = if !(-9999..=9999).contains(&year) { 5 } else { 4 };
+    pad_number(f, year, spec, padding)
+}
+
 #[cfg(feature = "formatting")]
 impl<'a, 'b, Spec> core::fmt::Display for DateFormatter<'a, 'b, Spec>
 where
@@ -1477,7 +1997,7 @@ where
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for spec in self.spec.as_ref() {
             match spec.kind {
-                FormatSpecKind::Raw(s) => f.write_str(s)?,
+                FormatSpecKind::Raw(s) | FormatSpecKind::Optional(s) => f.write_str(s)?,
                 FormatSpecKind::AbbreviatedWeekday => f.write_str(abbreviated_weekday(self.date.weekday()))?,
                 FormatSpecKind::FullWeekday => f.write_str(full_weekday(self.date.weekday()))?,
                 FormatSpecKind::Weekday => {
@@ -1495,13 +2015,7 @@ where
                 FormatSpecKind::Month => {
                     pad_number(f, self.date.month(), spec.padding, 2)?;
                 }
-                FormatSpecKind::Year => {
-                    // This one's a bit special since the padding depends on whether
-                    // it's 4 or 5 digits
-                    let year = self.date.year();
-                    let padding = if !(-9999..=9999).contains(&year) { 5 } else { 4 };
-                    pad_number(f, year, spec.padding, padding)?;
-                }
+                FormatSpecKind::Year => pad_year(f, self.date.year(), spec.padding)?,
                 FormatSpecKind::SignedYear => {
                     // This one needs to be done manually
                     let year = self.date.year();
@@ -1517,9 +2031,7 @@ where
                         }
                     }
                 }
-                FormatSpecKind::IsoWeekYear => {
-                    pad_number(f, self.date.iso_week().year(), spec.padding, 4)?;
-                }
+                FormatSpecKind::IsoWeekYear => pad_year(f, self.date.iso_week().year(), spec.padding)?,
                 FormatSpecKind::IsoWeek => {
                     pad_number(f, self.date.iso_week().week(), spec.padding, 2)?;
                 }
@@ -1540,16 +2052,9 @@ where
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for spec in self.spec.as_ref() {
             match spec.kind {
-                FormatSpecKind::Raw(s) => f.write_str(s)?,
+                FormatSpecKind::Raw(s) | FormatSpecKind::Optional(s) => f.write_str(s)?,
                 FormatSpecKind::Hour => pad_number(f, self.time.hour(), spec.padding, 2)?,
-                FormatSpecKind::Hour12 => {
-                    let h = self.time.hour();
-                    if h <= 12 {
-                        pad_number(f, h, spec.padding, 2)?;
-                    } else {
-                        pad_number(f, h - 12, spec.padding, 2)?;
-                    }
-                }
+                FormatSpecKind::Hour12 => pad_number(f, hour12(self.time.hour()), spec.padding, 2)?,
                 FormatSpecKind::Meridiem => {
                     if self.time.hour() >= 12 {
                         f.write_str("PM")?
@@ -1591,7 +2096,7 @@ where
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for spec in self.spec.as_ref() {
             match spec.kind {
-                FormatSpecKind::Raw(s) => f.write_str(s)?,
+                FormatSpecKind::Raw(s) | FormatSpecKind::Optional(s) => f.write_str(s)?,
                 FormatSpecKind::AbbreviatedWeekday => f.write_str(abbreviated_weekday(self.dt.weekday()))?,
                 FormatSpecKind::FullWeekday => f.write_str(full_weekday(self.dt.weekday()))?,
                 FormatSpecKind::Weekday => {
@@ -1609,13 +2114,7 @@ where
                 FormatSpecKind::Month => {
                     pad_number(f, self.dt.month(), spec.padding, 2)?;
                 }
-                FormatSpecKind::Year => {
-                    // This one's a bit special since the padding depends on whether
-                    // it's 4 or 5 digits
-                    let year = self.dt.year();
-                    let padding = if !(-9999..=9999).contains(&year) { 5 } else { 4 };
-                    pad_number(f, year, spec.padding, padding)?;
-                }
+                FormatSpecKind::Year => pad_year(f, self.dt.year(), spec.padding)?,
                 FormatSpecKind::SignedYear => {
                     // This one needs to be done manually
                     let year = self.dt.year();
@@ -1631,21 +2130,12 @@ where
                         }
                     }
                 }
-                FormatSpecKind::IsoWeekYear => {
-                    pad_number(f, self.dt.iso_week().year(), spec.padding, 4)?;
-                }
+                FormatSpecKind::IsoWeekYear => pad_year(f, self.dt.iso_week().year(), spec.padding)?,
                 FormatSpecKind::IsoWeek => {
                     pad_number(f, self.dt.iso_week().week(), spec.padding, 2)?;
                 }
                 FormatSpecKind::Hour => pad_number(f, self.dt.hour(), spec.padding, 2)?,
-                FormatSpecKind::Hour12 => {
-                    let h = self.dt.hour();
-                    if h <= 12 {
-                        pad_number(f, h, spec.padding, 2)?;
-                    } else {
-                        pad_number(f, h - 12, spec.padding, 2)?;
-                    }
-                }
+                FormatSpecKind::Hour12 => pad_number(f, hour12(self.dt.hour()), spec.padding, 2)?,
                 FormatSpecKind::Meridiem => {
                     if self.dt.hour() >= 12 {
                         f.write_str("PM")?
@@ -1670,7 +2160,8 @@ where
                     pad_number(f, ns, spec.padding, 7)?
                 }
                 FormatSpecKind::UtcOffset => self.dt.offset().fmt(f)?,
-                FormatSpecKind::UtcOffsetBrief => {
+                FormatSpecKind::UtcOffsetBriefZ if self.dt.offset().is_utc() => f.write_char('Z')?,
+                FormatSpecKind::UtcOffsetBrief | FormatSpecKind::UtcOffsetBriefZ => {
                     let (hour, minute, second) = self.dt.offset().into_hms();
                     let (minute, second) = (minute.abs(), second.abs());
                     if second > 0 {
@@ -1684,6 +2175,7 @@ where
                         f.write_str(name)?;
                     }
                 }
+                FormatSpecKind::Timestamp => write!(f, "{}", self.dt.timestamp().as_seconds())?,
                 FormatSpecKind::Escape => f.write_char('%')?,
             }
         }
@@ -1691,6 +2183,29 @@ where
     }
 }
 
+#[cfg(feature = "formatting")]
+impl<'a> core::fmt::Display for HttpDateFormatter<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let time = self.dt.time();
+        let second = if time.nanosecond() >= 1_000_000_000 {
+            time.second() + 1
+        } else {
+            time.second()
+        };
+        write!(
+            f,
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            abbreviated_weekday(self.dt.weekday()),
+            self.dt.day(),
+            abbreviated_month(self.dt.month()),
+            self.dt.year(),
+            time.hour(),
+            time.minute(),
+            second
+        )
+    }
+}
+
 #[cfg(feature = "formatting")]
 impl<'a, Tz> core::fmt::Display for Rfc3339Formatter<'a, Tz>
 where
@@ -1700,37 +2215,36 @@ where
         let (h, m, _) = self.dt.offset().into_hms();
         let m = m.abs();
         let time = self.dt.time();
-        let mut us = time.microsecond();
         let mut s = time.second();
-        if us >= 1_000_000 {
-            s += 1;
-            us -= 1_000_000;
-        }
+        write!(f, "{} {:02}:{:02}:", self.dt.date(), time.hour(), time.minute())?;
+
+        if self.nanoseconds {
+            let mut ns = time.nanosecond();
+            if ns >= 1_000_000_000 {
+                s += 1;
+                ns -= 1_000_000_000;
+            }
 
-        if us != 0 {
-            write!(
-                f,
-                "{} {:02}:{:02}:{:02}.{:06}{:+03}:{:02}",
-                self.dt.date(),
-                time.hour(),
-                time.minute(),
-                s,
-                us,
-                h,
-                m
-            )
+            write!(f, "{:02}", s)?;
+            if ns != 0 {
+                let (ns, width) = crate::utils::trim_trailing_zeroes(ns as u64, 9);
+                write!(f, ".{:0width$}", ns, width = width)?;
+            }
         } else {
-            write!(
-                f,
-                "{} {:02}:{:02}:{:02}{:+03}:{:02}",
-                self.dt.date(),
-                time.hour(),
-                time.minute(),
-                s,
-                h,
-                m
-            )
+            let mut us = time.microsecond();
+            if us >= 1_000_000 {
+                s += 1;
+                us -= 1_000_000;
+            }
+
+            if us != 0 {
+                write!(f, "{:02}.{:06}", s, us)?;
+            } else {
+                write!(f, "{:02}", s)?;
+            }
         }
+
+        write!(f, "{:+03}:{:02}", h, m)
     }
 }
 
@@ -1751,6 +2265,12 @@ mod tests {
         let mut parser = Parser::new("-45678-02-13");
         assert_eq!(parser.parse_year(), Err(ParseError::OutOfBounds));
 
+        let mut parser = Parser::new("-32768-01-01");
+        assert_eq!(parser.parse_year(), Ok(i16::MIN));
+
+        let mut parser = Parser::new("+32768-01-01");
+        assert_eq!(parser.parse_year(), Err(ParseError::OutOfBounds));
+
         let mut parser = Parser::new("234");
         assert_eq!(parser.parse_year(), Err(ParseError::UnexpectedEnd));
 
@@ -10,6 +10,8 @@ Thanks to Joda-Time and Noda-Time for being inspirations as well.
 
 */
 
+use crate::Weekday;
+
 // The first index is unused
 pub(crate) const DAYS_IN_MONTH: [u8; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 pub(crate) const DAYS_BEFORE_MONTH: [u16; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
@@ -49,9 +51,9 @@ pub const fn date_to_ordinal(year: i16, month: u8, day: u8) -> u16 {
     days_before_month(year, month) + day as u16
 }
 
-const ERA_DURATION: i32 = 400;
+pub(crate) const ERA_DURATION: i32 = 400;
 /// The number of days in a 400 year period
-const DAYS_IN_ERA: i32 = 146097;
+pub(crate) const DAYS_IN_ERA: i32 = 146097;
 /// The number of days to go from 0000-03-01 to 1970-01-01
 const DAYS_TO_EPOCH: i32 = 719468;
 
@@ -167,6 +169,29 @@ pub const fn weekday_from_days(civil: i32) -> u8 {
     (civil + 4).rem_euclid(7) as u8
 }
 
+/// Returns the [`Weekday`] of a given year, month, and day.
+///
+/// Unlike [`weekday_from_days`] this returns the [`Weekday`] enum rather than a raw number.
+///
+/// Note that this method does *not* do any bound checking on `month` or `day` values.
+/// See [`date_to_epoch_days`] for more information.
+///
+/// ```rust
+/// use eos::{gregorian::weekday, Weekday};
+///
+/// assert_eq!(weekday(1970, 1, 1), Weekday::Thursday);
+/// assert_eq!(weekday(2021, 12, 25), Weekday::Saturday);
+/// assert_eq!(weekday(2012, 2, 29), Weekday::Wednesday);
+/// ```
+#[inline]
+pub const fn weekday(year: i16, month: u8, day: u8) -> Weekday {
+    match Weekday::from_days_from_sunday(weekday_from_days(date_to_epoch_days(year, month, day))) {
+        Some(weekday) => weekday,
+        // weekday_from_days always returns a number between 0 and 6
+        None => unreachable!(),
+    }
+}
+
 /// Returns the next weekday, given a weekday.
 ///
 /// Note that 0 is Sunday and 6 is Saturday
@@ -226,6 +251,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_weekday() {
+        use crate::date;
+
+        assert_eq!(weekday(1970, 1, 1), date!(1970 - 01 - 01).weekday());
+        assert_eq!(weekday(1969, 12, 31), date!(1969 - 12 - 31).weekday());
+        assert_eq!(weekday(2000, 2, 29), date!(2000 - 02 - 29).weekday());
+        assert_eq!(weekday(2021, 12, 25), date!(2021 - 12 - 25).weekday());
+        assert_eq!(weekday(-1, 3, 1), date!(-1 - 03 - 01).weekday());
+        assert_eq!(weekday(i16::MIN, 1, 1), crate::Date::MIN.weekday());
+        assert_eq!(weekday(i16::MAX, 12, 31), crate::Date::MAX.weekday());
+
+        let mut date = date!(1999 - 12 - 01);
+        for _ in 0..400 {
+            assert_eq!(weekday(date.year(), date.month(), date.day()), date.weekday());
+            date = date.add_days(1);
+        }
+    }
+
     #[test]
     fn test_weekday_diff() {
         #[rustfmt::skip]
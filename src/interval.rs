@@ -22,10 +22,12 @@ use crate::fmt::{FromIsoFormat, ParseError, Parser};
 pub(crate) const NANOS_PER_SEC: u64 = 1_000_000_000;
 pub(crate) const NANOS_PER_MIN: u64 = 60 * NANOS_PER_SEC;
 pub(crate) const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MIN;
+pub(crate) const NANOS_PER_DAY: u64 = 24 * NANOS_PER_HOUR;
 
 pub(crate) const MICROS_PER_SEC: i64 = 1_000_000;
 pub(crate) const MICROS_PER_MIN: i64 = 60 * MICROS_PER_SEC;
 pub(crate) const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MIN;
+pub(crate) const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
 
 /// An interval of time such as 2 years, 30 minutes, etc.
 ///
@@ -81,6 +83,19 @@ impl Interval {
         Self { months, ..Self::ZERO }
     }
 
+    /// Creates a [`Interval`] representing the specified number of quarters.
+    ///
+    /// A quarter is 3 months. If the computation would end up overflowing then the
+    /// value is saturated to the upper bounds.
+    #[inline]
+    #[must_use]
+    pub const fn from_quarters(quarters: i32) -> Self {
+        Self {
+            months: quarters.saturating_mul(3),
+            ..Self::ZERO
+        }
+    }
+
     /// Creates a [`Interval`] representing the specified number of weeks.
     #[inline]
     #[must_use]
@@ -147,6 +162,158 @@ impl Interval {
         }
     }
 
+    /// Creates a [`Interval`] from the raw months, days, and microseconds components.
+    ///
+    /// This matches the layout of the PostgreSQL `INTERVAL` type, which is stored as
+    /// a 32-bit number of months, a 32-bit number of days, and a 64-bit number of
+    /// microseconds. Like PostgreSQL, the components are kept separate and are not
+    /// normalized into each other since a month does not have a fixed number of days
+    /// and a day does not always have 24 hours.
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// let interval = Interval::from_postgres(14, 3, 3_600_000_000);
+    /// assert_eq!(interval, 1.years() + 2.months() + 3.days() + 1.hours());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_postgres(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Returns the raw months, days, and microseconds components of this interval.
+    ///
+    /// This is the inverse of [`Interval::from_postgres`] and matches the layout
+    /// of the PostgreSQL `INTERVAL` type.
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// let interval = 1.years() + 2.months() + 3.days() + 1.hours();
+    /// assert_eq!(interval.to_postgres(), (14, 3, 3_600_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_postgres(&self) -> (i32, i32, i64) {
+        (self.months, self.days, self.microseconds)
+    }
+
+    /// Moves every whole 24 hours of the time component into the days component.
+    ///
+    /// Intervals such as those returned by [`Interval::between`] can end up with a time
+    /// component that spans more than a day. This carries the excess over into the
+    /// days, keeping the remainder as the time component. The sign of the remainder
+    /// matches the sign of the original time component.
+    ///
+    /// The months are left untouched since a month does not have a fixed number of
+    /// days. Likewise, this assumes that every day is 24 hours long, which is not
+    /// true around daylight saving time transitions.
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// let interval = 26.hours().normalize_time();
+    /// assert_eq!(interval, 1.days() + 2.hours());
+    /// assert_eq!(interval.days(), 1);
+    /// assert_eq!(interval.hours(), 2);
+    /// ```
+    ///
+    /// If the days would overflow then they saturate at the numeric bounds instead, which
+    /// loses the excess days. Use [`Interval::checked_normalize_time`] to detect this.
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn normalize_time(self) -> Self {
+        Self {
+            months: self.months,
+            days: self.days.saturating_add((self.microseconds / MICROS_PER_DAY) as i32),
+            microseconds: self.microseconds % MICROS_PER_DAY,
+        }
+    }
+
+    /// Moves every whole 24 hours of the time component into the days component,
+    /// returning [`None`] if the days would overflow.
+    ///
+    /// See [`Interval::normalize_time`] for more information.
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// assert_eq!(26.hours().checked_normalize_time(), Some(1.days() + 2.hours()));
+    /// assert_eq!((Interval::from_days(i32::MAX) + 24.hours()).checked_normalize_time(), None);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_normalize_time(self) -> Option<Self> {
+        let days = match self.days.checked_add((self.microseconds / MICROS_PER_DAY) as i32) {
+            Some(days) => days,
+            None => return None,
+        };
+        Some(Self {
+            months: self.months,
+            days,
+            microseconds: self.microseconds % MICROS_PER_DAY,
+        })
+    }
+
+    /// Rolls the excess days into whole months, starting from the given anchor date.
+    ///
+    /// Since months vary in length, the number of days that make up a month depends on
+    /// where the interval starts. This adds the interval's months and days to the anchor
+    /// and then recomputes them as the whole months and remaining days between the two
+    /// dates. This means the result is **anchor-dependent**, e.g. 31 days is 1 month
+    /// starting from January 1st but 1 month and 3 days starting from February 1st 2021.
+    ///
+    /// The time component is left untouched. See [`Interval::normalize_time`] to roll it
+    /// into the days first. Arithmetic with the original interval is not affected by this,
+    /// it's mainly useful for displaying human friendly durations.
+    ///
+    /// ```
+    /// use eos::{date, ext::IntervalLiteral};
+    ///
+    /// let interval = 45.days().normalize_with(date!(2021-01-01));
+    /// assert_eq!(interval, 1.months() + 14.days());
+    /// assert_eq!(31.days().normalize_with(date!(2021-01-01)), 1.months());
+    /// assert_eq!(31.days().normalize_with(date!(2021-02-01)), 1.months() + 3.days());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn normalize_with(self, anchor: Date) -> Self {
+        let end = anchor.add_months(self.months).add_days(self.days);
+        Self {
+            microseconds: self.microseconds,
+            ..Self::between_dates(&anchor, &end)
+        }
+    }
+
+    /// Returns the remainder of the time component after removing every whole unit
+    /// of the given number of microseconds, e.g. the leftover after whole hours.
+    ///
+    /// The months and days cannot be reduced this way since they vary in length, so they
+    /// are not part of the result. The sign of the remainder matches the sign of the time
+    /// component, similar to the `%` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit_micros` is zero.
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// let hour = Interval::from_hours(1).total_microseconds();
+    /// assert_eq!(100.minutes().rem_time(hour), 40.minutes());
+    /// assert_eq!((2.days() + 90.seconds()).rem_time(60_000_000), 30.seconds());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn rem_time(&self, unit_micros: i64) -> Self {
+        assert!(unit_micros != 0, "unit_micros must not be zero");
+        Self::from_microseconds(self.microseconds.wrapping_rem(unit_micros))
+    }
+
     /// Returns the number of *whole* years within this interval.
     #[inline]
     #[must_use]
@@ -253,6 +420,414 @@ impl Interval {
         self.microseconds
     }
 
+    /// Returns the time component of this interval in fractional seconds.
+    ///
+    /// This ignores the calendar components, i.e. the months and days, since
+    /// their length in seconds depends on when the interval is applied.
+    /// For a conversion that includes them, see [`Interval::as_seconds_f64`].
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// let interval = 1.days() + 1.hours() + 500.milliseconds();
+    /// assert_eq!(interval.time_component_seconds_f64(), 3600.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn time_component_seconds_f64(&self) -> f64 {
+        self.microseconds as f64 / MICROS_PER_SEC as f64
+    }
+
+    /// Returns the approximate number of seconds this interval elapses when applied
+    /// to the given anchor datetime.
+    ///
+    /// The calendar components of the interval, i.e. the months and days, vary in length
+    /// so they're resolved by adding this interval to the anchor. The result is the
+    /// number of seconds between the anchor and that datetime, which takes into account
+    /// the timezone's UTC offset changes such as DST transitions.
+    ///
+    /// ```
+    /// use eos::{datetime, ext::IntervalLiteral};
+    ///
+    /// let anchor = datetime!(2020-02-01 00:00);
+    /// assert_eq!(1.months().as_seconds_f64(&anchor), 29.0 * 86400.0);
+    /// assert_eq!((1.years() + 500.milliseconds()).as_seconds_f64(&anchor), 366.0 * 86400.0 + 0.5);
+    /// ```
+    #[must_use]
+    pub fn as_seconds_f64<Tz>(&self, anchor: &DateTime<Tz>) -> f64
+    where
+        Tz: TimeZone,
+    {
+        let start = anchor.timestamp();
+        let end = (anchor.clone() + *self).timestamp();
+        // Subtract before converting to minimise the floating point error
+        let seconds = end.as_seconds() - start.as_seconds();
+        let nanoseconds = end.nanoseconds as i64 - start.nanoseconds as i64;
+        seconds as f64 + nanoseconds as f64 / 1e9
+    }
+
+    /// Returns an approximate number of seconds this interval elapses without an anchor.
+    ///
+    /// This assumes that every month is 30.44 days long and that every day is 86400 seconds
+    /// long. The result is **not** exact and should not be used for arithmetic. However, it
+    /// gives a single scalar for ordering intervals by magnitude, which the [`Ord`]
+    /// implementation does not since it compares the months, days, and time components
+    /// lexicographically. For an exact conversion see [`Interval::as_seconds_f64`].
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!(1.days().approx_seconds_f64(), 86400.0);
+    /// assert_eq!(1.months().approx_seconds_f64(), 30.44 * 86400.0);
+    ///
+    /// let mut intervals = [1.months(), 20.days(), (-1).years(), 5.hours()];
+    /// intervals.sort_by(|a, b| a.approx_seconds_f64().total_cmp(&b.approx_seconds_f64()));
+    /// assert_eq!(intervals, [(-1).years(), 5.hours(), 20.days(), 1.months()]);
+    /// ```
+    #[must_use]
+    pub fn approx_seconds_f64(&self) -> f64 {
+        const SECONDS_PER_DAY: f64 = 86400.0;
+        const SECONDS_PER_MONTH: f64 = 30.44 * SECONDS_PER_DAY;
+        self.months as f64 * SECONDS_PER_MONTH + self.days as f64 * SECONDS_PER_DAY + self.time_component_seconds_f64()
+    }
+
+    /// Returns the approximate number of microseconds this interval elapses, using the same
+    /// assumptions as [`Interval::approx_seconds_f64`].
+    const fn approx_microseconds(&self) -> i128 {
+        // 30.44 days is exactly 2630016 seconds
+        const MICROS_PER_MONTH: i128 = 2_630_016 * MICROS_PER_SEC as i128;
+        self.months as i128 * MICROS_PER_MONTH + self.days as i128 * MICROS_PER_DAY as i128 + self.microseconds as i128
+    }
+
+    /// Returns the approximate number of whole units of the given length, truncated towards zero.
+    const fn whole_units_approx(&self, micros_per_unit: i64) -> i64 {
+        // This cannot overflow since the largest interval is below 2^53 seconds
+        (self.approx_microseconds() / micros_per_unit as i128) as i64
+    }
+
+    /// Returns the approximate number of whole days this interval elapses.
+    ///
+    /// Every month is assumed to be 30.44 days long, similar to [`Interval::approx_seconds_f64`],
+    /// so this is only approximate if the interval has any months. The result is truncated
+    /// towards zero.
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!((2.days() + 23.hours()).whole_days_approx(), 2);
+    /// assert_eq!(1.months().whole_days_approx(), 30);
+    /// assert_eq!(1.years().whole_days_approx(), 365);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn whole_days_approx(&self) -> i64 {
+        self.whole_units_approx(MICROS_PER_DAY)
+    }
+
+    /// Returns the approximate number of whole hours this interval elapses.
+    ///
+    /// Every month is assumed to be 30.44 days and every day 24 hours long, so this is only
+    /// approximate if the interval has any months or days. The result is truncated towards
+    /// zero.
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!(90.minutes().whole_hours_approx(), 1);
+    /// assert_eq!((1.days() + 2.hours()).whole_hours_approx(), 26);
+    /// assert_eq!(1.months().whole_hours_approx(), 730);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn whole_hours_approx(&self) -> i64 {
+        self.whole_units_approx(MICROS_PER_HOUR)
+    }
+
+    /// Returns the approximate number of whole minutes this interval elapses.
+    ///
+    /// See [`Interval::whole_hours_approx`] for the assumptions made.
+    #[inline]
+    #[must_use]
+    pub const fn whole_minutes_approx(&self) -> i64 {
+        self.whole_units_approx(MICROS_PER_MIN)
+    }
+
+    /// Returns the approximate number of whole seconds this interval elapses.
+    ///
+    /// See [`Interval::whole_hours_approx`] for the assumptions made.
+    #[inline]
+    #[must_use]
+    pub const fn whole_seconds_approx(&self) -> i64 {
+        self.whole_units_approx(MICROS_PER_SEC)
+    }
+
+    /// Returns a human readable English description of this interval.
+    ///
+    /// Only the two largest non-zero units are written, with the units pluralized as
+    /// needed, e.g. `1 year 2 months` or `3 minutes 4 seconds`. The remaining smaller
+    /// units are truncated. An interval of zero is written as `0 seconds`.
+    ///
+    /// Negative intervals are written with an `ago` suffix, e.g. `2 hours 5 minutes ago`.
+    /// The sign is taken from the largest unit, so a smaller unit with the opposite sign
+    /// is written as a negative number.
+    ///
+    /// Like the other accessors, the time component is not rolled over into days. To do
+    /// so, use [`Interval::normalize_time`] beforehand.
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!((1.years() + 2.months() + 3.days()).humanize(), "1 year 2 months");
+    /// assert_eq!((3.minutes() + 4.seconds()).humanize(), "3 minutes 4 seconds");
+    /// assert_eq!((-(2.hours() + 5.minutes())).humanize(), "2 hours 5 minutes ago");
+    /// assert_eq!(1.days().humanize(), "1 day");
+    /// ```
+    #[cfg(feature = "formatting")]
+    #[must_use]
+    pub fn humanize(&self) -> String {
+        let units = [
+            ((self.months / 12) as i64, "year"),
+            ((self.months % 12) as i64, "month"),
+            (self.days as i64, "day"),
+            (self.hours(), "hour"),
+            (self.minutes(), "minute"),
+            (self.seconds(), "second"),
+            (self.microseconds() / 1000, "millisecond"),
+            (self.microseconds() % 1000, "microsecond"),
+        ];
+
+        let mut units = units.iter().skip_while(|(value, _)| *value == 0);
+        let (value, unit) = match units.next() {
+            Some(&(value, unit)) => (value, unit),
+            None => return String::from("0 seconds"),
+        };
+
+        // Every unit is written relative to the sign of the largest one
+        let sign = value.signum();
+        let mut buffer = String::new();
+        write_unit(&mut buffer, value * sign, unit);
+        if let Some(&(next, unit)) = units.find(|(value, _)| *value != 0) {
+            buffer.push(' ');
+            write_unit(&mut buffer, next * sign, unit);
+        }
+
+        if value < 0 {
+            buffer.push_str(" ago");
+        }
+        buffer
+    }
+
+    /// Converts the time component of this interval into a [`Duration`].
+    ///
+    /// Months and days cannot be converted without an anchor date since their lengths
+    /// vary, so [`None`] is returned if either of them are present. [`None`] is also
+    /// returned if the interval is negative since a [`Duration`] cannot be negative.
+    /// To treat days as exactly 24 hours, see [`Interval::to_std_duration_with_days`].
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// let interval = 2.hours() + 30.minutes() + 250.milliseconds();
+    /// assert_eq!(interval.to_std_duration(), Some(Duration::from_millis(9_000_250)));
+    /// assert_eq!((-5).seconds().to_std_duration(), None);
+    /// assert_eq!(1.days().to_std_duration(), None);
+    /// assert_eq!(1.months().to_std_duration(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_std_duration(&self) -> Option<Duration> {
+        self.to_std_duration_with_days(false)
+    }
+
+    /// Converts this interval into a [`Duration`], optionally treating days as 86400 seconds.
+    ///
+    /// If `days_are_24h` is `false` then this behaves the same as [`Interval::to_std_duration`]
+    /// and returns [`None`] if there are any days. Note that a day is not always 24 hours
+    /// long, e.g. during daylight saving time transitions.
+    ///
+    /// Months cannot be converted without an anchor date since their length varies, so
+    /// [`None`] is always returned if there are any months. [`None`] is also returned if the
+    /// resulting total is negative.
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// let interval = 1.days() + 2.hours();
+    /// assert_eq!(interval.to_std_duration_with_days(true), Some(Duration::from_secs(93_600)));
+    /// assert_eq!(interval.to_std_duration_with_days(false), None);
+    /// assert_eq!((1.days() - 2.hours()).to_std_duration_with_days(true), Some(Duration::from_secs(79_200)));
+    /// assert_eq!((1.months() + 1.days()).to_std_duration_with_days(true), None);
+    /// ```
+    #[must_use]
+    pub const fn to_std_duration_with_days(&self, days_are_24h: bool) -> Option<Duration> {
+        if self.days != 0 && !days_are_24h {
+            return None;
+        }
+
+        match self.to_duration() {
+            Ok(duration) => Some(duration),
+            Err(_) => None,
+        }
+    }
+
+    /// Converts this interval into a [`Duration`], treating days as exactly 86400 seconds.
+    ///
+    /// This is the same as [`Interval::to_duration_with_days_as`] with 86400 seconds per day.
+    ///
+    /// # Errors
+    ///
+    /// Months cannot be converted without an anchor date since their length varies, so
+    /// [`crate::Error::OutOfRange`] is returned if there are any months. The same error is
+    /// returned if the resulting total is negative since a [`Duration`] cannot be negative.
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use eos::{ext::IntervalLiteral, Error};
+    ///
+    /// assert_eq!((2.hours() + 500.milliseconds()).to_duration(), Ok(Duration::from_millis(7_200_500)));
+    /// assert_eq!((1.days() + 2.hours()).to_duration(), Ok(Duration::from_secs(93_600)));
+    /// assert_eq!((1.hours() - 1.days()).to_duration(), Err(Error::OutOfRange));
+    /// assert_eq!(1.months().to_duration(), Err(Error::OutOfRange));
+    /// ```
+    #[inline]
+    pub const fn to_duration(&self) -> Result<Duration, crate::Error> {
+        self.to_duration_with_days_as(86400)
+    }
+
+    /// Converts this interval into a [`Duration`], treating each day as the given number of seconds.
+    ///
+    /// The time component is converted directly without any loss of precision.
+    ///
+    /// # Errors
+    ///
+    /// [`crate::Error::OutOfRange`] is returned if there are any months, if the resulting
+    /// total is negative, or if it's too large to fit in a [`Duration`].
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use eos::{ext::IntervalLiteral, Error};
+    ///
+    /// let interval = 2.days() + 30.minutes();
+    /// assert_eq!(interval.to_duration_with_days_as(3600), Ok(Duration::from_secs(9000)));
+    /// assert_eq!(interval.to_duration_with_days_as(0), Ok(Duration::from_secs(1800)));
+    /// assert_eq!((30.minutes() - 1.days()).to_duration_with_days_as(3600), Err(Error::OutOfRange));
+    /// ```
+    pub const fn to_duration_with_days_as(&self, secs_per_day: u64) -> Result<Duration, crate::Error> {
+        if self.months != 0 {
+            return Err(crate::Error::OutOfRange);
+        }
+
+        // This cannot overflow since the days are at most 2^31 * 2^64 * 10^6 which is below 2^116
+        let micros = self.days as i128 * secs_per_day as i128 * MICROS_PER_SEC as i128 + self.microseconds as i128;
+        if micros < 0 {
+            return Err(crate::Error::OutOfRange);
+        }
+        let seconds = micros / MICROS_PER_SEC as i128;
+        if seconds > u64::MAX as i128 {
+            return Err(crate::Error::OutOfRange);
+        }
+        let nanoseconds = (micros % MICROS_PER_SEC as i128) as u32 * 1_000;
+        Ok(Duration::new(seconds as u64, nanoseconds))
+    }
+
+    /// Checked [`Interval`] addition. Computes `self + other`, returning [`None`]
+    /// if any of the underlying components would overflow.
+    ///
+    /// ```rust
+    /// use eos::Interval;
+    ///
+    /// let interval = Interval::from_days(10);
+    /// assert_eq!(interval.checked_add(Interval::from_days(5)), Some(Interval::from_days(15)));
+    /// assert_eq!(interval.checked_add(Interval::from_days(i32::MAX)), None);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        let months = match self.months.checked_add(other.months) {
+            Some(months) => months,
+            None => return None,
+        };
+        let days = match self.days.checked_add(other.days) {
+            Some(days) => days,
+            None => return None,
+        };
+        let microseconds = match self.microseconds.checked_add(other.microseconds) {
+            Some(microseconds) => microseconds,
+            None => return None,
+        };
+        Some(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Checked [`Interval`] subtraction. Computes `self - other`, returning [`None`]
+    /// if any of the underlying components would overflow.
+    ///
+    /// ```rust
+    /// use eos::Interval;
+    ///
+    /// let interval = Interval::from_days(10);
+    /// assert_eq!(interval.checked_sub(Interval::from_days(5)), Some(Interval::from_days(5)));
+    /// assert_eq!(Interval::from_days(-10).checked_sub(Interval::from_days(i32::MAX)), None);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        let months = match self.months.checked_sub(other.months) {
+            Some(months) => months,
+            None => return None,
+        };
+        let days = match self.days.checked_sub(other.days) {
+            Some(days) => days,
+            None => return None,
+        };
+        let microseconds = match self.microseconds.checked_sub(other.microseconds) {
+            Some(microseconds) => microseconds,
+            None => return None,
+        };
+        Some(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Checked [`Interval`] multiplication. Computes `self * factor`, returning [`None`]
+    /// if any of the underlying components would overflow.
+    ///
+    /// ```rust
+    /// use eos::Interval;
+    ///
+    /// let interval = Interval::from_months(2) + Interval::from_hours(3);
+    /// assert_eq!(interval.checked_mul(4), Some(Interval::from_months(8) + Interval::from_hours(12)));
+    /// assert_eq!(interval.checked_mul(i32::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_mul(self, factor: i32) -> Option<Self> {
+        let months = match self.months.checked_mul(factor) {
+            Some(months) => months,
+            None => return None,
+        };
+        let days = match self.days.checked_mul(factor) {
+            Some(days) => days,
+            None => return None,
+        };
+        let microseconds = match self.microseconds.checked_mul(factor as i64) {
+            Some(microseconds) => microseconds,
+            None => return None,
+        };
+        Some(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
     /// Constructs an [`Interval`] between two dates.
     ///
     /// If `end` is before `start` then each property will be negative.
@@ -276,12 +851,12 @@ impl Interval {
 
         let mut result = *start;
         let years = years_between(&result, end);
-        result = result.add_years(years);
+        result = result.add_months(years * 12);
         let months = months_between(&result, end);
         result = result.add_months(months);
         let days = end.days_since_epoch() - result.days_since_epoch();
         Self {
-            months: years as i32 * 12 + months,
+            months: years * 12 + months,
             days,
             ..Self::ZERO
         }
@@ -403,7 +978,9 @@ impl Interval {
             } else {
                 (Ordering::Greater, -1)
             };
-            if offset.cmp_cross_timezone(end) == cmp {
+            // The estimate is based on the local dates which might be in different
+            // timezones, so it can overshoot by more than a single month
+            while offset.cmp_cross_timezone(end) == cmp {
                 months += inc;
                 offset = start.clone().add_months(months);
             }
@@ -444,19 +1021,25 @@ impl Interval {
     ///
     /// The first boolean argument is whether the time ended up being negative.
     pub(crate) fn get_time_duration(&self) -> (bool, Duration) {
-        let (seconds, microseconds) = divmod!(self.microseconds, MICROS_PER_SEC);
-        let nanoseconds = (microseconds as u32).saturating_mul(1_000);
-        (seconds < 0, Duration::new(seconds.unsigned_abs(), nanoseconds))
+        // The magnitude has to be split rather than the signed value, otherwise
+        // e.g. -1.5 seconds would floor into -2 seconds and 0.5 seconds
+        let microseconds = self.microseconds.unsigned_abs();
+        let nanoseconds = (microseconds % MICROS_PER_SEC as u64) as u32 * 1_000;
+        (
+            self.microseconds < 0,
+            Duration::new(microseconds / MICROS_PER_SEC as u64, nanoseconds),
+        )
     }
 }
 
 // Lower level algorithms to compute intervals
-fn years_between(start: &Date, end: &Date) -> i16 {
+fn years_between(start: &Date, end: &Date) -> i32 {
     // Assume we're starting from 2019-01-30 and ending at 2021-02-14
     // First get the raw difference in years (in this example, 2)
-    let diff = end.year() - start.year();
+    // This is done in i32 since the difference between the extreme years doesn't fit in an i16
+    let diff = end.year() as i32 - start.year() as i32;
     // Check the start date at the ending year... (in this example, 2021-01-30)
-    let location = start.add_years(diff);
+    let location = start.add_months(diff * 12);
 
     // If our start time is earlier then we've moved forward in time
     if start <= end {
@@ -503,6 +1086,24 @@ impl From<UtcOffset> for Interval {
     }
 }
 
+/// Converts the raw `(months, days, microseconds)` components into an [`Interval`].
+///
+/// This is equivalent to [`Interval::from_postgres`].
+impl From<(i32, i32, i64)> for Interval {
+    fn from((months, days, microseconds): (i32, i32, i64)) -> Self {
+        Self::from_postgres(months, days, microseconds)
+    }
+}
+
+/// Converts an [`Interval`] into its raw `(months, days, microseconds)` components.
+///
+/// This is equivalent to [`Interval::to_postgres`].
+impl From<Interval> for (i32, i32, i64) {
+    fn from(interval: Interval) -> Self {
+        interval.to_postgres()
+    }
+}
+
 impl Neg for Interval {
     type Output = Self;
 
@@ -621,18 +1222,28 @@ impl Add<DateTime> for Interval {
     }
 }
 
+/// Writes a single humanized unit such as `1 day` or `2 hours` for [`Interval::humanize`].
+#[cfg(feature = "formatting")]
+fn write_unit(buffer: &mut String, value: i64, unit: &str) {
+    let plural = if value.unsigned_abs() == 1 { "" } else { "s" };
+    write!(buffer, "{} {}{}", value, unit, plural).expect("unexpected error when writing string");
+}
+
 impl core::fmt::Display for Interval {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self == &Self::ZERO {
             return f.write_str("PT0S");
         }
         f.write_char('P')?;
-        if self.years() != 0 {
-            write!(f, "{}Y", self.years())?;
+        // Both of these are computed directly to avoid the truncation in Interval::years
+        let years = self.months / 12;
+        let months = self.months % 12;
+        if years != 0 {
+            write!(f, "{}Y", years)?;
         }
 
-        if self.months() != 0 {
-            write!(f, "{}M", self.months())?;
+        if months != 0 {
+            write!(f, "{}M", months)?;
         }
 
         if self.days != 0 {
@@ -651,16 +1262,19 @@ impl core::fmt::Display for Interval {
             write!(f, "{}M", self.minutes())?;
         }
 
+        let seconds = self.seconds();
         let microseconds = self.microseconds();
         if microseconds == 0 {
-            if self.seconds() != 0 {
-                write!(f, "{}S", self.seconds())?;
+            if seconds != 0 {
+                write!(f, "{}S", seconds)?;
             }
         } else {
-            let as_frac = (self.microseconds % MICROS_PER_MIN) as f64 / MICROS_PER_SEC as f64;
-            if as_frac != 0.0 {
-                write!(f, "{}S", as_frac)?
+            // The sign needs to be written manually when there are no whole seconds, e.g. -0.5S
+            if seconds == 0 && microseconds < 0 {
+                f.write_char('-')?;
             }
+            let (fraction, width) = crate::utils::trim_trailing_zeroes(microseconds.unsigned_abs(), 6);
+            write!(f, "{}.{:0width$}S", seconds, fraction, width = width)?;
         }
 
         Ok(())
@@ -684,8 +1298,9 @@ impl ToIsoFormat for Interval {
 #[cfg(feature = "parsing")]
 #[derive(Copy, Clone, Default)]
 struct ParseState {
-    years: i16,
+    years: i32,
     months: i32,
+    weeks: i32,
     days: i32,
     hours: i32,
     minutes: i32,
@@ -712,22 +1327,28 @@ impl FromIsoFormat for Interval {
     ///
     /// The syntax accepted by this function deviated from the actual ISO-8601 standard
     /// since it accepts negative numbers. The base syntax accepted is something similar
-    /// to `PnYnMnDTnHnMn.nS`.
+    /// to `PnYnMnWnDTnHnMn.nS`.
     ///
     /// The string can start with an optional sign, denoted by the ASCII negative or positive symbol.
-    /// If negative, the whole period is negated. The accepted units are `Y`, `M`, `D`, `H`, and `S`.
-    /// They must be in uppercase. Up to 9 digits of precision are supported by all units except years,
-    /// which must be up to 5 digits. Note that fractions are only supported in the seconds position
-    /// and only up to 6 digits of precision are supported.
+    /// If negative, the whole period is negated. The accepted units are `Y`, `M`, `W`, `D`, `H`, and `S`.
+    /// They must be in uppercase. Up to 9 digits of precision are supported by all units.
+    /// Note that fractions are only supported in the seconds position and only up to 6 digits
+    /// of precision are supported.
+    ///
+    /// Weeks are converted into 7 days and added to the number of days. Unlike the ISO-8601
+    /// standard, the week unit can be mixed with the other units, e.g. `P1Y2W3D`. Since
+    /// [`Interval`] does not store weeks separately, these are formatted back as days.
     ///
     /// Some example strings:
     ///
     /// - `PT15M` (15 minutes)
     /// - `PT20.5S` (20.5 seconds)
     /// - `P10Y2M3DT10S` (10 years, 2 months, 3 days, and 10 seconds).
+    /// - `P3Y6M4W2DT12H30M5S` (3 years, 6 months, 30 days, 12 hours, 30 minutes, and 5 seconds).
     /// - `-P30D` (-30 days)
     /// - `P-30D` (-30 days)
     /// - `-P-30DT30S` (30 days and -30 seconds).
+    /// - `PT-0.5S` (-0.5 seconds)
     ///
     fn from_iso_format(s: &str) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
@@ -761,13 +1382,15 @@ impl FromIsoFormat for Interval {
                 _ => {}
             }
 
+            // A value such as -0.5 would lose its sign once parsed as an integer
+            let value_negative = parser.peek() == Some(b'-');
             let value = parser.parse_i32()?;
             match parser.advance() {
                 Some(b'Y') => {
                     if time_units {
                         return Err(ParseError::UnexpectedChar('Y'));
                     }
-                    result.years = i16::try_from(value)?;
+                    result.years = value;
                 }
                 Some(b'M') => {
                     if time_units {
@@ -776,6 +1399,12 @@ impl FromIsoFormat for Interval {
                         result.months = value;
                     }
                 }
+                Some(b'W') => {
+                    if time_units {
+                        return Err(ParseError::UnexpectedChar('W'));
+                    }
+                    result.weeks = value;
+                }
                 Some(b'D') => {
                     if time_units {
                         return Err(ParseError::UnexpectedChar('D'));
@@ -807,7 +1436,7 @@ impl FromIsoFormat for Interval {
                         return Err(ParseError::UnexpectedChar(c as char));
                     }
 
-                    if value < 0 {
+                    if value_negative {
                         micros = -micros;
                     }
                     result.microseconds = micros;
@@ -820,16 +1449,22 @@ impl FromIsoFormat for Interval {
             parsed_once = true;
         }
 
-        let months = (result.years as i32 * 12)
-            .checked_add(result.months)
+        let months = result
+            .years
+            .checked_mul(12)
+            .and_then(|m| m.checked_add(result.months))
+            .ok_or(ParseError::OutOfBounds)?;
+        let days = result
+            .weeks
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(result.days))
             .ok_or(ParseError::OutOfBounds)?;
-        let days = result.days;
         let microseconds = result.to_micros().ok_or(ParseError::OutOfBounds)?;
         Ok(if negative {
             Self {
-                months: -months,
-                days: -days,
-                microseconds: -microseconds,
+                months: months.checked_neg().ok_or(ParseError::OutOfBounds)?,
+                days: days.checked_neg().ok_or(ParseError::OutOfBounds)?,
+                microseconds: microseconds.checked_neg().ok_or(ParseError::OutOfBounds)?,
             }
         } else {
             Self {
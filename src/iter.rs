@@ -1,10 +1,12 @@
 //! Iterator types for iterating over dates and times.
 //!
 //! This module contains the named types that are returned in iterators
-//! such as [`DateTime::every`].
+//! such as [`DateTime::every`] or [`Date::range_inclusive`].
 //!
 
-use crate::{DateTime, Interval, Time, TimeZone, Weekday};
+use core::iter::FusedIterator;
+
+use crate::{Date, DateTime, Interval, Time, TimeZone, Weekday};
 
 /// An iterator builder over a [`DateTime`] recurrence.
 ///
@@ -17,6 +19,7 @@ pub struct Every<Tz: TimeZone> {
     until: Option<DateTime<Tz>>,
     weekday: Option<Weekday>,
     time: Option<Time>,
+    anchored: bool,
 }
 
 impl<Tz: TimeZone> Every<Tz> {
@@ -27,9 +30,59 @@ impl<Tz: TimeZone> Every<Tz> {
             until: None,
             weekday: None,
             time: None,
+            anchored: false,
         }
     }
 
+    /// Sets the recurrence to happen every month.
+    ///
+    /// This overrides the interval that was originally given. Unlike repeatedly
+    /// adding a month, every recurrence is computed from the starting date. This
+    /// means that the day of the month is clamped on every step rather than drifting.
+    /// For example, starting from January 31st yields February 28th (or 29th),
+    /// March 31st, April 30th, and so on.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let start = datetime!(2024-01-31 10:00);
+    /// let dates: Vec<_> = start.every(Default::default()).monthly().into_iter().take(3).collect();
+    /// assert_eq!(dates, [
+    ///     datetime!(2024-02-29 10:00),
+    ///     datetime!(2024-03-31 10:00),
+    ///     datetime!(2024-04-30 10:00),
+    /// ]);
+    /// ```
+    pub fn monthly(mut self) -> Self {
+        self.interval = Interval::from_months(1);
+        self.anchored = true;
+        self
+    }
+
+    /// Sets the recurrence to happen every year.
+    ///
+    /// This overrides the interval that was originally given. Similar to [`Every::monthly`],
+    /// every recurrence is computed from the starting date, meaning that February 29th
+    /// is clamped to February 28th on non-leap years but not on leap years.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let start = datetime!(2024-02-29 10:00);
+    /// let dates: Vec<_> = start.every(Default::default()).yearly().into_iter().take(4).collect();
+    /// assert_eq!(dates, [
+    ///     datetime!(2025-02-28 10:00),
+    ///     datetime!(2026-02-28 10:00),
+    ///     datetime!(2027-02-28 10:00),
+    ///     datetime!(2028-02-29 10:00),
+    /// ]);
+    /// ```
+    pub fn yearly(mut self) -> Self {
+        self.interval = Interval::from_years(1);
+        self.anchored = true;
+        self
+    }
+
     /// Sets the time that the recurrence must fall on.
     ///
     /// Setting the time of the recurrence takes priority over
@@ -59,6 +112,16 @@ impl<Tz: TimeZone> Every<Tz> {
     }
 
     fn build(mut self) -> EveryIter<Tz> {
+        // The anchor is the original date prior to any weekday shifting
+        let anchor = if self.anchored {
+            Some(Anchor {
+                date: self.start.date,
+                steps: 0,
+            })
+        } else {
+            None
+        };
+
         // Check if our initial data needs to be shifted
         if let Some(weekday) = self.weekday {
             if self.start.weekday() != weekday {
@@ -73,11 +136,18 @@ impl<Tz: TimeZone> Every<Tz> {
             until: self.until,
             weekday: self.weekday,
             first_time: self.time,
+            anchor,
             fixed,
         }
     }
 }
 
+/// The starting date that anchored recurrences are computed from.
+struct Anchor {
+    date: Date,
+    steps: i32,
+}
+
 /// The actual iterator created by [`Every`].
 #[must_use]
 pub struct EveryIter<Tz: TimeZone> {
@@ -86,6 +156,7 @@ pub struct EveryIter<Tz: TimeZone> {
     until: Option<DateTime<Tz>>,
     weekday: Option<Weekday>,
     first_time: Option<Time>,
+    anchor: Option<Anchor>,
     fixed: bool,
 }
 
@@ -116,11 +187,19 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
             self.start.time.add_with_duration(duration)
         };
 
-        let mut date = self
-            .start
-            .date
-            .add_months(self.interval.total_months())
-            .add_days(self.interval.days() + days);
+        let mut date = match &self.anchor {
+            // Anchored recurrences only consist of months
+            Some(anchor) => anchor.date.add_months(
+                self.interval
+                    .total_months()
+                    .saturating_mul(anchor.steps.saturating_add(1)),
+            ),
+            None => self
+                .start
+                .date
+                .add_months(self.interval.total_months())
+                .add_days(self.interval.days() + days),
+        };
 
         if let Some(weekday) = self.weekday {
             if date.weekday() != weekday {
@@ -128,9 +207,11 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
             }
         }
 
-        let timezone = self.start.timezone.clone();
+        let timezone = &self.start.timezone;
+        let mut stepped = true;
         let dt = if let Some(first_time) = self.first_time.take() {
             if first_time > self.start.time {
+                stepped = false;
                 timezone.resolve(self.start.date, first_time).lenient()
             } else {
                 timezone.resolve(date, first_time).lenient()
@@ -140,7 +221,7 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
                 date,
                 time,
                 offset: self.start.offset,
-                timezone,
+                timezone: timezone.clone(),
             }
         } else if sub {
             timezone.resolve(date, time).backwards()
@@ -148,6 +229,12 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
             timezone.resolve(date, time).lenient()
         };
 
+        if let Some(anchor) = self.anchor.as_mut() {
+            if stepped {
+                anchor.steps = anchor.steps.saturating_add(1);
+            }
+        }
+
         self.start.time = dt.time;
         self.start.date = dt.date;
         self.start.offset = dt.offset;
@@ -155,6 +242,92 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
     }
 }
 
+/// An iterator over consecutive days.
+///
+/// This struct is created via [`Date::range_inclusive`] or [`Date::iter_days_until`].
+/// See their documentation for more details.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct Days {
+    // The days since the UNIX epoch of the remaining range, i.e. [front, back)
+    front: i32,
+    back: i32,
+    // Whether the range is yielded from back to front
+    reverse: bool,
+}
+
+impl Days {
+    pub(crate) fn new(front: i32, back: i32) -> Self {
+        Self {
+            front,
+            back: back.max(front),
+            reverse: false,
+        }
+    }
+
+    /// Creates an iterator from `start` up to but excluding `end`, counting
+    /// down if `end` is before `start`.
+    pub(crate) fn until(start: i32, end: i32) -> Self {
+        if end >= start {
+            Self::new(start, end)
+        } else {
+            Self {
+                front: end + 1,
+                back: start + 1,
+                reverse: true,
+            }
+        }
+    }
+
+    fn take_front(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = Date::UNIX_EPOCH.add_days(self.front);
+        self.front += 1;
+        Some(date)
+    }
+
+    fn take_back(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(Date::UNIX_EPOCH.add_days(self.back))
+    }
+}
+
+impl Iterator for Days {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reverse {
+            self.take_back()
+        } else {
+            self.take_front()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Days {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.reverse {
+            self.take_front()
+        } else {
+            self.take_back()
+        }
+    }
+}
+
+impl ExactSizeIterator for Days {}
+
+impl FusedIterator for Days {}
+
 #[cfg(test)]
 mod tests {
     use core::time::Duration;
@@ -205,4 +378,76 @@ mod tests {
         let duration = dt.duration_since(&start);
         assert_eq!(duration, DAY * 2);
     }
+
+    #[test]
+    fn monthly_clamps_without_drift() {
+        let start = datetime!(2024-01-31 10:00);
+        let dates: Vec<_> = start.every(Interval::ZERO).monthly().into_iter().take(13).collect();
+        let expected = [
+            datetime!(2024-02-29 10:00),
+            datetime!(2024-03-31 10:00),
+            datetime!(2024-04-30 10:00),
+            datetime!(2024-05-31 10:00),
+            datetime!(2024-06-30 10:00),
+            datetime!(2024-07-31 10:00),
+            datetime!(2024-08-31 10:00),
+            datetime!(2024-09-30 10:00),
+            datetime!(2024-10-31 10:00),
+            datetime!(2024-11-30 10:00),
+            datetime!(2024-12-31 10:00),
+            datetime!(2025-01-31 10:00),
+            datetime!(2025-02-28 10:00),
+        ];
+        assert_eq!(dates, expected);
+
+        // Compared to repeatedly adding a month which drifts
+        let drifted: Vec<_> = start.every(Interval::from_months(1)).into_iter().take(3).collect();
+        assert_eq!(
+            drifted,
+            [
+                datetime!(2024-02-29 10:00),
+                datetime!(2024-03-29 10:00),
+                datetime!(2024-04-29 10:00)
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_with_time_and_until() {
+        let start = datetime!(2024-01-31 10:00);
+        let until = datetime!(2024-04-30 12:00);
+        let dates: Vec<_> = start
+            .every(Interval::ZERO)
+            .monthly()
+            .at(time!(12:00))
+            .until(until)
+            .into_iter()
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                datetime!(2024-01-31 12:00),
+                datetime!(2024-02-29 12:00),
+                datetime!(2024-03-31 12:00),
+                datetime!(2024-04-30 12:00),
+            ]
+        );
+    }
+
+    #[test]
+    fn yearly_clamps_without_drift() {
+        let start = datetime!(2020-02-29 00:00);
+        let dates: Vec<_> = start.every(Interval::ZERO).yearly().into_iter().take(8).collect();
+        let expected = [
+            datetime!(2021-02-28 00:00),
+            datetime!(2022-02-28 00:00),
+            datetime!(2023-02-28 00:00),
+            datetime!(2024-02-29 00:00),
+            datetime!(2025-02-28 00:00),
+            datetime!(2026-02-28 00:00),
+            datetime!(2027-02-28 00:00),
+            datetime!(2028-02-29 00:00),
+        ];
+        assert_eq!(dates, expected);
+    }
 }
@@ -35,12 +35,16 @@ pub mod fmt;
 #[cfg(all(feature = "parsing", feature = "serde"))]
 pub mod serde;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 mod builder;
 mod date;
 mod datetime;
 mod error;
 pub mod ext;
 pub mod extra;
+pub mod fiscal;
 pub mod gregorian;
 mod interval;
 pub mod iter;
@@ -52,7 +56,7 @@ pub mod unit;
 mod utils;
 
 pub use builder::Builder;
-pub use date::{Date, IsoWeekDate, Weekday};
+pub use date::{Date, IsoWeekDate, Parity, Weekday, WEEKDAYS};
 pub use datetime::DateTime;
 pub use error::Error;
 pub use interval::Interval;
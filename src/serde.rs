@@ -6,7 +6,7 @@
 //! [`with`]: https://serde.rs/field-attrs.html#with
 
 use serde::de::{self, Deserialize, Visitor};
-use serde::ser::Serialize;
+use serde::ser::{Serialize, SerializeStruct};
 
 use crate::fmt::FromIsoFormat;
 use crate::{Date, DateTime, Interval, IsoWeekDate, Time, TimeZone, Utc, UtcOffset};
@@ -55,12 +55,24 @@ where
 }
 
 /// Serialize into an ISO-8601 string.
+///
+/// Formats that are not human readable, such as binary formats, instead serialize
+/// into a struct with the `months`, `days`, and `microseconds` fields.
 impl Serialize for Interval {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let (months, days, microseconds) = self.to_postgres();
+            let mut state = serializer.serialize_struct("Interval", 3)?;
+            state.serialize_field("months", &months)?;
+            state.serialize_field("days", &days)?;
+            state.serialize_field("microseconds", &microseconds)?;
+            state.end()
+        }
     }
 }
 
@@ -132,6 +144,90 @@ impl<'de> Visitor<'de> for IntervalVisitor {
     }
 }
 
+const INTERVAL_FIELDS: &[&str] = &["months", "days", "microseconds"];
+
+enum IntervalField {
+    Months,
+    Days,
+    Microseconds,
+}
+
+struct IntervalFieldVisitor;
+
+impl<'de> Visitor<'de> for IntervalFieldVisitor {
+    type Value = IntervalField;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("`months`, `days`, or `microseconds`")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            "months" => Ok(IntervalField::Months),
+            "days" => Ok(IntervalField::Days),
+            "microseconds" => Ok(IntervalField::Microseconds),
+            _ => Err(E::unknown_field(v, INTERVAL_FIELDS)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IntervalField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(IntervalFieldVisitor)
+    }
+}
+
+struct IntervalStructVisitor;
+
+impl<'de> Visitor<'de> for IntervalStructVisitor {
+    type Value = Interval;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a struct with months, days, and microseconds")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let months = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let days = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let microseconds = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Ok(Interval::from_postgres(months, days, microseconds))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut months = None;
+        let mut days = None;
+        let mut microseconds = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                IntervalField::Months if months.is_some() => return Err(de::Error::duplicate_field("months")),
+                IntervalField::Months => months = Some(map.next_value()?),
+                IntervalField::Days if days.is_some() => return Err(de::Error::duplicate_field("days")),
+                IntervalField::Days => days = Some(map.next_value()?),
+                IntervalField::Microseconds if microseconds.is_some() => {
+                    return Err(de::Error::duplicate_field("microseconds"))
+                }
+                IntervalField::Microseconds => microseconds = Some(map.next_value()?),
+            }
+        }
+        let months = months.ok_or_else(|| de::Error::missing_field("months"))?;
+        let days = days.ok_or_else(|| de::Error::missing_field("days"))?;
+        let microseconds = microseconds.ok_or_else(|| de::Error::missing_field("microseconds"))?;
+        Ok(Interval::from_postgres(months, days, microseconds))
+    }
+}
+
 struct DateTimeVisitor;
 
 impl<'de> Visitor<'de> for DateTimeVisitor {
@@ -180,12 +276,19 @@ impl<'de> Deserialize<'de> for IsoWeekDate {
 }
 
 /// Deserialize from an ISO-8601 string.
+///
+/// Formats that are not human readable, such as binary formats, instead deserialize
+/// from a struct with the `months`, `days`, and `microseconds` fields.
 impl<'de> Deserialize<'de> for Interval {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(IntervalVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IntervalVisitor)
+        } else {
+            deserializer.deserialize_struct("Interval", INTERVAL_FIELDS, IntervalStructVisitor)
+        }
     }
 }
 
@@ -442,3 +545,311 @@ pub mod timestamp_ms {
         }
     }
 }
+
+/// Serialize and deserialize to and from a compact fixed-size representation.
+///
+/// This is meant for binary formats such as `bincode` or `postcard` where the
+/// default ISO-8601 strings waste space. It works with [`Date`], [`Time`], and
+/// [`DateTime<Utc>`] fields.
+///
+/// The wire format is as follows:
+///
+/// | Type              | Representation                                          | Size in `bincode` |
+/// |:-----------------:|:-------------------------------------------------------:|:-----------------:|
+/// | [`Date`]          | `(i16, u8, u8)` for the year, month, and day            | 4 bytes           |
+/// | [`Time`]          | `u64` for the nanoseconds since midnight                | 8 bytes           |
+/// | [`DateTime<Utc>`] | `(i64, u32)` for the UNIX timestamp and its nanoseconds | 12 bytes          |
+///
+/// Leap seconds are supported. For [`Time`] they are stored after the first 24 hours, and for
+/// [`DateTime<Utc>`] they are stored as the 59th second with more than 1 billion nanoseconds.
+/// Values that are out of range are rejected when deserializing.
+///
+/// This is intended to be used with the [`with`] field attribute in `serde`.
+///
+/// [`with`]: https://serde.rs/field-attrs.html#with
+///
+/// # Example
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// use eos::{datetime, time, Date, DateTime, Time, Utc};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct T {
+///     #[serde(with = "eos::serde::compact")]
+///     date: Date,
+///     #[serde(with = "eos::serde::compact")]
+///     time: Time,
+///     #[serde(with = "eos::serde::compact")]
+///     dt: DateTime<Utc>,
+/// }
+///
+/// let dt = datetime!(2022-02-01 12:34:56);
+/// let t = T { date: dt.date(), time: time!(10:30), dt };
+/// let bytes = bincode::serialize(&t)?;
+/// assert_eq!(bytes.len(), 4 + 8 + 12);
+/// assert_eq!(bincode::deserialize::<T>(&bytes)?, t);
+/// # Ok::<_, bincode::Error>(())
+/// ```
+pub mod compact {
+    use super::*;
+    use serde::de::Error as _;
+
+    mod private {
+        pub trait Sealed {}
+        impl Sealed for crate::Date {}
+        impl Sealed for crate::Time {}
+        impl Sealed for crate::DateTime<crate::Utc> {}
+    }
+
+    /// A type that has a compact fixed-size representation.
+    ///
+    /// This trait is sealed and cannot be implemented outside of this library.
+    pub trait Compact: private::Sealed + Sized {
+        #[doc(hidden)]
+        fn serialize_compact<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer;
+
+        #[doc(hidden)]
+        fn deserialize_compact<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>;
+    }
+
+    impl Compact for Date {
+        fn serialize_compact<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            (self.year(), self.month(), self.day()).serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let (year, month, day) = <(i16, u8, u8)>::deserialize(deserializer)?;
+            Date::new(year, month, day).ok_or_else(|| D::Error::custom(crate::Error::OutOfRange))
+        }
+    }
+
+    impl Compact for Time {
+        fn serialize_compact<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            // Leap seconds are stored as the 59th second offset by a whole day, since
+            // their total nanoseconds would otherwise overlap with the following minute.
+            if self.nanosecond >= 1_000_000_000 {
+                let nanos = self.total_nanos() - crate::interval::NANOS_PER_SEC;
+                serializer.serialize_u64(nanos + crate::interval::NANOS_PER_DAY)
+            } else {
+                serializer.serialize_u64(self.total_nanos())
+            }
+        }
+
+        fn deserialize_compact<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let nanos = u64::deserialize(deserializer)?;
+            if nanos < crate::interval::NANOS_PER_DAY {
+                // This cast is safe since it was bound checked earlier
+                return Ok(Time::adjust_from_nanos(nanos as i64).1);
+            }
+
+            let nanos = nanos - crate::interval::NANOS_PER_DAY;
+            if nanos >= crate::interval::NANOS_PER_DAY {
+                return Err(D::Error::custom(crate::Error::OutOfRange));
+            }
+            let mut time = Time::adjust_from_nanos(nanos as i64).1;
+            if time.second != 59 {
+                return Err(D::Error::custom(crate::Error::OutOfRange));
+            }
+            time.nanosecond += 1_000_000_000;
+            Ok(time)
+        }
+    }
+
+    impl Compact for DateTime<Utc> {
+        fn serialize_compact<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            // Leap seconds keep the timestamp of the 59th second with the extra nanoseconds
+            let seconds = self.timestamp().as_seconds();
+            if self.nanosecond() >= 1_000_000_000 {
+                (seconds - 1, self.nanosecond()).serialize(serializer)
+            } else {
+                (seconds, self.nanosecond()).serialize(serializer)
+            }
+        }
+
+        fn deserialize_compact<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            let (seconds, nanoseconds) = <(i64, u32)>::deserialize(deserializer)?;
+            let range = crate::Timestamp::MIN_VALID..=crate::Timestamp::MAX_VALID;
+            if nanoseconds >= 2_000_000_000 || !range.contains(&seconds) {
+                return Err(D::Error::custom(crate::Error::OutOfRange));
+            }
+
+            if nanoseconds >= 1_000_000_000 {
+                let mut dt = DateTime::from_timestamp(crate::Timestamp::new(seconds, nanoseconds - 1_000_000_000), Utc);
+                if dt.second() != 59 {
+                    return Err(D::Error::custom(crate::Error::OutOfRange));
+                }
+                dt.time.nanosecond = nanoseconds;
+                Ok(dt)
+            } else {
+                Ok(DateTime::from_timestamp(
+                    crate::Timestamp::new(seconds, nanoseconds),
+                    Utc,
+                ))
+            }
+        }
+    }
+
+    /// Serialize a value into its compact representation.
+    ///
+    /// This is intended to be used with the [`serialize_with`] field attribute in `serde`.
+    ///
+    /// [`serialize_with`]: https://serde.rs/field-attrs.html#serialize_with
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Compact,
+        S: serde::Serializer,
+    {
+        value.serialize_compact(serializer)
+    }
+
+    /// Deserialize a value from its compact representation.
+    ///
+    /// This is intended to be used with the [`deserialize_with`] field attribute in `serde`.
+    ///
+    /// [`deserialize_with`]: https://serde.rs/field-attrs.html#deserialize_with
+    pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+    where
+        T: Compact,
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize_compact(d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{datetime, time, Timestamp};
+
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, PartialEq, Debug)]
+    struct Compact<T: compact::Compact>(#[serde(with = "compact")] T);
+
+    fn roundtrip<T>(value: T, size: usize)
+    where
+        T: compact::Compact + PartialEq + core::fmt::Debug,
+    {
+        let value = Compact(value);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes.len(), size);
+        assert_eq!(bincode::deserialize::<Compact<T>>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_compact_roundtrip() {
+        for dt in [
+            datetime!(2022-02-01 12:34:56),
+            datetime!(1969-12-31 23:59:59),
+            Date::new(-100, 3, 1).unwrap().at(Time::MIDNIGHT),
+            DateTime::UNIX_EPOCH,
+        ] {
+            let dt = dt.with_nanosecond(123_456_789).unwrap();
+            roundtrip(dt.date(), 4);
+            roundtrip(dt.time(), 8);
+            roundtrip(dt, 12);
+        }
+
+        roundtrip(Date::MIN, 4);
+        roundtrip(Date::MAX, 4);
+        roundtrip(Time::MIN, 8);
+        roundtrip(Time::MAX, 8);
+        roundtrip(time!(00:00:01), 8);
+    }
+
+    #[test]
+    fn test_compact_rejects_out_of_range() {
+        let bytes = bincode::serialize(&(2022i16, 2u8, 30u8)).unwrap();
+        assert!(bincode::deserialize::<Compact<Date>>(&bytes).is_err());
+
+        let bytes = bincode::serialize(&86_400_000_000_000u64).unwrap();
+        assert!(bincode::deserialize::<Compact<Time>>(&bytes).is_err());
+
+        let bytes = bincode::serialize(&(0i64, 1_000_000_000u32)).unwrap();
+        assert!(bincode::deserialize::<Compact<DateTime<Utc>>>(&bytes).is_err());
+
+        let bytes = bincode::serialize(&(59i64, 2_000_000_000u32)).unwrap();
+        assert!(bincode::deserialize::<Compact<DateTime<Utc>>>(&bytes).is_err());
+
+        // Leap seconds must be on the 59th second
+        let bytes = bincode::serialize(&(86_400_000_000_000u64 + 30_000_000_000)).unwrap();
+        assert!(bincode::deserialize::<Compact<Time>>(&bytes).is_err());
+
+        let bytes = bincode::serialize(&(2 * 86_400_000_000_000u64)).unwrap();
+        assert!(bincode::deserialize::<Compact<Time>>(&bytes).is_err());
+
+        for seconds in [i64::MIN, i64::MAX, Timestamp::MIN_VALID - 1, Timestamp::MAX_VALID + 1] {
+            let bytes = bincode::serialize(&(seconds, 0u32)).unwrap();
+            assert!(bincode::deserialize::<Compact<DateTime<Utc>>>(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_compact_leap_seconds() {
+        let time = Time::from_hms_milli(23, 59, 60, 500).unwrap();
+        roundtrip(time, 8);
+        roundtrip(Time::from_hms_nano(10, 30, 60, 999_999_999).unwrap(), 8);
+        roundtrip(datetime!(2016-12-31 00:00).with_time(time), 12);
+        roundtrip(datetime!(1969-12-31 00:00).with_time(time), 12);
+
+        // A leap second does not overlap with the following minute
+        let bytes = bincode::serialize(&Compact(Time::from_hms_nano(10, 30, 60, 0).unwrap())).unwrap();
+        assert_ne!(bytes, bincode::serialize(&Compact(time!(10:31))).unwrap());
+    }
+
+    #[test]
+    fn test_interval_roundtrip() {
+        use crate::ext::IntervalLiteral;
+
+        let intervals = [
+            Interval::ZERO,
+            1.years() + 2.months() + 10.days() + 2.hours() + 30.minutes(),
+            -(3.weeks() + 5.seconds()),
+            -(3.weeks() + 500.milliseconds()),
+            Interval::from_postgres(1234, -56, 7_890_123_456),
+        ];
+
+        for interval in intervals {
+            let json = serde_json::to_string(&interval).unwrap();
+            assert_eq!(json, format!("\"{}\"", interval));
+            assert_eq!(serde_json::from_str::<Interval>(&json).unwrap(), interval);
+
+            let bytes = bincode::serialize(&interval).unwrap();
+            assert_eq!(bytes.len(), 16);
+            assert_eq!(bincode::deserialize::<Interval>(&bytes).unwrap(), interval);
+        }
+
+        let interval = 1.years() + 2.months() + 10.days() + 2.hours() + 30.minutes();
+        assert_eq!(serde_json::to_string(&interval).unwrap(), "\"P1Y2M10DT2H30M\"");
+        assert_eq!(
+            serde_json::from_str::<Interval>("\"-P-30DT30S\"").unwrap(),
+            Interval::from_postgres(0, 30, -30_000_000)
+        );
+        assert_eq!(serde_json::to_string(&(-500).milliseconds()).unwrap(), "\"PT-0.5S\"");
+        assert_eq!(
+            bincode::serialize(&interval).unwrap(),
+            bincode::serialize(&(14i32, 10i32, 9_000_000_000i64)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Utilities for testing format spec usage in downstream crates.
+//!
+//! Format specs are easy to get subtly wrong, e.g. by forgetting a component or using one
+//! that drops information. The helpers in this module format a [`DateTime`] with a spec
+//! and parse the result back using the same spec so that these mistakes show up in tests.
+//!
+//! This module requires the `testing` feature.
+//!
+//! # Lossy specs
+//!
+//! Not every spec can round-trip. A spec is lossy if formatting with it drops information
+//! that parsing cannot recover. Some examples are:
+//!
+//! - Leaving out a component entirely, e.g. `%Y-%m-%d` drops the time and `%H:%M:%S` drops the nanoseconds.
+//! - Leaving out the UTC offset for a datetime that isn't in UTC, since parsing assumes UTC.
+//! - Timezone names that cannot be parsed back, such as `%Z` with `EST`.
+//! - A `%Y` directly followed by another number, e.g. `%Y%m%d`. Since years can be larger
+//!   than four digits, parsing the year consumes the digits that belong to the month.
+//!
+//! These are not bugs in the spec per se, but [`assert_roundtrip`] will rightfully fail for them.
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    fmt::{FormatSpec, ParseError},
+    DateTime, TimeZone, UtcOffset,
+};
+
+/// Formats the datetime using the given spec and parses the output back using the same spec.
+///
+/// Returns the formatted string along with the parsed [`DateTime`]. Since parsing with a
+/// spec always results in a [`UtcOffset`] timezone, the timezone of the original datetime
+/// is not preserved.
+///
+/// ```
+/// use eos::{datetime, fmt::format_spec, testing::roundtrip};
+///
+/// let dt = datetime!(2022-03-14 12:30:45 +05:00);
+/// let (formatted, parsed) = roundtrip(&dt, format_spec!("%Y-%m-%dT%H:%M:%S%o"))?;
+/// assert_eq!(formatted, "2022-03-14T12:30:45+05:00");
+/// assert_eq!(parsed, dt);
+/// # Ok::<_, eos::fmt::ParseError>(())
+/// ```
+pub fn roundtrip<'a, Tz, S>(dt: &DateTime<Tz>, spec: S) -> Result<(String, DateTime<UtcOffset>), ParseError>
+where
+    Tz: TimeZone,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+    let spec = spec.as_ref();
+    let formatted = dt.format(spec).to_string();
+    let parsed = DateTime::parse_from_spec(&formatted, spec)?;
+    Ok((formatted, parsed))
+}
+
+/// Asserts that the datetime survives being formatted and parsed back with the given spec.
+///
+/// The parsed datetime must refer to the same instant *and* have the same local date
+/// and time as the original. Checking both catches specs that drop the UTC offset of a
+/// datetime that isn't in UTC.
+///
+/// Check the [module documentation](self) for which specs are lossy.
+///
+/// # Panics
+///
+/// Panics if parsing the formatted string fails or if the parsed datetime differs from the original.
+///
+/// ```
+/// use eos::{datetime, fmt::format_spec, testing::assert_roundtrip};
+///
+/// let dt = datetime!(2022-03-14 12:30:45 +05:00);
+/// assert_roundtrip(&dt, format_spec!("%Y-%m-%dT%H:%M:%S%o"));
+/// ```
+///
+/// A lossy spec fails the assertion:
+///
+/// ```should_panic
+/// use eos::{datetime, fmt::format_spec, testing::assert_roundtrip};
+///
+/// let dt = datetime!(2022-03-14 12:30:45 +05:00);
+/// assert_roundtrip(&dt, format_spec!("%Y-%m-%d"));
+/// ```
+#[track_caller]
+pub fn assert_roundtrip<'a, Tz, S>(dt: &DateTime<Tz>, spec: S)
+where
+    Tz: TimeZone,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+    match roundtrip(dt, spec) {
+        Ok((formatted, parsed)) => {
+            let same_local = parsed.date() == dt.date() && parsed.time() == dt.time();
+            if parsed != *dt || !same_local {
+                panic!(
+                    "datetime did not round-trip\n  original: {}\n formatted: {:?}\n    parsed: {}",
+                    dt, formatted, parsed
+                );
+            }
+        }
+        Err(e) => panic!("could not parse formatted datetime of {}: {}", dt, e),
+    }
+}
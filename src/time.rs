@@ -1,8 +1,10 @@
 use crate::{
-    interval::{MICROS_PER_HOUR, MICROS_PER_MIN, MICROS_PER_SEC, NANOS_PER_HOUR, NANOS_PER_MIN, NANOS_PER_SEC},
+    interval::{
+        MICROS_PER_HOUR, MICROS_PER_MIN, MICROS_PER_SEC, NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MIN, NANOS_PER_SEC,
+    },
     step::Advance,
     utils::{divmod, ensure_in_range},
-    Date, DateTime, Interval, Utc,
+    Date, DateTime, Error, Interval, Utc,
 };
 
 use core::{
@@ -103,6 +105,109 @@ impl Time {
         })
     }
 
+    /// Creates a new [`Time`] from the specified hour, minute, second, and millisecond.
+    ///
+    /// The `hour` value must be between `0..24`, the `minute` value must be between `0..60`,
+    /// and the `millisecond` value must be between `0..1000`. The `second` value must be
+    /// between `0..60` or exactly `60` to represent a leap second. Similar to parsing, a leap
+    /// second is stored as the last second of the minute with the extra second carried in
+    /// the sub-second component.
+    ///
+    /// If any of the values are out of bounds then [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use eos::{Error, Time};
+    /// let time = Time::from_hms_milli(23, 10, 30, 250)?;
+    /// assert_eq!(time.second(), 30);
+    /// assert_eq!(time.millisecond(), 250);
+    ///
+    /// let leap = Time::from_hms_milli(23, 59, 60, 500)?;
+    /// assert_eq!(leap.second(), 59);
+    /// assert_eq!(leap.millisecond(), 1500);
+    ///
+    /// assert_eq!(Time::from_hms_milli(23, 59, 59, 1000), Err(Error::OutOfRange));
+    /// assert_eq!(Time::from_hms_milli(23, 59, 61, 0), Err(Error::OutOfRange));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub const fn from_hms_milli(hour: u8, minute: u8, second: u8, millisecond: u16) -> Result<Self, Error> {
+        if millisecond > 999 {
+            return Err(Error::OutOfRange);
+        }
+        Self::from_hms_nano(hour, minute, second, millisecond as u32 * 1_000_000)
+    }
+
+    /// Creates a new [`Time`] from the specified hour, minute, second, and microsecond.
+    ///
+    /// The `microsecond` value must be between `0..1_000_000`. See [`Time::from_hms_milli`]
+    /// for the bounds of the other values and how leap seconds are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use eos::{Error, Time};
+    /// let time = Time::from_hms_micro(12, 0, 0, 123_456)?;
+    /// assert_eq!(time.microsecond(), 123_456);
+    ///
+    /// let leap = Time::from_hms_micro(23, 59, 60, 0)?;
+    /// assert_eq!(leap.microsecond(), 1_000_000);
+    ///
+    /// assert_eq!(Time::from_hms_micro(12, 0, 0, 1_000_000), Err(Error::OutOfRange));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub const fn from_hms_micro(hour: u8, minute: u8, second: u8, microsecond: u32) -> Result<Self, Error> {
+        if microsecond > 999_999 {
+            return Err(Error::OutOfRange);
+        }
+        Self::from_hms_nano(hour, minute, second, microsecond * 1_000)
+    }
+
+    /// Creates a new [`Time`] from the specified hour, minute, second, and nanosecond.
+    ///
+    /// The `nanosecond` value must be between `0..1_000_000_000`. See [`Time::from_hms_milli`]
+    /// for the bounds of the other values and how leap seconds are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use eos::{Error, Time};
+    /// let time = Time::from_hms_nano(12, 0, 0, 999_999_999)?;
+    /// assert_eq!(time.nanosecond(), 999_999_999);
+    ///
+    /// let leap = Time::from_hms_nano(23, 59, 60, 999_999_999)?;
+    /// assert_eq!(leap.second(), 59);
+    /// assert_eq!(leap.nanosecond(), 1_999_999_999);
+    ///
+    /// assert_eq!(Time::from_hms_nano(12, 0, 0, 1_000_000_000), Err(Error::OutOfRange));
+    /// assert_eq!(Time::from_hms_nano(24, 0, 0, 0), Err(Error::OutOfRange));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub const fn from_hms_nano(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Result<Self, Error> {
+        if hour > 23 || minute > 59 || second > 60 || nanosecond > 999_999_999 {
+            return Err(Error::OutOfRange);
+        }
+
+        if second == 60 {
+            Ok(Self {
+                hour,
+                minute,
+                second: 59,
+                nanosecond: nanosecond + NANOS_PER_SEC as u32,
+            })
+        } else {
+            Ok(Self {
+                hour,
+                minute,
+                second,
+                nanosecond,
+            })
+        }
+    }
+
     /// Combines this [`Time`] with a [`Date`] to create a [`DateTime`] in [`Utc`].
     #[inline]
     #[must_use]
@@ -176,7 +281,7 @@ impl Time {
     /// Total number of nanoseconds represented by this time.
     ///
     /// The valid range for this type is [0, 86,401,000,000,000]
-    pub(crate) fn total_nanos(&self) -> u64 {
+    pub(crate) const fn total_nanos(&self) -> u64 {
         self.hour as u64 * NANOS_PER_HOUR
             + self.minute as u64 * NANOS_PER_MIN
             + self.second as u64 * NANOS_PER_SEC
@@ -356,6 +461,82 @@ impl Time {
         self.nanosecond = nanosecond;
         Some(self)
     }
+
+    /// Returns `true` if adding the time component of the interval would cross a day boundary.
+    ///
+    /// The days and months of the interval are ignored. A negative interval that
+    /// goes before midnight also counts as crossing a day boundary. This does not
+    /// compute the resulting date or time.
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, time};
+    ///
+    /// assert!(time!(23:00).would_overflow_day(2.hours()));
+    /// assert!(!time!(23:00).would_overflow_day(59.minutes()));
+    /// assert!(time!(01:00).would_overflow_day((-2).hours()));
+    /// assert!(!time!(01:00).would_overflow_day(3.days()));
+    /// ```
+    #[must_use]
+    pub const fn would_overflow_day(self, interval: Interval) -> bool {
+        let total = self.total_nanos() as i128 + interval.total_microseconds() as i128 * 1_000;
+        total < 0 || total >= NANOS_PER_DAY as i128
+    }
+
+    /// Restricts this time to the `[min, max]` window within a day.
+    ///
+    /// Returns `max` if this time is later than `max`, and `min` if this time is earlier
+    /// than `min`. Otherwise this returns the time unchanged. This is the same as
+    /// [`Ord::clamp`] except usable in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// ```
+    /// use eos::time;
+    ///
+    /// let (open, close) = (time!(09:00), time!(17:00));
+    /// assert_eq!(time!(07:00).clamp(open, close), open);
+    /// assert_eq!(time!(20:00).clamp(open, close), close);
+    /// assert_eq!(time!(12:30).clamp(open, close), time!(12:30));
+    /// assert_eq!(time!(17:00).clamp(open, close), close);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.total_nanos() <= max.total_nanos(),
+            "min must be less than or equal to max"
+        );
+        if self.total_nanos() < min.total_nanos() {
+            min
+        } else if self.total_nanos() > max.total_nanos() {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Converts to an ISO-8601 extended formatted string using a comma as the decimal sign.
+    ///
+    /// This is the same as [`ToIsoFormat::to_iso_format_with_precision`] except the fractional
+    /// seconds are separated with `,` rather than `.`. Both are allowed by ISO-8601 and
+    /// are accepted by [`FromIsoFormat`](crate::fmt::FromIsoFormat).
+    ///
+    /// ```
+    /// use eos::{time, fmt::IsoFormatPrecision};
+    ///
+    /// let time = time!(10:24:30).with_millisecond(500).unwrap();
+    /// assert_eq!(time.to_iso_format_with_comma(IsoFormatPrecision::Decisecond), "10:24:30,5");
+    /// assert_eq!(time.to_iso_format_with_comma(IsoFormatPrecision::Millisecond), "10:24:30,500");
+    /// assert_eq!(time.to_iso_format_with_comma(IsoFormatPrecision::Second), "10:24:30");
+    /// ```
+    #[cfg(feature = "formatting")]
+    #[must_use]
+    pub fn to_iso_format_with_comma(&self, precision: IsoFormatPrecision) -> String {
+        let mut buffer = String::with_capacity(16);
+        fmt_iso_time(&mut buffer, self, precision, ',').unwrap();
+        buffer
+    }
 }
 
 impl Add<Interval> for Time {
@@ -386,19 +567,40 @@ impl Sub<Interval> for Time {
     }
 }
 
+/// Only keeps the part of the duration that is smaller than a day.
+///
+/// Adding whole days to a [`Time`] is a no-op, and the full duration
+/// in nanoseconds might not fit in an `i64`.
+fn duration_within_day(duration: Duration) -> Duration {
+    Duration::new(duration.as_secs() % 86400, duration.subsec_nanos())
+}
+
+/// Adds the duration to the time, wrapping around at midnight.
+///
+/// The number of days that have passed is discarded.
+///
+/// ```
+/// use core::time::Duration;
+/// use eos::time;
+///
+/// assert_eq!(time!(23:00) + Duration::from_secs(7200), time!(01:00));
+/// ```
 impl Add<Duration> for Time {
     type Output = Self;
 
     fn add(self, rhs: Duration) -> Self::Output {
-        self.add_with_duration(rhs).1
+        self.add_with_duration(duration_within_day(rhs)).1
     }
 }
 
+/// Subtracts the duration from the time, wrapping around at midnight.
+///
+/// The number of days that have passed is discarded.
 impl Sub<Duration> for Time {
     type Output = Self;
 
     fn sub(self, rhs: Duration) -> Self::Output {
-        self.sub_with_duration(rhs).1
+        self.sub_with_duration(duration_within_day(rhs)).1
     }
 }
 
@@ -411,7 +613,7 @@ impl Sub for Time {
 }
 
 #[cfg(feature = "formatting")]
-pub(crate) fn fmt_iso_time<W>(f: &mut W, t: &Time, precision: IsoFormatPrecision) -> core::fmt::Result
+pub(crate) fn fmt_iso_time<W>(f: &mut W, t: &Time, precision: IsoFormatPrecision, decimal: char) -> core::fmt::Result
 where
     W: core::fmt::Write,
 {
@@ -419,23 +621,60 @@ where
         IsoFormatPrecision::Hour => write!(f, "{:02}:00", t.hour),
         IsoFormatPrecision::Minute => write!(f, "{:02}:{:02}", t.hour, t.minute),
         IsoFormatPrecision::Second => write!(f, "{:02}:{:02}:{:02}", t.hour, t.minute, t.second),
+        IsoFormatPrecision::Decisecond => {
+            let ds = t.nanosecond / 100_000_000;
+            write!(f, "{:02}:{:02}:{:02}{}{}", t.hour, t.minute, t.second, decimal, ds)
+        }
         IsoFormatPrecision::Millisecond => {
             let ms = t.millisecond();
-            write!(f, "{:02}:{:02}:{:02}.{:03}", t.hour, t.minute, t.second, ms)
+            write!(f, "{:02}:{:02}:{:02}{}{:03}", t.hour, t.minute, t.second, decimal, ms)
         }
         IsoFormatPrecision::Microsecond => {
             let ms = t.microsecond();
-            write!(f, "{:02}:{:02}:{:02}.{:06}", t.hour, t.minute, t.second, ms)
+            write!(f, "{:02}:{:02}:{:02}{}{:06}", t.hour, t.minute, t.second, decimal, ms)
         }
         IsoFormatPrecision::Nanosecond => {
-            write!(f, "{:02}:{:02}:{:02}.{:07}", t.hour, t.minute, t.second, t.nanosecond)
+            write!(
+                f,
+                "{:02}:{:02}:{:02}{}{:07}",
+                t.hour, t.minute, t.second, decimal, t.nanosecond
+            )
         }
     }
 }
 
+/// Displays the [`Time`] in ISO-8601 format.
+///
+/// A precision can be given to write a fixed number of fractional second digits, similar
+/// to floats. The fraction is truncated rather than rounded and a precision of `0` omits it
+/// entirely. Since nanoseconds are the maximum supported precision, anything above 9 digits
+/// is treated as 9. A leap second is written as second `60`.
+///
+/// ```
+/// use eos::time;
+///
+/// let time = time!(10:30:15).with_microsecond(123_456).unwrap();
+/// assert_eq!(format!("{:.3}", time), "10:30:15.123");
+/// assert_eq!(format!("{:.6}", time), "10:30:15.123456");
+/// assert_eq!(format!("{:.0}", time), "10:30:15");
+/// ```
 impl core::fmt::Display for Time {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.nanosecond != 0 {
+        if let Some(precision) = f.precision() {
+            // Leap seconds are stored as an overflowing nanosecond so they're written as :60
+            let (second, nanosecond) = if self.nanosecond >= NANOS_PER_SEC as u32 {
+                (self.second + 1, self.nanosecond - NANOS_PER_SEC as u32)
+            } else {
+                (self.second, self.nanosecond)
+            };
+            write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, second)?;
+            let precision = precision.min(9);
+            if precision != 0 {
+                let fraction = nanosecond / 10u32.pow(9 - precision as u32);
+                write!(f, ".{:0width$}", fraction, width = precision)?;
+            }
+            Ok(())
+        } else if self.nanosecond != 0 {
             write!(
                 f,
                 "{:02}:{:02}:{:02}.{:07}",
@@ -451,7 +690,7 @@ impl core::fmt::Display for Time {
 impl ToIsoFormat for Time {
     fn to_iso_format_with_precision(&self, precision: IsoFormatPrecision) -> String {
         let mut buffer = String::with_capacity(16);
-        fmt_iso_time(&mut buffer, self, precision).unwrap();
+        fmt_iso_time(&mut buffer, self, precision, '.').unwrap();
         buffer
     }
 
@@ -477,8 +716,17 @@ impl FromIsoFormat for Time {
     ///
     /// Notably, formats *without* the colon are not allowed despite being part of the
     /// ISO-8601 standard.
+    ///
+    /// A leap second (e.g. `23:59:60`) is parsed as the last second of the minute
+    /// with the extra second stored in the nanosecond component.
+    ///
+    /// Any trailing characters result in [`ParseError::UnexpectedChar`].
     fn from_iso_format(s: &str) -> Result<Self, ParseError> {
         let mut parser = Parser::new(s);
-        parser.parse_time()
+        let time = parser.parse_time()?;
+        match parser.advance() {
+            Some(c) => Err(ParseError::UnexpectedChar(c as char)),
+            None => Ok(time),
+        }
     }
 }
@@ -2,6 +2,7 @@ use crate::{
     date::Date,
     datetime::DateTime,
     gregorian::{date_from_epoch_days, MAX_EPOCH_DAYS, MIN_EPOCH_DAYS},
+    interval::Interval,
     time::Time,
     timezone::{Utc, UtcOffset},
     utils::{divmod, divrem},
@@ -27,8 +28,8 @@ pub struct Timestamp {
 }
 
 impl Timestamp {
-    const MIN_VALID: i64 = MIN_EPOCH_DAYS as i64 * 86400;
-    const MAX_VALID: i64 = MAX_EPOCH_DAYS as i64 * 86400 + (23 * 3600) + (59 * 60) + 59;
+    pub(crate) const MIN_VALID: i64 = MIN_EPOCH_DAYS as i64 * 86400;
+    pub(crate) const MAX_VALID: i64 = MAX_EPOCH_DAYS as i64 * 86400 + (23 * 3600) + (59 * 60) + 59;
 
     /// Creates a new `Timestamp` from the specified whole seconds and additional nanoseconds.
     ///
@@ -112,6 +113,32 @@ impl Timestamp {
         (self.seconds as f32) + (self.nanoseconds as f32) / (NANOS_PER_SEC as f32)
     }
 
+    /// Returns the exact [`Interval`] that has elapsed since the `other` timestamp.
+    ///
+    /// The resulting interval only contains a time component, i.e. it has no months or days.
+    /// It has up to microsecond precision, with any remaining nanoseconds being truncated.
+    /// If `other` is after this timestamp then the interval is negative.
+    ///
+    /// This is more efficient than converting both timestamps into a [`DateTime`] and
+    /// subtracting them.
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Timestamp};
+    ///
+    /// let start = Timestamp::from_seconds(1641155925);
+    /// let end = Timestamp::from_seconds(1641155925 + 90 * 60);
+    /// assert_eq!(end.interval_since(&start), 1.hours() + 30.minutes());
+    /// assert_eq!(start.interval_since(&end), -(1.hours() + 30.minutes()));
+    /// ```
+    #[must_use]
+    pub fn interval_since(&self, other: &Self) -> Interval {
+        let seconds = self.seconds as i128 - other.seconds as i128;
+        let nanos = seconds * NANOS_PER_SEC as i128 + (self.nanoseconds as i128 - other.nanoseconds as i128);
+        // Sub-microsecond precision is truncated towards zero
+        let micros = nanos / NANOS_PER_MICRO as i128;
+        Interval::from_microseconds(micros.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
     /// Converts the `Timestamp` into a [`DateTime`] in UTC.
     ///
     /// If the timestamp is out of range whether in the negative or positive
@@ -120,14 +147,14 @@ impl Timestamp {
     pub const fn to_utc(self) -> DateTime<Utc> {
         // This is a manual implementation due to the `const fn` requirement.
 
-        if self.seconds >= Self::MAX_VALID {
+        if self.seconds > Self::MAX_VALID {
             return DateTime {
                 date: Date::MAX,
                 time: Time::MAX,
                 offset: UtcOffset::UTC,
                 timezone: Utc,
             };
-        } else if self.seconds <= Self::MIN_VALID {
+        } else if self.seconds < Self::MIN_VALID {
             return DateTime {
                 date: Date::MIN,
                 time: Time::MIN,
@@ -184,4 +211,43 @@ mod tests {
             datetime!(2022-01-02 20:38:45 -5:00)
         );
     }
+
+    #[test]
+    fn test_interval_since() {
+        let start = Timestamp::new(1641155925, 250_000_000);
+        let end = Timestamp::new(1641155925 + 90 * 60, 250_000_000);
+        let interval = end.interval_since(&start);
+        assert_eq!(interval, Interval::from_hours(1) + Interval::from_minutes(30));
+        assert_eq!(interval.hours(), 1);
+        assert_eq!(interval.minutes(), 30);
+        assert_eq!(interval.total_months(), 0);
+        assert_eq!(interval.days(), 0);
+        assert_eq!(start.interval_since(&end), -interval);
+        assert_eq!(start.interval_since(&start), Interval::ZERO);
+
+        // Sub-second precision
+        let a = Timestamp::new(-1, 999_999_000);
+        let b = Timestamp::new(0, 1_000);
+        assert_eq!(b.interval_since(&a), Interval::from_microseconds(2));
+        assert_eq!(a.interval_since(&b), Interval::from_microseconds(-2));
+        assert_eq!(
+            Timestamp::new(10, 123_456_789).interval_since(&Timestamp::from_seconds(0)),
+            Interval::from_microseconds(10_123_456)
+        );
+
+        // Half a microsecond across a second boundary is truncated to zero
+        let a = Timestamp::new(0, 999_999_500);
+        let b = Timestamp::from_seconds(1);
+        assert_eq!(b.interval_since(&a), Interval::ZERO);
+        assert_eq!(a.interval_since(&b), Interval::ZERO);
+        assert_eq!(
+            Timestamp::new(2, 0).interval_since(&a),
+            Interval::from_microseconds(1_000_000)
+        );
+
+        // Equivalent to subtracting the datetimes if less than a day apart
+        let a = datetime!(2021-03-14 01:30:15);
+        let b = datetime!(2021-03-14 13:59:59 -05:00);
+        assert_eq!(b.timestamp().interval_since(&a.timestamp()), b - a);
+    }
 }
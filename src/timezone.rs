@@ -1,5 +1,8 @@
 use crate::{utils::ensure_in_range, Date, DateTime, Error, Time, Timestamp};
 
+#[cfg(feature = "parsing")]
+use crate::fmt::{FromIsoFormat, ParseError, Parser};
+
 /// An offset from UTC.
 ///
 /// This struct can only store values up to ±24:00:00.
@@ -119,6 +122,27 @@ impl UtcOffset {
         Some(Self::from_seconds_unchecked(seconds))
     }
 
+    /// Creates a new [`UtcOffset`] from a total number of minutes.
+    /// The value must be between `-1080..=1080`, i.e. up to 18 hours in either direction.
+    ///
+    /// Similar to [`UtcOffset::total_seconds`], positive values are ahead of (east of) UTC
+    /// and negative values are behind (west of) UTC. Note that this is the opposite of
+    /// ECMAScript's `Date.prototype.getTimezoneOffset`, whose values must be negated first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{utc_offset, UtcOffset};
+    /// assert_eq!(UtcOffset::from_minutes(330), Some(utc_offset!(05:30)));
+    /// assert_eq!(UtcOffset::from_minutes(-300), Some(utc_offset!(-05:00)));
+    /// assert_eq!(UtcOffset::from_minutes(1081), None);
+    /// ```
+    #[inline]
+    pub const fn from_minutes(minutes: i32) -> Option<Self> {
+        ensure_in_range!(minutes, -1080 => 1080);
+        Some(Self::from_seconds_unchecked(minutes * 60))
+    }
+
     pub(crate) const fn from_seconds_unchecked(seconds: i32) -> Self {
         let hours = seconds / 3600;
         let seconds = seconds % 3600;
@@ -170,6 +194,25 @@ impl UtcOffset {
         self.hours as i32 * 3600 + self.minutes as i32 * 60 + self.seconds as i32
     }
 
+    /// Returns the total number of whole minutes in this offset.
+    ///
+    /// This has the same sign convention as [`UtcOffset::total_seconds`]. Any leftover
+    /// seconds are truncated towards zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use eos::utc_offset;
+    /// assert_eq!(utc_offset!(05:30).total_minutes(), 330);
+    /// assert_eq!(utc_offset!(-05:00).total_minutes(), -300);
+    /// assert_eq!(utc_offset!(-00:30:30).total_minutes(), -30);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn total_minutes(&self) -> i32 {
+        self.total_seconds() / 60
+    }
+
     /// Unwraps this offset into their individual `(hours, minutes, seconds)` components.
     #[inline]
     #[must_use]
@@ -499,6 +542,45 @@ impl<Tz: TimeZone> DateTimeResolution<Tz> {
         matches!(self.kind, DateTimeResolutionKind::Missing)
     }
 
+    /// Returns the [*fold*] of the date time that [`DateTimeResolution::lenient`] resolves to.
+    ///
+    /// Following [PEP 495], a fold of `0` refers to the earlier occurrence of an ambiguous
+    /// date time and a fold of `1` refers to the later occurrence. Since the lenient
+    /// resolution always picks the earlier occurrence, this is `Some(0)` for ambiguous
+    /// date times. If the date time is unambiguous or missing then there's no fold to
+    /// choose from and [`None`] is returned.
+    ///
+    /// To resolve a specific occurrence, use [`DateTimeResolution::with_fold`]. To get the fold
+    /// of an already resolved date time, use [`DateTime::fold`].
+    ///
+    /// [*fold*]: https://www.python.org/dev/peps/pep-0495/#terminology
+    /// [PEP 495]: https://www.python.org/dev/peps/pep-0495/
+    #[must_use]
+    pub fn fold(&self) -> Option<u8> {
+        match self.kind {
+            DateTimeResolutionKind::Ambiguous => Some(0),
+            DateTimeResolutionKind::Missing | DateTimeResolutionKind::Unambiguous => None,
+        }
+    }
+
+    /// Returns the date time that was resolved for the given [*fold*].
+    ///
+    /// A fold of `0` returns the earlier date time and any other value returns the later
+    /// date time, similar to [`DateTimeResolution::earlier`] and [`DateTimeResolution::later`].
+    /// Unambiguous date times are returned regardless of the fold. This allows restoring
+    /// a previously stored fold, e.g. from [`DateTime::fold`].
+    ///
+    /// If the date time was skipped then an [`Error`] is returned.
+    ///
+    /// [*fold*]: https://www.python.org/dev/peps/pep-0495/#terminology
+    pub fn with_fold(self, fold: u8) -> Result<DateTime<Tz>, Error> {
+        if fold == 0 {
+            self.earlier()
+        } else {
+            self.later()
+        }
+    }
+
     /// Returns the earlier date time that was resolved.
     ///
     /// If the date time was skipped then an [`Error`] is returned.
@@ -644,9 +726,81 @@ pub trait TimeZone: Clone {
     /// for more information.
     ///
     /// The `date` and `time` parameters represent the local date and time.
-    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    ///
+    /// By default this is implemented using [`TimeZone::offset_at_local`].
+    fn resolve(&self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        self.offset_at_local(date, time)
+    }
+
+    /// Resolves the given local date and time using only [`TimeZone::offset`].
+    ///
+    /// This is the default implementation of [`TimeZone::resolve`] and is meant to
+    /// make custom timezones easier to write. Fixed offset timezones are always
+    /// unambiguous. Otherwise, the offsets a day before and a day after the local
+    /// time are checked to see whether the local time falls in a gap or a fold.
+    /// This assumes that there is at most one transition within that window.
+    ///
+    /// Timezones that know their transitions should implement [`TimeZone::resolve`]
+    /// directly since it's more efficient.
+    ///
+    /// The `date` and `time` parameters represent the local date and time.
+    ///
+    /// ```
+    /// use eos::{date, time, utc_offset, DateTime, TimeZone, Timestamp, Utc, UtcOffset};
+    ///
+    /// #[derive(Clone)]
+    /// struct Tokyo;
+    ///
+    /// impl TimeZone for Tokyo {
+    ///     fn offset(&self, _ts: Timestamp) -> UtcOffset {
+    ///         utc_offset!(+09:00)
+    ///     }
+    ///
+    ///     fn convert_utc(&self, utc: DateTime<Utc>) -> DateTime<Self> {
+    ///         utc.in_timezone(utc_offset!(+09:00)).with_timezone(Tokyo)
+    ///     }
+    ///
+    ///     fn is_fixed(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let resolved = Tokyo.resolve(date!(2022-01-01), time!(09:00));
+    /// assert!(resolved.is_unambiguous());
+    /// assert_eq!(resolved.lenient().in_timezone(Utc), eos::datetime!(2022-01-01 00:00));
+    /// ```
+    fn offset_at_local(&self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        // The local time as if it were in UTC
+        let local = date.at(time).timestamp().as_seconds();
+        if self.is_fixed() {
+            let offset = self.offset(Timestamp::from_seconds(local));
+            return DateTimeResolution::unambiguous(date, time, offset, self.clone());
+        }
+
+        let before = self.offset(Timestamp::from_seconds(local.saturating_sub(86400)));
+        let after = self.offset(Timestamp::from_seconds(local.saturating_add(86400)));
+        let is_valid = |offset: UtcOffset| {
+            let utc = local.saturating_sub(offset.total_seconds() as i64);
+            self.offset(Timestamp::from_seconds(utc)) == offset
+        };
+
+        if before == after {
+            return DateTimeResolution::unambiguous(date, time, before, self.clone());
+        }
+
+        match (is_valid(before), is_valid(after)) {
+            (true, true) => DateTimeResolution::ambiguous(date, time, before, after, self.clone()),
+            (true, false) => DateTimeResolution::unambiguous(date, time, before, self.clone()),
+            (false, true) => DateTimeResolution::unambiguous(date, time, after, self.clone()),
+            (false, false) => DateTimeResolution::missing(date, time, before, after, self.clone()),
+        }
+    }
 
     /// Resolves the given date and time to this time zone leniently.
     ///
@@ -659,7 +813,7 @@ pub trait TimeZone: Clone {
     /// [`TimeZone::resolve`] method instead.
     ///
     /// The `date` and `time` parameters represent the local date and time.
-    fn at(self, date: Date, time: Time) -> DateTime<Self>
+    fn at(&self, date: Date, time: Time) -> DateTime<Self>
     where
         Self: Sized,
     {
@@ -675,7 +829,7 @@ pub trait TimeZone: Clone {
     /// [`TimeZone::resolve`] method instead.
     ///
     /// The `date` and `time` parameters represent the local date and time.
-    fn at_exactly(self, date: Date, time: Time) -> Result<DateTime<Self>, Error>
+    fn at_exactly(&self, date: Date, time: Time) -> Result<DateTime<Self>, Error>
     where
         Self: Sized,
     {
@@ -683,10 +837,34 @@ pub trait TimeZone: Clone {
     }
 
     /// Converts from a UTC [`DateTime`] to a datetime in this timezone.
-    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
+    ///
+    /// Implementations that are not [`Copy`] should clone themselves only once,
+    /// into the returned [`DateTime`].
+    fn convert_utc(&self, utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized;
 
+    /// Returns the UNIX timestamp of the most recent transition at or before the given
+    /// UNIX timestamp, i.e. when the offset in effect at that timestamp took effect.
+    ///
+    /// If there is no such transition, such as with fixed offset timezones, then
+    /// [`None`] is returned. By default this returns [`None`].
+    fn previous_transition(&self, _ts: Timestamp) -> Option<Timestamp> {
+        None
+    }
+
+    /// Returns `true` if the timezone's data covers the given UNIX timestamp.
+    ///
+    /// Timezones backed by a limited set of transitions have to extrapolate the
+    /// offset for timestamps outside of them, e.g. by extending the earliest
+    /// known offset to the beginning of time. This allows callers to detect when
+    /// the value returned by [`TimeZone::offset`] is such an extrapolation.
+    ///
+    /// By default this returns `true`.
+    fn covers(&self, _ts: Timestamp) -> bool {
+        true
+    }
+
     /// Returns `true` if the timezone is fixed offset.
     ///
     /// This is used as an optimisation hint in some cases. A fixed
@@ -702,20 +880,20 @@ impl TimeZone for UtcOffset {
         *self
     }
 
-    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    fn resolve(&self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
         // This is always unambiguous
-        DateTimeResolution::unambiguous(date, time, self, self)
+        DateTimeResolution::unambiguous(date, time, *self, *self)
     }
 
-    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    fn convert_utc(&self, mut utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
-        utc.shift(self);
-        utc.with_timezone(self)
+        utc.shift(*self);
+        utc.with_timezone(*self)
     }
 
     fn is_fixed(&self) -> bool {
@@ -723,6 +901,51 @@ impl TimeZone for UtcOffset {
     }
 }
 
+#[cfg(feature = "parsing")]
+impl FromIsoFormat for UtcOffset {
+    /// Parses an ISO-8601 formatted UTC offset string into a [`UtcOffset`].
+    ///
+    /// The syntax accepted by this function are:
+    ///
+    /// - `±HH` (e.g. `+12`)
+    /// - `±HH:MM` (e.g. `+12:23`)
+    /// - `±HH:MM:SS` (e.g. `+12:23:45`)
+    /// - `Z` (represents UTC)
+    ///
+    /// The hours must be between `0..=23` and the minutes and seconds must be
+    /// between `0..=59`, otherwise [`ParseError::OutOfBounds`] is returned.
+    /// Any trailing characters result in [`ParseError::UnexpectedChar`].
+    ///
+    /// ```
+    /// use eos::{fmt::FromIsoFormat, utc_offset, UtcOffset};
+    ///
+    /// assert_eq!(UtcOffset::from_iso_format("+05:30")?, utc_offset!(+05:30));
+    /// assert_eq!(UtcOffset::from_iso_format("-08")?, utc_offset!(-08:00));
+    /// assert_eq!(UtcOffset::from_iso_format("Z")?, UtcOffset::UTC);
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    fn from_iso_format(s: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(s);
+        let offset = parser.parse_utc_offset()?;
+        match parser.advance() {
+            Some(c) => Err(ParseError::UnexpectedChar(c as char)),
+            None => Ok(offset),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for UtcOffset {
+    type Err = ParseError;
+
+    /// Parses a UTC offset string into a [`UtcOffset`].
+    ///
+    /// This is equivalent to [`UtcOffset::from_iso_format`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_iso_format(s)
+    }
+}
+
 /// The UTC timezone.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Utc;
@@ -737,15 +960,15 @@ impl TimeZone for Utc {
         UtcOffset::UTC
     }
 
-    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    fn resolve(&self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
         // This is always unambiguous
-        DateTimeResolution::unambiguous(date, time, UtcOffset::UTC, self)
+        DateTimeResolution::unambiguous(date, time, UtcOffset::UTC, Utc)
     }
 
-    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
+    fn convert_utc(&self, utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
@@ -786,4 +1009,52 @@ mod tests {
         assert!(UtcOffset::from_seconds(3600).is_some());
         assert!(UtcOffset::from_seconds(-3600).is_some());
     }
+
+    #[test]
+    fn test_minutes() {
+        assert!(UtcOffset::from_minutes(-1080).is_some());
+        assert!(UtcOffset::from_minutes(1080).is_some());
+        assert!(UtcOffset::from_minutes(-1081).is_none());
+        assert!(UtcOffset::from_minutes(1081).is_none());
+        assert_eq!(UtcOffset::from_minutes(0), Some(UtcOffset::UTC));
+        assert_eq!(
+            UtcOffset::from_minutes(-90).map(UtcOffset::into_hms),
+            Some((-1, -30, 0))
+        );
+
+        for minutes in -1080..=1080 {
+            let offset = UtcOffset::from_minutes(minutes).unwrap();
+            assert_eq!(offset.total_minutes(), minutes);
+            assert_eq!(offset.total_seconds(), minutes * 60);
+        }
+
+        // Seconds are truncated towards zero
+        assert_eq!(UtcOffset::from_seconds(-3659).unwrap().total_minutes(), -60);
+        assert_eq!(UtcOffset::from_seconds(3659).unwrap().total_minutes(), 60);
+    }
+
+    #[test]
+    fn test_resolution_fold() {
+        let date = crate::date!(2021 - 11 - 07);
+        let time = crate::time!(01:30);
+        let edt = UtcOffset::from_hms(-4, 0, 0).unwrap();
+        let est = UtcOffset::from_hms(-5, 0, 0).unwrap();
+
+        let resolution = DateTimeResolution::ambiguous(date, time, edt, est, Utc);
+        assert_eq!(resolution.fold(), Some(0));
+        assert_eq!(resolution.with_fold(0).unwrap().offset(), edt);
+        assert_eq!(resolution.with_fold(1).unwrap().offset(), est);
+        assert_eq!(resolution.with_fold(0), resolution.earlier());
+        assert_eq!(resolution.with_fold(1), resolution.later());
+
+        let resolution = DateTimeResolution::missing(date, time, est, edt, Utc);
+        assert_eq!(resolution.fold(), None);
+        assert!(resolution.with_fold(0).is_err());
+        assert!(resolution.with_fold(1).is_err());
+
+        let resolution = DateTimeResolution::unambiguous(date, time, UtcOffset::UTC, Utc);
+        assert_eq!(resolution.fold(), None);
+        assert_eq!(resolution.with_fold(0), resolution.with_fold(1));
+        assert_eq!(resolution.with_fold(1).unwrap().fold(), None);
+    }
 }
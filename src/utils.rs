@@ -39,14 +39,34 @@ macro_rules! divmod {
 pub(crate) use divmod;
 pub(crate) use divrem;
 
+/// Strips the trailing zeroes from a non-zero fraction that is written with `width` digits.
+///
+/// Returns the remaining fraction and the number of digits it should be zero-padded to,
+/// e.g. `(500_000, 6)` becomes `(5, 1)` and `(10, 9)` becomes `(1, 8)`.
+pub(crate) fn trim_trailing_zeroes(mut fraction: u64, mut width: usize) -> (u64, usize) {
+    debug_assert!(fraction != 0);
+    while fraction % 10 == 0 {
+        fraction /= 10;
+        width -= 1;
+    }
+    (fraction, width)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_divrem() {
         assert_eq!(divrem!(-23, 12), (-1, -11));
         assert_eq!(divrem!(27, 12), (2, 3));
         assert_eq!(divrem!(23, -12), (-1, 11));
     }
+
+    #[test]
+    fn test_trim_trailing_zeroes() {
+        use super::trim_trailing_zeroes;
+        assert_eq!(trim_trailing_zeroes(500_000, 6), (5, 1));
+        assert_eq!(trim_trailing_zeroes(123_456, 6), (123_456, 6));
+        assert_eq!(trim_trailing_zeroes(10, 9), (1, 8));
+        assert_eq!(trim_trailing_zeroes(100_000_000, 9), (1, 1));
+    }
 }
@@ -1,6 +1,6 @@
 use eos::{
     date, datetime,
-    fmt::{format_spec, FormatSpec, FormatSpecKind},
+    fmt::{format_spec, FormatSpec, FormatSpecKind, OwnedFormatSpec},
     format_dt, time, utc_offset, DateTime, Utc,
 };
 
@@ -42,6 +42,121 @@ fn parse_valid_format_spec() {
     );
 }
 
+#[test]
+fn parse_spec_iter() {
+    use eos::fmt::{parse_spec, parse_spec_iter};
+
+    for format in [
+        "",
+        "hello world",
+        "%Y-%m-%d",
+        "%#d/%_m %% %Y",
+        "%a %B %d %Y at %I:%M %p",
+    ] {
+        let specs: Result<Vec<_>, _> = parse_spec_iter(format).collect();
+        assert_eq!(specs, parse_spec(format));
+    }
+
+    let mut iter = parse_spec_iter("a%Lb");
+    assert_eq!(iter.next(), Some(Ok(FormatSpec::raw("a"))));
+    assert_eq!(iter.next(), Some(Err(eos::fmt::Error::UnknownSpecifier(b'L'))));
+}
+
+#[test]
+fn parse_from_spec_iter() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::{parse_spec_iter, ParseError};
+
+    let format = String::from("%Y-%m-%dT%H:%M:%S.%f%o");
+    for _ in 0..3 {
+        assert_eq!(
+            DateTime::parse_from_spec_iter("2020-02-12T12:34:56.123456-05:00", parse_spec_iter(&format))?,
+            datetime!(2020-02-12 12:34:56 -05:00).with_microsecond(123456).unwrap()
+        );
+    }
+
+    assert_eq!(
+        DateTime::parse_from_spec_iter("2020-02-12", parse_spec_iter("%Y-%m-%L")),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec_iter("2020-02-12", parse_spec_iter("%Y-%m-%d %H")),
+        Err(ParseError::UnexpectedEnd)
+    );
+    Ok(())
+}
+
+#[test]
+fn owned_format_spec() {
+    let owned = {
+        let input = String::from("%Y-%m-%d at %H:%M");
+        let owned = OwnedFormatSpec::parse(&input).unwrap();
+        assert_eq!(owned.as_slice(), eos::fmt::parse_spec(&input).unwrap().as_slice());
+        owned
+    };
+
+    let cloned = owned.clone();
+    assert_eq!(owned, cloned);
+    drop(owned);
+
+    let dt = datetime!(2022-01-23 18:20:30);
+    assert_eq!(dt.format(&cloned).to_string(), "2022-01-23 at 18:20");
+
+    let raw = FormatSpec::raw("hello").into_owned();
+    assert_eq!(raw.as_slice(), &[FormatSpec::raw("hello")]);
+    assert_eq!(dt.format(&raw).to_string(), "hello");
+}
+
+#[test]
+fn owned_format_spec_moves_and_drops() {
+    let dt = datetime!(2022-01-23 18:20:30);
+    let expected = "date: 2022-01-23, time: 18:20 (UTC)";
+
+    // Moving the value into and out of heap allocations keeps the fragments valid
+    let owned = OwnedFormatSpec::parse(&String::from("date: %Y-%m-%d, time: %H:%M (%Z)")).unwrap();
+    let boxed = Box::new(owned);
+    let owned = *boxed;
+    assert_eq!(dt.format(&owned).to_string(), expected);
+
+    // Growing a Vec moves every element to a new allocation
+    let mut specs = Vec::with_capacity(1);
+    specs.push(owned);
+    for i in 0..64 {
+        specs.push(OwnedFormatSpec::parse(&format!("[{}] %Y", i)).unwrap());
+    }
+    assert_eq!(dt.format(&specs[0]).to_string(), expected);
+    for (i, spec) in specs.iter().enumerate().skip(1) {
+        assert_eq!(dt.format(spec).to_string(), format!("[{}] 2022", i - 1));
+    }
+
+    // Clones have their own buffer so they outlive the original
+    let original = specs.swap_remove(0);
+    let cloned = original.clone();
+    assert_eq!(original, cloned);
+    drop(original);
+    drop(specs);
+    assert_eq!(dt.format(&cloned).to_string(), expected);
+
+    // Moving across threads
+    let moved = std::thread::spawn(move || cloned).join().unwrap();
+    assert_eq!(dt.format(&moved).to_string(), expected);
+    assert_eq!(moved.clone().as_slice(), moved.as_slice());
+}
+
+#[test]
+fn owned_format_spec_across_threads() {
+    static SPEC: std::sync::OnceLock<OwnedFormatSpec> = std::sync::OnceLock::new();
+
+    let spec = SPEC.get_or_init(|| OwnedFormatSpec::parse(&String::from("%Y/%m/%d")).unwrap());
+    std::thread::scope(|s| {
+        for day in 1..=4 {
+            s.spawn(move || {
+                let date = date!(2022 - 03 - 01).with_day(day).unwrap();
+                assert_eq!(date.format(spec).to_string(), format!("2022/03/{:02}", day));
+            });
+        }
+    });
+}
+
 #[test]
 fn test_date_format() {
     assert_eq!(format_dt!("%Y-%m-%d", date!(2021 - 02 - 28)).to_string(), "2021-02-28");
@@ -58,6 +173,28 @@ fn test_time_format() {
     assert_eq!(format_dt!("%#I:%M:%S %p", time!(13:45:59)).to_string(), "1:45:59 PM");
 }
 
+#[test]
+fn test_hour12_format() {
+    for (time, expected) in [
+        (time!(00:00), "12:00 AM"),
+        (time!(00:30), "12:30 AM"),
+        (time!(01:00), "01:00 AM"),
+        (time!(11:59), "11:59 AM"),
+        (time!(12:00), "12:00 PM"),
+        (time!(13:00), "01:00 PM"),
+        (time!(23:59), "11:59 PM"),
+    ] {
+        assert_eq!(format_dt!("%I:%M %p", time).to_string(), expected);
+        assert_eq!(
+            format_dt!("%I:%M %p", date!(2022 - 01 - 01).at(time)).to_string(),
+            expected
+        );
+    }
+
+    assert_eq!(format_dt!("%#I %_I", time!(00:00)).to_string(), "12 12");
+    assert_eq!(format_dt!("%#I %_I", time!(13:00)).to_string(), "1  1");
+}
+
 #[test]
 fn test_datetime_format() {
     let dt = datetime!(2022-01-23 18:20:30 -05:00);
@@ -100,6 +237,52 @@ fn test_datetime_to_rfc3339() {
     assert_eq!(o5.to_rfc3339().to_string(), "2001-02-03 04:05:01.123000+16:18");
 }
 
+#[test]
+fn test_datetime_to_rfc3339_nanoseconds() {
+    let dt = datetime!(2001-02-03 04:05:01);
+    assert_eq!(
+        dt.to_rfc3339().with_nanoseconds().to_string(),
+        "2001-02-03 04:05:01+00:00"
+    );
+
+    let half = dt.with_millisecond(500).unwrap();
+    assert_eq!(half.to_rfc3339().to_string(), "2001-02-03 04:05:01.500000+00:00");
+    assert_eq!(
+        half.to_rfc3339().with_nanoseconds().to_string(),
+        "2001-02-03 04:05:01.5+00:00"
+    );
+
+    let nanos = dt
+        .with_nanosecond(123_456_789)
+        .unwrap()
+        .with_timezone(utc_offset!(-05:00));
+    assert_eq!(nanos.to_rfc3339().to_string(), "2001-02-03 04:05:01.123456-05:00");
+    assert_eq!(
+        nanos.to_rfc3339().with_nanoseconds().to_string(),
+        "2001-02-03 04:05:01.123456789-05:00"
+    );
+
+    let small = dt.with_nanosecond(1_000).unwrap();
+    assert_eq!(
+        small.to_rfc3339().with_nanoseconds().to_string(),
+        "2001-02-03 04:05:01.000001+00:00"
+    );
+    let small = dt.with_nanosecond(1).unwrap();
+    assert_eq!(
+        small.to_rfc3339().with_nanoseconds().to_string(),
+        "2001-02-03 04:05:01.000000001+00:00"
+    );
+
+    for dt in [
+        half.with_timezone(utc_offset!(00:00)),
+        nanos,
+        small.with_timezone(utc_offset!(00:00)),
+    ] {
+        let out = dt.to_rfc3339().with_nanoseconds().to_string();
+        assert_eq!(DateTime::from_rfc3339(&out).unwrap(), dt);
+    }
+}
+
 #[test]
 fn test_datetime_rfc3339_roundtrip() {
     let dates = [
@@ -137,6 +320,59 @@ fn test_datetime_rfc3339_roundtrip() {
     }
 }
 
+#[test]
+fn test_leap_second_parse() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::{FromIsoFormat, ParseError};
+
+    let expected = datetime!(2016-12-31 23:59:59).with_nanosecond(1_000_000_000).unwrap();
+    let dt = DateTime::parse_from_spec("2016-12-31 23:59:60", format_spec!("%Y-%m-%d %H:%M:%S"))?;
+    assert_eq!(dt, expected);
+    assert_eq!(dt, DateTime::from_iso_format("2016-12-31T23:59:60")?);
+    assert_eq!(dt.format(format_spec!("%H:%M:%S")).to_string(), "23:59:60");
+
+    // The order of %S and %f does not matter
+    let expected = expected.with_nanosecond(1_500_000_000).unwrap();
+    let dt = DateTime::parse_from_spec("2016-12-31 23:59:60.5", format_spec!("%Y-%m-%d %H:%M:%S.%f"))?;
+    assert_eq!(dt, expected);
+    let dt = DateTime::parse_from_spec("500000000 23:59:60 2016-12-31", format_spec!("%f %H:%M:%S %Y-%m-%d"))?;
+    assert_eq!(dt, expected);
+
+    assert_eq!(
+        DateTime::parse_from_spec("23:59:61", format_spec!("%H:%M:%S")),
+        Err(ParseError::OutOfBounds)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("23:59:99", format_spec!("%H:%M:%S")),
+        Err(ParseError::OutOfBounds)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_hour12_parse() -> Result<(), eos::fmt::ParseError> {
+    for (s, expected) in [
+        ("12:00 AM", time!(00:00)),
+        ("12:30 am", time!(00:30)),
+        ("12:00 PM", time!(12:00)),
+        ("12:30 pm", time!(12:30)),
+        ("01:00 AM", time!(01:00)),
+        ("01:00 PM", time!(13:00)),
+        ("11:59 PM", time!(23:59)),
+    ] {
+        let dt = DateTime::parse_from_spec(s, format_spec!("%I:%M %p"))?;
+        assert_eq!(dt.time(), expected);
+
+        // The meridiem can come before the hour
+        let swapped = format!("{} {}", &s[6..], &s[..5]);
+        let dt = DateTime::parse_from_spec(&swapped, format_spec!("%p %I:%M"))?;
+        assert_eq!(dt.time(), expected);
+    }
+
+    assert!(DateTime::parse_from_spec("00:00 AM", format_spec!("%I:%M %p")).is_err());
+    assert!(DateTime::parse_from_spec("13:00 PM", format_spec!("%I:%M %p")).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_datetime_from_spec() -> Result<(), eos::fmt::ParseError> {
     assert_eq!(
@@ -164,6 +400,131 @@ fn test_datetime_from_spec() -> Result<(), eos::fmt::ParseError> {
     Ok(())
 }
 
+#[test]
+fn test_datetime_from_spec_zone_name() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::ParseError;
+
+    let spec = format_spec!("%Y-%m-%d %H:%M %Z");
+    let expected = datetime!(2022-01-23 18:20 +00:00);
+    assert_eq!(DateTime::parse_from_spec("2022-01-23 18:20 UTC", spec)?, expected);
+    assert_eq!(DateTime::parse_from_spec("2022-01-23 18:20 GMT", spec)?, expected);
+    assert_eq!(DateTime::parse_from_spec("2022-01-23 18:20 UT", spec)?, expected);
+    assert_eq!(DateTime::parse_from_spec("2022-01-23 18:20 Z", spec)?, expected);
+
+    // Military timezones
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 A", spec)?,
+        datetime!(2022-01-23 18:20 +01:00)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 M", spec)?,
+        datetime!(2022-01-23 18:20 +12:00)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 N", spec)?,
+        datetime!(2022-01-23 18:20 -01:00)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 Y", spec)?,
+        datetime!(2022-01-23 18:20 -12:00)
+    );
+
+    // Ambiguous or unknown names
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 EST", spec),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 J", spec),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 AEST", spec),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-23 18:20 ", spec),
+        Err(ParseError::UnexpectedEnd)
+    );
+
+    // Roundtrip
+    let utc = datetime!(2022-01-23 18:20:30).with_timezone(eos::UtcOffset::UTC);
+    let out = format_dt!("%Y-%m-%d %H:%M:%S %Z", datetime!(2022-01-23 18:20:30)).to_string();
+    assert_eq!(
+        DateTime::parse_from_spec(out, format_spec!("%Y-%m-%d %H:%M:%S %Z"))?,
+        utc
+    );
+    Ok(())
+}
+
+#[test]
+fn test_datetime_parse_any() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::ParseError;
+
+    let iso: &[FormatSpec] = &format_spec!("%Y-%m-%dT%H:%M:%S%o");
+    let rfc2822: &[FormatSpec] = &format_spec!("%a, %d %b %Y %H:%M:%S %z");
+    let specs = [iso, rfc2822];
+    let expected = datetime!(2022-01-23 18:20:30 -05:00);
+
+    assert_eq!(DateTime::parse_any("2022-01-23T18:20:30-05:00", &specs)?, expected);
+    assert_eq!(
+        DateTime::parse_any("Sun, 23 Jan 2022 18:20:30 -0500", &specs)?,
+        expected
+    );
+    assert_eq!(
+        DateTime::parse_any("Sun, 23 Jan 2022 18:20:30 -0500", &[rfc2822, iso])?,
+        expected
+    );
+
+    // The last error is returned
+    assert_eq!(
+        DateTime::parse_any("2022-01-23T18:20:30", &specs),
+        DateTime::parse_from_spec("2022-01-23T18:20:30", rfc2822)
+    );
+    assert_eq!(
+        DateTime::parse_any("Sun, 23 Jan 2022", &specs),
+        Err(ParseError::UnexpectedEnd)
+    );
+    assert_eq!(
+        DateTime::parse_any("2022-01-23T18:20:30-05:00", &[]),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iso_week_format_parse_roundtrip() {
+    // These dates are near ISO year boundaries, where the ISO year differs from the Gregorian year
+    let dates = [
+        date!(2004 - 12 - 31),
+        date!(2005 - 01 - 01),
+        date!(2005 - 01 - 02),
+        date!(2005 - 01 - 03),
+        date!(2008 - 12 - 28),
+        date!(2008 - 12 - 29),
+        date!(2009 - 12 - 31),
+        date!(2010 - 01 - 03),
+        date!(2020 - 12 - 31),
+        date!(2021 - 01 - 03),
+        date!(2022 - 06 - 15),
+    ];
+
+    let spec = format_spec!("%G-W%V-%u");
+    for date in dates {
+        let dt = date.at(time!(00:00)).with_timezone(utc_offset!(00:00));
+        let out = dt.format(spec).to_string();
+        assert_eq!(out, date.iso_week().to_string());
+        assert_eq!(DateTime::parse_from_spec(&out, spec), Ok(dt), "{}", out);
+    }
+
+    // Mixing Gregorian and ISO week date components
+    let dt = datetime!(2008-12-29 00:00 +00:00);
+    let spec = format_spec!("%Y-%m-%d %G-W%V-%u");
+    let out = dt.format(spec).to_string();
+    assert_eq!(out, "2008-12-29 2009-W01-1");
+    assert_eq!(DateTime::parse_from_spec(&out, spec), Ok(dt));
+}
+
 #[test]
 fn test_datetime_format_parse_roundtrip() {
     let dates = [
@@ -212,3 +573,499 @@ fn test_datetime_format_parse_roundtrip() {
         }
     }
 }
+
+#[test]
+fn test_datetime_parse_many() {
+    use eos::fmt::ParseError;
+
+    let spec = format_spec!("%Y-%m-%d %H:%M%o");
+    let rows = [
+        String::from("2022-01-23 18:20-05:00"),
+        String::from("2022-01-23 18:20"),
+        String::from("2022-02-30 10:00+00:00"),
+        String::from("2022-03-01 00:00+09:00"),
+        String::from("garbage"),
+    ];
+    let (parsed, errors) = DateTime::parse_many(rows.iter().map(String::as_str), spec);
+    assert_eq!(
+        parsed,
+        [datetime!(2022-01-23 18:20 -05:00), datetime!(2022-03-01 00:00 +09:00)]
+    );
+    assert_eq!(
+        errors,
+        [
+            (1, ParseError::UnexpectedEnd),
+            (2, ParseError::OutOfBounds),
+            (4, ParseError::UnexpectedNonDigit)
+        ]
+    );
+
+    let (parsed, errors) = DateTime::parse_many([], spec);
+    assert!(parsed.is_empty());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_datetime_to_string_with() -> Result<(), eos::fmt::Error> {
+    use eos::fmt::Error;
+
+    let dt = datetime!(2022-01-23 18:20:30 -05:00);
+    assert_eq!(dt.to_string_with("%Y-%m-%d %H:%M")?, "2022-01-23 18:20");
+    assert_eq!(
+        dt.to_string_with("%a, %d %b %Y %H:%M:%S %z")?,
+        "Sun, 23 Jan 2022 18:20:30 -0500"
+    );
+    assert_eq!(dt.to_string_with("")?, "");
+    assert_eq!(dt.to_string_with("no specifiers")?, "no specifiers");
+
+    // Matches the two step process
+    let format = String::from("%#I:%M %p on %A");
+    let spec = eos::fmt::parse_spec(&format)?;
+    assert_eq!(dt.to_string_with(&format)?, dt.format(&spec).to_string());
+
+    assert_eq!(dt.to_string_with("%Y-%m-%d %L"), Err(Error::UnknownSpecifier(b'L')));
+    assert_eq!(dt.to_string_with("%Y-%m-%d %"), Err(Error::SpecifierNotFound));
+    assert_eq!(dt.to_string_with("%#"), Err(Error::SpecifierNotFound));
+    Ok(())
+}
+
+#[test]
+fn test_iso_week_year_padding() {
+    use eos::{Date, Time};
+
+    let cases = [
+        (Date::new(-5, 6, 15).unwrap(), "-005", "-5", "  -5"),
+        (Date::new(12000, 6, 15).unwrap(), "12000", "12000", "12000"),
+        (Date::new(-12000, 6, 15).unwrap(), "-12000", "-12000", "-12000"),
+        (Date::new(7, 6, 15).unwrap(), "0007", "7", "   7"),
+    ];
+
+    for (date, zero, empty, space) in cases {
+        let dt = date.at(Time::MIDNIGHT);
+        assert_eq!(date.format(format_spec!("%G")).to_string(), zero);
+        assert_eq!(dt.format(format_spec!("%G")).to_string(), zero);
+        assert_eq!(dt.format(format_spec!("%#G")).to_string(), empty);
+        assert_eq!(dt.format(format_spec!("%_G")).to_string(), space);
+        // The ISO week year is the same as the calendar year here
+        assert_eq!(
+            dt.format(format_spec!("%G|%#G|%_G")).to_string(),
+            dt.format(format_spec!("%Y|%#Y|%_Y")).to_string()
+        );
+    }
+
+    // 12000-01-01 is a Saturday so it belongs to the last ISO week of 11999
+    let dt = Date::new(12000, 1, 1).unwrap().at(Time::MIDNIGHT);
+    assert_eq!(dt.format(format_spec!("%G-W%V-%u")).to_string(), "11999-W52-6");
+}
+
+#[test]
+fn test_timestamp_format() {
+    let dt = datetime!(2022-01-03 10:38:45 +09:00);
+    assert_eq!(format_dt!("%s", dt).to_string(), "1641173925");
+    assert_eq!(
+        format_dt!("%Y-%m-%d %H:%M:%S%z (%s)", dt).to_string(),
+        "2022-01-03 10:38:45+0900 (1641173925)"
+    );
+    assert_eq!(format_dt!("%s", datetime!(1969-12-31 23:59:59)).to_string(), "-1");
+    assert_eq!(
+        eos::fmt::parse_spec("%s").unwrap(),
+        [FormatSpec::new(FormatSpecKind::Timestamp)]
+    );
+
+    // Dates and times on their own are not instants in time
+    assert_eq!(format_dt!("[%s]", date!(2022 - 01 - 03)).to_string(), "[]");
+    assert_eq!(format_dt!("[%s]", time!(10:38:45)).to_string(), "[]");
+}
+
+#[test]
+fn test_timestamp_parse() {
+    let dt = DateTime::parse_from_spec("1641173925 +0900", format_spec!("%s %z")).unwrap();
+    assert_eq!(dt, datetime!(2022-01-03 10:38:45 +09:00));
+    assert_eq!(dt.offset(), utc_offset!(+09:00));
+
+    // The offset can come before the timestamp and other components are ignored
+    let dt = DateTime::parse_from_spec("-05:00 2000-01-01 1641173925", format_spec!("%o %Y-%m-%d %s")).unwrap();
+    assert_eq!(dt, datetime!(2022-01-02 20:38:45 -05:00));
+
+    let dt = DateTime::parse_from_spec("-1", format_spec!("%s")).unwrap();
+    assert_eq!(dt, datetime!(1969-12-31 23:59:59));
+
+    assert_eq!(
+        DateTime::parse_from_spec("", format_spec!("%s")),
+        Err(eos::fmt::ParseError::UnexpectedNonDigit)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("99999999999999999999", format_spec!("%s")),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("9223372036854775807", format_spec!("%s")),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+
+    // The bounds are inclusive
+    let min = eos::Date::MIN.at(eos::Time::MIN);
+    let max = eos::Date::MAX.at(eos::Time::MAX).with_nanosecond(0).unwrap();
+    for dt in [min, max] {
+        let formatted = dt.format(format_spec!("%s %z")).to_string();
+        assert_eq!(
+            DateTime::parse_from_spec(&formatted, format_spec!("%s %z")).unwrap(),
+            dt
+        );
+    }
+
+    // The local date and time has to be in range after the offset is applied
+    assert_eq!(
+        DateTime::parse_from_spec("-1096225401599 -0500", format_spec!("%s %z")),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("971890963198 +0500", format_spec!("%s %z")),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+
+    // Roundtrip with the runtime parser
+    let spec = eos::fmt::parse_spec("%s%z").unwrap();
+    let dt = datetime!(1999-12-31 18:00:00 -06:00);
+    let formatted = dt.format(&spec).to_string();
+    assert_eq!(formatted, "946684800-0600");
+    assert_eq!(DateTime::parse_from_spec(&formatted, &spec).unwrap(), dt);
+}
+
+#[test]
+fn test_http_date() {
+    let dt = datetime!(1994-11-06 08:49:37);
+    assert_eq!(dt.to_http_date().unwrap().to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(dt));
+
+    // Only four digit years can be represented
+    let dt = datetime!(9999-12-31 23:59:59);
+    assert_eq!(dt.to_http_date().unwrap().to_string(), "Fri, 31 Dec 9999 23:59:59 GMT");
+    let dt = datetime!(0000-01-01 00:00);
+    assert_eq!(dt.to_http_date().unwrap().to_string(), "Sat, 01 Jan 0000 00:00:00 GMT");
+    assert_eq!(
+        datetime!(10000-01-01 00:00).to_http_date().err(),
+        Some(eos::Error::OutOfRange)
+    );
+    let dt = datetime!(2000-01-01 00:00).with_year(-5).unwrap();
+    assert_eq!(dt.to_http_date().err(), Some(eos::Error::OutOfRange));
+
+    // Parsing also requires exactly four digit years
+    for s in [
+        "Sun, 06 Nov 10000 08:49:37 GMT",
+        "Sun, 06 Nov -1994 08:49:37 GMT",
+        "Sun, 06 Nov 994 08:49:37 GMT",
+        "Sun Nov  6 08:49:37 10000",
+        "Sun Nov  6 08:49:37 -1994",
+    ] {
+        assert!(DateTime::from_http_date(s).is_err(), "{:?} was accepted", s);
+    }
+    assert_eq!(
+        DateTime::from_http_date("Sun, 06 Nov 10000 08:49:37 GMT"),
+        Err(eos::fmt::ParseError::UnexpectedChar('0'))
+    );
+
+    // Fractional seconds are dropped
+    let dt = datetime!(2022-01-09 00:05:03).with_millisecond(250).unwrap();
+    let formatted = dt.to_http_date().unwrap().to_string();
+    assert_eq!(formatted, "Sun, 09 Jan 2022 00:05:03 GMT");
+    assert_eq!(DateTime::from_http_date(&formatted), Ok(datetime!(2022-01-09 00:05:03)));
+
+    // Obsolete RFC 850 format
+    assert_eq!(
+        DateTime::from_http_date("Sunday, 06-Nov-94 08:49:37 GMT"),
+        Ok(datetime!(1994-11-06 08:49:37))
+    );
+    assert_eq!(
+        DateTime::from_http_date("Thursday, 01-Jan-70 00:00:00 GMT"),
+        Ok(datetime!(1970-01-01 00:00))
+    );
+    assert_eq!(
+        DateTime::from_http_date("Monday, 31-Dec-69 23:59:59 GMT"),
+        Ok(datetime!(2069-12-31 23:59:59))
+    );
+
+    // Obsolete asctime format
+    assert_eq!(
+        DateTime::from_http_date("Sun Nov  6 08:49:37 1994"),
+        Ok(datetime!(1994-11-06 08:49:37))
+    );
+    assert_eq!(
+        DateTime::from_http_date("Wed Nov 16 08:49:37 1994"),
+        Ok(datetime!(1994-11-16 08:49:37))
+    );
+
+    use eos::fmt::ParseError;
+    assert_eq!(DateTime::from_http_date(""), Err(ParseError::UnexpectedEnd));
+    assert_eq!(
+        DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 UTC"),
+        Err(ParseError::UnexpectedChar('U'))
+    );
+    assert_eq!(
+        DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT "),
+        Err(ParseError::UnexpectedChar(' '))
+    );
+    assert_eq!(
+        DateTime::from_http_date("Sun, 31 Nov 1994 08:49:37 GMT"),
+        Err(ParseError::OutOfBounds)
+    );
+    assert!(DateTime::from_http_date("Sun, 06 Foo 1994 08:49:37 GMT").is_err());
+    assert!(DateTime::from_http_date("Sun Nov 6 08:49:37 1994").is_err());
+}
+
+#[test]
+fn test_optional_literal() {
+    use eos::fmt::ParseError;
+
+    let spec = [
+        FormatSpec::new(FormatSpecKind::Year),
+        FormatSpec::raw("-"),
+        FormatSpec::new(FormatSpecKind::Month),
+        FormatSpec::raw("-"),
+        FormatSpec::new(FormatSpecKind::Day),
+        FormatSpec::optional("T"),
+        FormatSpec::new(FormatSpecKind::Hour),
+        FormatSpec::raw(":"),
+        FormatSpec::new(FormatSpecKind::Minute),
+        FormatSpec::optional("Z"),
+    ];
+
+    let expected = datetime!(2022-01-09 10:30 +00:00);
+    assert_eq!(DateTime::parse_from_spec("2022-01-09T10:30Z", spec), Ok(expected));
+    assert_eq!(DateTime::parse_from_spec("2022-01-0910:30Z", spec), Ok(expected));
+    assert_eq!(DateTime::parse_from_spec("2022-01-09T10:30", spec), Ok(expected));
+    assert_eq!(DateTime::parse_from_spec("2022-01-0910:30", spec), Ok(expected));
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09 10:30", spec),
+        Err(ParseError::UnexpectedNonDigit)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09TT10:30", spec),
+        Err(ParseError::UnexpectedNonDigit)
+    );
+
+    // The optional literal is always written when formatting
+    assert_eq!(expected.format(&spec).to_string(), "2022-01-09T10:30Z");
+
+    // Partial matches do not consume anything
+    let spec = [
+        FormatSpec::new(FormatSpecKind::Year),
+        FormatSpec::raw("-"),
+        FormatSpec::new(FormatSpecKind::Month),
+        FormatSpec::raw("-"),
+        FormatSpec::new(FormatSpecKind::Day),
+        FormatSpec::optional(" UTC"),
+        FormatSpec::raw(" U"),
+    ];
+    let expected = datetime!(2022-01-09 00:00 +00:00);
+    assert_eq!(DateTime::parse_from_spec("2022-01-09 UTC U", spec), Ok(expected));
+    assert_eq!(DateTime::parse_from_spec("2022-01-09 U", spec), Ok(expected));
+
+    let owned = OwnedFormatSpec::new(&spec);
+    assert_eq!(owned.as_slice(), spec);
+    assert_eq!(DateTime::parse_from_spec("2022-01-09 U", &owned), Ok(expected));
+}
+
+#[test]
+fn test_fractional_separators() {
+    use eos::fmt::ParseError;
+
+    let expected = datetime!(2022-01-09 10:30:05 +00:00)
+        .with_nanosecond(250_000_000)
+        .unwrap();
+    for (input, spec) in [
+        ("2022-01-09 10:30:05.25", format_spec!("%Y-%m-%d %H:%M:%S.%f")),
+        ("2022-01-09 10:30:05,25", format_spec!("%Y-%m-%d %H:%M:%S,%f")),
+        ("2022-01-09 10:30:05.25", format_spec!("%Y-%m-%d %H:%M:%S%f")),
+        ("2022-01-09 10:30:05,25", format_spec!("%Y-%m-%d %H:%M:%S%f")),
+        ("2022-01-09 10:30:0525", format_spec!("%Y-%m-%d %H:%M:%S%f")),
+    ] {
+        assert_eq!(DateTime::parse_from_spec(input, spec), Ok(expected), "{input}");
+    }
+
+    // A separator without any digits following it is an error
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09 10:30:05.", format_spec!("%Y-%m-%d %H:%M:%S%f")),
+        Err(ParseError::UnexpectedNonDigit)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09 10:30:05,", format_spec!("%Y-%m-%d %H:%M:%S,%f")),
+        Err(ParseError::UnexpectedNonDigit)
+    );
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09 10:30:05", format_spec!("%Y-%m-%d %H:%M:%S%f")),
+        Err(ParseError::UnexpectedNonDigit)
+    );
+}
+
+#[test]
+fn test_fractional_timestamp_parse() {
+    let dt = DateTime::parse_from_spec("1641173925.123456", format_spec!("%s.%f")).unwrap();
+    assert_eq!(dt.timestamp(), eos::Timestamp::new(1641173925, 123_456_000));
+    assert_eq!(dt.nanosecond(), 123_456_000);
+    assert_eq!(dt.second(), 45);
+
+    // The fraction is kept when shifted into the parsed offset
+    let dt = DateTime::parse_from_spec("1641173925,5 +0900", format_spec!("%s%f %z")).unwrap();
+    assert_eq!(dt.nanosecond(), 500_000_000);
+    assert_eq!(dt.time(), time!(10:38:45).with_nanosecond(500_000_000).unwrap());
+
+    // The fraction can come before the timestamp
+    let dt = DateTime::parse_from_spec("999999999 0", format_spec!("%f %s")).unwrap();
+    assert_eq!(dt.timestamp(), eos::Timestamp::new(0, 999_999_999));
+
+    // Similar to formatting, the fraction is always forward in time
+    let dt = DateTime::parse_from_spec("-2.75", format_spec!("%s.%f")).unwrap();
+    assert_eq!(dt.timestamp(), eos::Timestamp::new(-2, 750_000_000));
+    assert_eq!(dt, datetime!(1969-12-31 23:59:58).with_nanosecond(750_000_000).unwrap());
+}
+
+#[test]
+fn test_signed_year_bounds() -> Result<(), eos::fmt::ParseError> {
+    use eos::{Date, Time};
+
+    let cases = [
+        (Date::MIN, "-32768-01-01", "-32768-01-01"),
+        (Date::MAX, "+32767-12-31", "32767-12-31"),
+        (Date::new(-1, 1, 1).unwrap(), "-0001-01-01", "-001-01-01"),
+        (Date::new(1, 1, 1).unwrap(), "+0001-01-01", "0001-01-01"),
+    ];
+
+    for (date, signed, unsigned) in cases {
+        let dt = date.at(Time::MIDNIGHT);
+        assert_eq!(date.format(format_spec!("%y-%m-%d")).to_string(), signed);
+        assert_eq!(dt.format(format_spec!("%y-%m-%d")).to_string(), signed);
+        assert_eq!(dt.format(format_spec!("%Y-%m-%d")).to_string(), unsigned);
+
+        let parsed = DateTime::parse_from_spec(signed, format_spec!("%y-%m-%d"))?;
+        assert_eq!(parsed.date(), date);
+        let parsed = DateTime::parse_from_spec(unsigned, format_spec!("%Y-%m-%d"))?;
+        assert_eq!(parsed.date(), date);
+    }
+
+    assert_eq!(
+        DateTime::parse_from_spec("-32769-01-01", format_spec!("%y-%m-%d")),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_compound_specifiers() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::parse_spec;
+
+    assert_eq!(parse_spec("%F"), parse_spec("%Y-%m-%d"));
+    assert_eq!(parse_spec("%T"), parse_spec("%H:%M:%S"));
+    assert_eq!(parse_spec("%#F %_T"), parse_spec("%#Y-%#m-%#d %_H:%_M:%_S"));
+    assert_eq!(parse_spec("[%FT%T]"), parse_spec("[%Y-%m-%dT%H:%M:%S]"));
+    assert_eq!(
+        format_spec!("%FT%T").as_slice(),
+        parse_spec("%FT%T").unwrap().as_slice()
+    );
+    assert_eq!(
+        format_spec!("%#F %_T").as_slice(),
+        parse_spec("%#Y-%#m-%#d %_H:%_M:%_S").unwrap().as_slice()
+    );
+
+    let dt = datetime!(2022-01-09 08:05:03);
+    assert_eq!(dt.format(format_spec!("%FT%T")).to_string(), "2022-01-09T08:05:03");
+    assert_eq!(dt.format(format_spec!("%#F %_T")).to_string(), "2022-1-9  8: 5: 3");
+    assert_eq!(dt.to_string_with("%FT%T").unwrap(), "2022-01-09T08:05:03");
+    assert_eq!(
+        date!(2022 - 01 - 09).format(format_spec!("%F")).to_string(),
+        "2022-01-09"
+    );
+    assert_eq!(time!(08:05:03).format(format_spec!("%T")).to_string(), "08:05:03");
+
+    let spec = OwnedFormatSpec::parse("%FT%T%z").unwrap();
+    assert_eq!(dt.format(&spec).to_string(), "2022-01-09T08:05:03+0000");
+
+    // Roundtrip parsing
+    let parsed = DateTime::parse_from_spec("2022-01-09T08:05:03", format_spec!("%FT%T"))?;
+    assert_eq!(parsed, dt.with_timezone(eos::UtcOffset::UTC));
+    let parsed = DateTime::parse_from_spec_iter("2022-01-09T08:05:03", eos::fmt::parse_spec_iter("%FT%T"))?;
+    assert_eq!(parsed, dt.with_timezone(eos::UtcOffset::UTC));
+    Ok(())
+}
+
+#[test]
+fn test_utc_offset_brief_z() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::parse_spec;
+
+    assert_eq!(
+        parse_spec("%J").unwrap(),
+        [FormatSpec::new(FormatSpecKind::UtcOffsetBriefZ)]
+    );
+    assert_eq!(format_spec!("%J"), [FormatSpec::new(FormatSpecKind::UtcOffsetBriefZ)]);
+    // %X is the locale's time representation in strftime so it's left unsupported
+    assert_eq!(parse_spec("%X"), Err(eos::fmt::Error::UnknownSpecifier(b'X')));
+
+    let utc = datetime!(2022-01-09 10:30 +00:00);
+    assert_eq!(
+        utc.format(format_spec!("%Y%m%dT%H%M%S%J")).to_string(),
+        "20220109T103000Z"
+    );
+    assert_eq!(utc.format(format_spec!("%z")).to_string(), "+0000");
+
+    let est = datetime!(2022-01-09 10:30 -05:00);
+    assert_eq!(est.format(format_spec!("%J")).to_string(), "-0500");
+    assert_eq!(
+        est.format(format_spec!("%J")).to_string(),
+        est.format(format_spec!("%z")).to_string()
+    );
+    let odd = datetime!(2022-01-09 10:30).in_timezone(utc_offset!(+10:23:40));
+    assert_eq!(odd.format(format_spec!("%J")).to_string(), "+102340");
+
+    // Dates and times have no offset
+    assert_eq!(date!(2022 - 01 - 09).format(format_spec!("[%J]")).to_string(), "[]");
+    assert_eq!(time!(10:30).format(format_spec!("[%J]")).to_string(), "[]");
+
+    // Parsing accepts either form
+    let spec = format_spec!("%Y-%m-%dT%H:%M:%S%J");
+    assert_eq!(DateTime::parse_from_spec("2022-01-09T10:30:00Z", spec)?, utc);
+    assert_eq!(DateTime::parse_from_spec("2022-01-09T10:30:00+0000", spec)?, utc);
+    assert_eq!(DateTime::parse_from_spec("2022-01-09T10:30:00-0500", spec)?, est);
+    assert_eq!(
+        DateTime::parse_from_spec("2022-01-09T10:30:00-05:00", spec),
+        Err(eos::fmt::ParseError::UnexpectedNonDigit)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_time_display_precision() {
+    let t = time!(08:05:03).with_nanosecond(123_456_789).unwrap();
+    assert_eq!(format!("{:.0}", t), "08:05:03");
+    assert_eq!(format!("{:.1}", t), "08:05:03.1");
+    assert_eq!(format!("{:.3}", t), "08:05:03.123");
+    assert_eq!(format!("{:.6}", t), "08:05:03.123456");
+    assert_eq!(format!("{:.9}", t), "08:05:03.123456789");
+    assert_eq!(format!("{:.12}", t), "08:05:03.123456789");
+
+    // Truncated rather than rounded
+    let t = time!(23:59:59).with_nanosecond(999_999_999).unwrap();
+    assert_eq!(format!("{:.3}", t), "23:59:59.999");
+
+    // Zero fractions are still written when a precision is given
+    let t = time!(12:00);
+    assert_eq!(format!("{:.3}", t), "12:00:00.000");
+    assert_eq!(format!("{:.0}", t), "12:00:00");
+    assert_eq!(format!("{}", t), "12:00:00");
+
+    let t = time!(12:00).with_millisecond(5).unwrap();
+    assert_eq!(format!("{:.3}", t), "12:00:00.005");
+    assert_eq!(format!("{:.2}", t), "12:00:00.00");
+}
+
+#[test]
+fn test_time_display_precision_leap_second() {
+    let t = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    assert_eq!(format!("{:.0}", t), "23:59:60");
+    assert_eq!(format!("{:.3}", t), "23:59:60.500");
+    assert_eq!(format!("{:.9}", t), "23:59:60.500000000");
+
+    let t = time!(23:59:59).with_nanosecond(1_000_000_000).unwrap();
+    assert_eq!(format!("{:.0}", t), "23:59:60");
+    assert_eq!(format!("{:.3}", t), "23:59:60.000");
+    assert_eq!(format!("{:.9}", t), "23:59:60.000000000");
+}
@@ -97,6 +97,25 @@ fn out_of_bounds_wrapping_time() {
     assert_eq!(time!(23:59:59) - 24.hours(), time!(23:59:59));
 }
 
+#[test]
+fn wrapping_time_with_duration() {
+    assert_eq!(time!(23:00) + Duration::from_secs(7200), time!(01:00));
+    assert_eq!(time!(01:00) - Duration::from_secs(7200), time!(23:00));
+    assert_eq!(time!(12:00) + Duration::from_secs(86400), time!(12:00));
+    assert_eq!(
+        time!(12:00) - Duration::from_millis(86_400_500),
+        time!(11:59:59).with_millisecond(500).unwrap()
+    );
+    assert_eq!(
+        time!(12:00) + Duration::from_secs(u64::MAX),
+        time!(12:00) + Duration::from_secs(u64::MAX % 86400)
+    );
+    assert_eq!(
+        time!(12:00) - Duration::from_secs(u64::MAX),
+        time!(12:00) - Duration::from_secs(u64::MAX % 86400)
+    );
+}
+
 #[test]
 fn random_single_units_to_dates() {
     assert_eq!(date!(268 - 7 - 31) + 10.years(), date!(278 - 7 - 31));
@@ -331,6 +350,164 @@ fn random_double_units_to_datetimes() {
     );
 }
 
+#[test]
+fn checked_add() {
+    let interval = 1.years() + 2.days() + 3.hours();
+    assert_eq!(interval.checked_add(interval), Some(2.years() + 4.days() + 6.hours()));
+    assert_eq!(interval.checked_add(-interval), Some(Interval::ZERO));
+    assert_eq!(Interval::ZERO.checked_add(Interval::ZERO), Some(Interval::ZERO));
+
+    // Overflow on each individual field
+    assert_eq!(Interval::from_months(i32::MAX).checked_add(1.months()), None);
+    assert_eq!(Interval::from_months(i32::MIN).checked_add((-1).months()), None);
+    assert_eq!(Interval::from_days(i32::MAX).checked_add(1.days()), None);
+    assert_eq!(Interval::from_days(i32::MIN).checked_add((-1).days()), None);
+    assert_eq!(
+        Interval::from_microseconds(i64::MAX).checked_add(1.microseconds()),
+        None
+    );
+    assert_eq!(
+        Interval::from_microseconds(i64::MIN).checked_add((-1).microseconds()),
+        None
+    );
+
+    // Reaching the bounds exactly is fine
+    assert_eq!(
+        Interval::from_days(i32::MAX - 1).checked_add(1.days()),
+        Some(Interval::from_days(i32::MAX))
+    );
+}
+
+#[test]
+fn checked_sub() {
+    let interval = 1.years() + 2.days() + 3.hours();
+    assert_eq!(interval.checked_sub(interval), Some(Interval::ZERO));
+    assert_eq!(interval.checked_sub(1.years()), Some(2.days() + 3.hours()));
+    assert_eq!(Interval::ZERO.checked_sub(interval), Some(-interval));
+
+    // Near the upper and lower bounds of months
+    assert_eq!(
+        Interval::from_months(i32::MAX).checked_sub(1.months()),
+        Some(Interval::from_months(i32::MAX - 1))
+    );
+    assert_eq!(Interval::from_months(i32::MAX).checked_sub((-1).months()), None);
+    assert_eq!(Interval::from_months(i32::MIN).checked_sub(1.months()), None);
+
+    // Days
+    assert_eq!(Interval::from_days(i32::MAX).checked_sub((-1).days()), None);
+    assert_eq!(Interval::from_days(i32::MIN).checked_sub(1.days()), None);
+
+    // Near the upper and lower bounds of microseconds
+    assert_eq!(
+        Interval::from_microseconds(i64::MAX).checked_sub(1.microseconds()),
+        Some(Interval::from_microseconds(i64::MAX - 1))
+    );
+    assert_eq!(
+        Interval::from_microseconds(i64::MAX).checked_sub((-1).microseconds()),
+        None
+    );
+    assert_eq!(
+        Interval::from_microseconds(i64::MIN).checked_sub(1.microseconds()),
+        None
+    );
+    assert_eq!(
+        Interval::from_microseconds(-1).checked_sub(Interval::from_microseconds(i64::MAX)),
+        Some(Interval::from_microseconds(i64::MIN))
+    );
+}
+
+#[test]
+fn checked_mul() {
+    let interval = 1.months() + 2.days() + 3.seconds();
+    assert_eq!(interval.checked_mul(0), Some(Interval::ZERO));
+    assert_eq!(interval.checked_mul(1), Some(interval));
+    assert_eq!(interval.checked_mul(-1), Some(-interval));
+    assert_eq!(interval.checked_mul(10), Some(10.months() + 20.days() + 30.seconds()));
+
+    // Overflow on each individual field
+    assert_eq!(Interval::from_months(i32::MAX / 2 + 1).checked_mul(2), None);
+    assert_eq!(Interval::from_months(i32::MIN).checked_mul(-1), None);
+    assert_eq!(Interval::from_days(i32::MAX / 2 + 1).checked_mul(2), None);
+    assert_eq!(Interval::from_days(i32::MIN).checked_mul(-1), None);
+    assert_eq!(Interval::from_microseconds(i64::MAX / 2 + 1).checked_mul(2), None);
+    assert_eq!(Interval::from_microseconds(i64::MIN).checked_mul(-1), None);
+}
+
+#[test]
+fn time_would_overflow_day() {
+    assert!(time!(23:00).would_overflow_day(2.hours()));
+    assert!(time!(23:00).would_overflow_day(1.hours()));
+    assert!(!time!(23:00).would_overflow_day(1.hours() - 1.microseconds()));
+    assert!(!time!(00:00).would_overflow_day(Interval::ZERO));
+    assert!(time!(00:00).would_overflow_day((-1).microseconds()));
+    assert!(time!(12:00).would_overflow_day(36.hours()));
+    assert!(time!(12:00).would_overflow_day((-13).hours()));
+    assert!(!time!(12:00).would_overflow_day((-12).hours()));
+
+    // Only the time component is considered
+    assert!(!time!(12:00).would_overflow_day(1.years() + 3.days() + 1.hours()));
+    assert!(time!(12:00).would_overflow_day(Interval::from_microseconds(i64::MAX)));
+    assert!(time!(12:00).would_overflow_day(Interval::from_microseconds(i64::MIN)));
+
+    // Consistent with the actual addition
+    for (time, interval) in [
+        (time!(23:00), 2.hours()),
+        (time!(01:00), (-2).hours()),
+        (time!(10:00), 5.hours()),
+    ] {
+        let crossed = (time.at(date!(2021 - 01 - 01)) + interval).date() != date!(2021 - 01 - 01);
+        assert_eq!(time.would_overflow_day(interval), crossed);
+    }
+}
+
+#[test]
+fn postgres_roundtrip() {
+    let intervals = [
+        Interval::ZERO,
+        1.years() + 2.months() + 3.days() + 4.hours() + 5.minutes() + 6.seconds() + 7.microseconds(),
+        (-1).months() + 31.days() - 1.microseconds(),
+        // Components are not normalized into each other
+        25.hours() + 40.days() + 13.months(),
+        Interval::from_postgres(i32::MIN, i32::MAX, i64::MIN),
+    ];
+
+    for interval in intervals {
+        let (months, days, microseconds) = interval.to_postgres();
+        assert_eq!(months, interval.total_months());
+        assert_eq!(days, interval.days());
+        assert_eq!(microseconds, interval.total_microseconds());
+        assert_eq!(Interval::from_postgres(months, days, microseconds), interval);
+    }
+
+    assert_eq!((25.hours() + 40.days()).to_postgres(), (0, 40, 90_000_000_000));
+    assert_eq!(Interval::from_postgres(-3, 0, 0), (-3).months());
+}
+
+#[test]
+fn tuple_roundtrip() {
+    let intervals = [
+        Interval::ZERO,
+        1.years() + 2.months() + 3.days() + 4.hours() + 5.minutes() + 6.seconds() + 7.microseconds(),
+        (-1).years() - 2.months() - 3.days() - 4.hours(),
+        (-1).months() + 31.days() - 1.microseconds(),
+        Interval::from_postgres(i32::MIN, i32::MIN, i64::MIN),
+        Interval::from_postgres(i32::MAX, i32::MAX, i64::MAX),
+    ];
+
+    for interval in intervals {
+        let tuple: (i32, i32, i64) = interval.into();
+        assert_eq!(tuple, interval.to_postgres());
+        assert_eq!(Interval::from(tuple), interval);
+    }
+
+    let tuple: (i32, i32, i64) = ((-14).months() - 3.days() - 1500.milliseconds()).into();
+    assert_eq!(tuple, (-14, -3, -1_500_000));
+    assert_eq!(
+        Interval::from((-14, -3, -1_500_000)),
+        (-1).years() - 2.months() - 3.days() - 1500.milliseconds()
+    );
+}
+
 #[test]
 fn diff_between_dates() {
     assert_eq!(
@@ -979,3 +1156,369 @@ fn diff_between_small_times_with_offsets() {
         3.hours() + 59.minutes()
     );
 }
+
+#[test]
+fn seconds_f64() {
+    // Time-only intervals do not need an anchor
+    assert_eq!(Interval::ZERO.time_component_seconds_f64(), 0.0);
+    assert_eq!((2.hours() + 30.minutes()).time_component_seconds_f64(), 9000.0);
+    assert_eq!((-1500).milliseconds().time_component_seconds_f64(), -1.5);
+    assert_eq!(1.microseconds().time_component_seconds_f64(), 1e-6);
+    // Calendar fields are ignored
+    assert_eq!((1.years() + 3.days() + 1.seconds()).time_component_seconds_f64(), 1.0);
+
+    // The anchor agrees with the time-only component when there are no calendar fields
+    let anchor = datetime!(2021-06-15 12:00);
+    for interval in [2.hours() + 30.minutes(), (-1500).milliseconds(), 1.microseconds()] {
+        assert_eq!(interval.as_seconds_f64(&anchor), interval.time_component_seconds_f64());
+    }
+
+    // Negative sub-second intervals are not floored
+    assert_eq!(
+        anchor + (-1500).milliseconds(),
+        datetime!(2021-06-15 11:59:58).with_nanosecond(500_000_000).unwrap()
+    );
+    assert_eq!(
+        time!(12:00) + (-1500).milliseconds(),
+        time!(11:59:58).with_nanosecond(500_000_000).unwrap()
+    );
+
+    // Calendar intervals depend on the anchor
+    assert_eq!(1.months().as_seconds_f64(&datetime!(2021-02-01 00:00)), 28.0 * 86400.0);
+    assert_eq!(1.months().as_seconds_f64(&datetime!(2020-02-01 00:00)), 29.0 * 86400.0);
+    assert_eq!(1.months().as_seconds_f64(&datetime!(2021-01-31 00:00)), 28.0 * 86400.0);
+    assert_eq!(1.years().as_seconds_f64(&datetime!(2020-01-01 00:00)), 366.0 * 86400.0);
+    assert_eq!(
+        (-1).years().as_seconds_f64(&datetime!(2020-01-01 00:00)),
+        -365.0 * 86400.0
+    );
+    assert_eq!(
+        (1.days() + 1.hours() + 250.milliseconds()).as_seconds_f64(&anchor),
+        90000.25
+    );
+}
+
+#[test]
+fn approx_seconds_f64() {
+    assert_eq!(Interval::ZERO.approx_seconds_f64(), 0.0);
+    assert_eq!(1.weeks().approx_seconds_f64(), 7.0 * 86400.0);
+    assert_eq!((1.years() + 1.seconds()).approx_seconds_f64(), 31_560_193.0);
+    assert_eq!((-1500).milliseconds().approx_seconds_f64(), -1.5);
+
+    assert!(1.months().approx_seconds_f64() > 20.days().approx_seconds_f64());
+    // The lexicographic ordering compares the months before the days
+    assert!(1.months() > 40.days());
+    assert!(1.months().approx_seconds_f64() < 40.days().approx_seconds_f64());
+    assert!(1.months().approx_seconds_f64() > 30.days().approx_seconds_f64());
+    assert!(1.months().approx_seconds_f64() < 31.days().approx_seconds_f64());
+}
+
+#[test]
+fn from_quarters() {
+    assert_eq!(Interval::from_quarters(1), 3.months());
+    assert_eq!(Interval::from_quarters(4), 1.years());
+    assert_eq!(Interval::from_quarters(-2).total_months(), -6);
+    assert_eq!(
+        date!(2021 - 11 - 30) + Interval::from_quarters(1),
+        date!(2022 - 02 - 28)
+    );
+    assert_eq!(
+        date!(2020 - 05 - 31) - Interval::from_quarters(1),
+        date!(2020 - 02 - 29)
+    );
+
+    // Overflowing saturates
+    assert_eq!(Interval::from_quarters(i32::MAX / 3).total_months(), i32::MAX - 1);
+    assert_eq!(Interval::from_quarters(i32::MAX / 3 + 1).total_months(), i32::MAX);
+    assert_eq!(Interval::from_quarters(i32::MIN / 3 - 1).total_months(), i32::MIN);
+    assert_eq!(Interval::from_quarters(i32::MAX).total_months(), i32::MAX);
+    assert_eq!(Interval::from_quarters(i32::MIN).total_months(), i32::MIN);
+}
+
+#[test]
+fn normalize_time() {
+    let interval = 26.hours().normalize_time();
+    assert_eq!(interval, 1.days() + 2.hours());
+    assert_eq!(interval.to_postgres(), (0, 1, 2 * 3_600_000_000));
+
+    assert_eq!(Interval::ZERO.normalize_time(), Interval::ZERO);
+    assert_eq!(23.hours().normalize_time(), 23.hours());
+    assert_eq!((3.days() + 48.hours()).normalize_time(), 5.days());
+    assert_eq!((-26).hours().normalize_time(), (-1).days() - 2.hours());
+
+    // Months are never touched
+    let interval = 2.months() + 40.days() + 73.hours() + 5.seconds();
+    assert_eq!(
+        interval.normalize_time(),
+        2.months() + 43.days() + 1.hours() + 5.seconds()
+    );
+
+    let start = datetime!(2021-01-01 00:00);
+    let end = datetime!(2021-01-03 12:30);
+    let interval = Interval::between(&start, &end);
+    assert_eq!(interval.normalize_time(), 2.days() + 12.hours() + 30.minutes());
+    assert_eq!(start + interval.normalize_time(), end);
+}
+
+#[test]
+fn normalize_time_overflow() {
+    let interval = Interval::from_days(i32::MAX) + 48.hours();
+    assert_eq!(interval.checked_normalize_time(), None);
+    assert_eq!(interval.normalize_time(), Interval::from_days(i32::MAX));
+
+    let interval = Interval::from_days(i32::MIN) - 49.hours();
+    assert_eq!(interval.checked_normalize_time(), None);
+    assert_eq!(interval.normalize_time(), Interval::from_days(i32::MIN) - 1.hours());
+
+    // Right at the boundary is still fine
+    let interval = Interval::from_days(i32::MAX - 2) + 48.hours() + 5.minutes();
+    assert_eq!(
+        interval.checked_normalize_time(),
+        Some(Interval::from_days(i32::MAX) + 5.minutes())
+    );
+    assert_eq!(interval.normalize_time(), Interval::from_days(i32::MAX) + 5.minutes());
+}
+
+#[test]
+fn between_overshooting_month_across_timezones() {
+    // The local dates differ by 13 months but the instants are just shy of 12 months apart
+    let start = datetime!(2000-02-29 23:00 -05:00);
+    let end = datetime!(2001-03-01 03:59).with_timezone(eos::UtcOffset::UTC);
+    let interval = Interval::between(&start, &end);
+    assert_eq!(interval.total_months(), 11);
+    assert_eq!(start + interval, end);
+}
+
+#[test]
+fn to_std_duration() {
+    assert_eq!(Interval::ZERO.to_std_duration(), Some(Duration::ZERO));
+    assert_eq!(90.minutes().to_std_duration(), Some(Duration::from_secs(5400)));
+    assert_eq!(
+        Interval::from_microseconds(1_500_001).to_std_duration(),
+        Some(Duration::from_micros(1_500_001))
+    );
+
+    // Negative intervals cannot be represented
+    assert_eq!((-1).microseconds().to_std_duration(), None);
+    assert_eq!((-1).days().to_std_duration_with_days(true), None);
+    assert_eq!(((-1).days() + 1.hours()).to_std_duration_with_days(true), None);
+
+    // Months require an anchor date
+    assert_eq!(1.months().to_std_duration(), None);
+    assert_eq!(1.months().to_std_duration_with_days(true), None);
+    assert_eq!(
+        (1.years() - 12.months() + 1.hours()).to_std_duration(),
+        Some(Duration::from_secs(3600))
+    );
+
+    // Days are only converted when requested
+    let interval = 2.days() + 3.hours();
+    assert_eq!(interval.to_std_duration(), None);
+    assert_eq!(interval.to_std_duration_with_days(false), None);
+    assert_eq!(
+        interval.to_std_duration_with_days(true),
+        Some(Duration::from_secs(2 * 86400 + 3 * 3600))
+    );
+    assert_eq!(
+        Interval::from_days(i32::MAX).to_std_duration_with_days(true),
+        Some(Duration::from_secs(i32::MAX as u64 * 86400))
+    );
+
+    // Roundtrips with the TryFrom<Duration> implementation
+    let duration = Duration::from_micros(123_456_789);
+    let interval = Interval::try_from(duration).unwrap();
+    assert_eq!(interval.to_std_duration(), Some(duration));
+}
+
+#[test]
+fn to_duration() {
+    use eos::Error;
+
+    // Pure time intervals are converted losslessly
+    let interval = 1.hours() + 2.minutes() + 3.seconds() + 456_789.microseconds();
+    assert_eq!(interval.to_duration(), Ok(Duration::new(3723, 456_789_000)));
+    assert_eq!(
+        interval.to_duration(),
+        interval.to_std_duration().ok_or(Error::OutOfRange)
+    );
+    assert_eq!(Interval::ZERO.to_duration(), Ok(Duration::ZERO));
+
+    // Days are 86400 seconds unless specified otherwise
+    let interval = 3.days() + 4.hours();
+    assert_eq!(interval.to_duration(), Ok(Duration::from_secs(3 * 86400 + 4 * 3600)));
+    assert_eq!(interval.to_duration_with_days_as(86400), interval.to_duration());
+    assert_eq!(
+        interval.to_duration_with_days_as(90_000),
+        Ok(Duration::from_secs(3 * 90_000 + 4 * 3600))
+    );
+    assert_eq!(interval.to_duration_with_days_as(0), Ok(Duration::from_secs(4 * 3600)));
+    assert_eq!((1.days() - 1.hours()).to_duration(), Ok(Duration::from_secs(23 * 3600)));
+
+    // Negative totals and months are errors
+    assert_eq!((-1).microseconds().to_duration(), Err(Error::OutOfRange));
+    assert_eq!((1.hours() - 1.days()).to_duration(), Err(Error::OutOfRange));
+    assert_eq!(
+        (1.days() - 1.hours()).to_duration_with_days_as(0),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(1.months().to_duration(), Err(Error::OutOfRange));
+    assert_eq!(
+        (1.years() - 12.months() + 1.days()).to_duration(),
+        Ok(Duration::from_secs(86400))
+    );
+
+    // Totals larger than a Duration are errors
+    assert_eq!(
+        Interval::from_days(i32::MAX).to_duration_with_days_as(u64::MAX),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        Interval::from_days(1).to_duration_with_days_as(u64::MAX),
+        Ok(Duration::from_secs(u64::MAX))
+    );
+}
+
+#[test]
+fn whole_units_approx() {
+    // Time-only intervals are exact
+    let interval = 49.hours() + 59.minutes() + 59.seconds() + 999.milliseconds();
+    assert_eq!(interval.whole_days_approx(), 2);
+    assert_eq!(interval.whole_hours_approx(), 49);
+    assert_eq!(interval.whole_minutes_approx(), 49 * 60 + 59);
+    assert_eq!(interval.whole_seconds_approx(), interval.total_seconds());
+    assert_eq!((-interval).whole_hours_approx(), -49);
+
+    // Months are approximated as 30.44 days
+    let interval = 2.months() + 1.days() + 12.hours();
+    assert_eq!(interval.whole_days_approx(), 62);
+    // 60.88 days is 1461.12 hours
+    assert_eq!(interval.whole_hours_approx(), 1461 + 36);
+    assert_eq!(interval.whole_seconds_approx(), 2 * 2_630_016 + 86400 + 12 * 3600);
+    assert_eq!(
+        interval.whole_seconds_approx() as f64,
+        interval.approx_seconds_f64().trunc()
+    );
+    assert_eq!((-interval).whole_days_approx(), -62);
+
+    let largest = Interval::from_postgres(i32::MAX, i32::MAX, i64::MAX);
+    assert!(largest.whole_seconds_approx() > 0);
+    assert!((-largest).whole_seconds_approx() < 0);
+}
+
+#[test]
+fn rem_time() {
+    let hour = Interval::from_hours(1).total_microseconds();
+    assert_eq!(100.minutes().rem_time(hour), 40.minutes());
+    assert_eq!(120.minutes().rem_time(hour), Interval::ZERO);
+    assert_eq!(59.minutes().rem_time(hour), 59.minutes());
+    assert_eq!((-100).minutes().rem_time(hour), (-40).minutes());
+    assert_eq!(100.minutes().rem_time(-hour), 40.minutes());
+
+    // Only the time component is used
+    let interval = 1.months() + 2.days() + 100.minutes();
+    assert_eq!(interval.rem_time(hour), 40.minutes());
+    assert_eq!(1.months().rem_time(hour), Interval::ZERO);
+
+    // Splitting a duration into whole units and the leftover
+    let interval = 3.hours() + 25.minutes() + 10.seconds();
+    let leftover = interval.rem_time(hour);
+    assert_eq!(leftover, 25.minutes() + 10.seconds());
+    assert_eq!(Interval::from_hours(interval.hours() as i32) + leftover, interval);
+
+    assert_eq!(Interval::from_microseconds(i64::MIN).rem_time(-1), Interval::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn rem_time_by_zero() {
+    let _ = 1.hours().rem_time(0);
+}
+
+#[test]
+fn normalize_with() {
+    let anchor = date!(2021 - 01 - 01);
+    assert_eq!(45.days().normalize_with(anchor), 1.months() + 14.days());
+    assert_eq!(30.days().normalize_with(anchor), 30.days());
+    assert_eq!(365.days().normalize_with(anchor), 1.years());
+    assert_eq!(Interval::ZERO.normalize_with(anchor), Interval::ZERO);
+
+    // The result depends on the anchor
+    assert_eq!(28.days().normalize_with(date!(2021 - 02 - 01)), 1.months());
+    assert_eq!(28.days().normalize_with(date!(2020 - 02 - 01)), 28.days());
+    assert_eq!(366.days().normalize_with(date!(2020 - 01 - 01)), 1.years());
+    assert_eq!(366.days().normalize_with(anchor), 1.years() + 1.days());
+
+    // Existing months are applied first
+    let interval = 1.months() + 40.days();
+    assert_eq!(interval.normalize_with(anchor), 2.months() + 12.days());
+
+    // The time component is left alone
+    let interval = 45.days() + 30.hours();
+    assert_eq!(interval.normalize_with(anchor), 1.months() + 14.days() + 30.hours());
+
+    // Negative intervals go backwards from the anchor
+    assert_eq!((-45).days().normalize_with(anchor), (-1).months() - 14.days());
+
+    // Month ends are clamped before the days are added
+    let anchor = date!(2021 - 01 - 31);
+    assert_eq!(anchor + (1.months() + 30.days()), date!(2021 - 03 - 30));
+    assert_eq!(
+        anchor + (1.months() + 30.days()).normalize_with(anchor),
+        date!(2021 - 03 - 30)
+    );
+
+    // Normalizing doesn't change where the interval ends up from the anchor
+    for anchor in [
+        date!(2021 - 01 - 01),
+        date!(2021 - 01 - 31),
+        date!(2021 - 03 - 31),
+        date!(2020 - 01 - 31),
+    ] {
+        for interval in [
+            45.days(),
+            1.months() + 40.days(),
+            1.months() + 30.days(),
+            (-1).months() + (-1).days(),
+            (-100).days(),
+            400.days(),
+        ] {
+            assert_eq!(anchor + interval.normalize_with(anchor), anchor + interval);
+        }
+    }
+}
+
+#[test]
+fn humanize() {
+    assert_eq!(Interval::ZERO.humanize(), "0 seconds");
+
+    // Single units
+    assert_eq!(1.years().humanize(), "1 year");
+    assert_eq!(2.years().humanize(), "2 years");
+    assert_eq!(1.months().humanize(), "1 month");
+    assert_eq!(3.weeks().humanize(), "21 days");
+    assert_eq!(1.hours().humanize(), "1 hour");
+    assert_eq!(30.minutes().humanize(), "30 minutes");
+    assert_eq!(1.seconds().humanize(), "1 second");
+    assert_eq!(250.milliseconds().humanize(), "250 milliseconds");
+    assert_eq!(1.microseconds().humanize(), "1 microsecond");
+
+    // Mixed units only keep the largest two
+    assert_eq!((1.years() + 2.months()).humanize(), "1 year 2 months");
+    assert_eq!((3.minutes() + 4.seconds()).humanize(), "3 minutes 4 seconds");
+    assert_eq!((2.hours() + 5.minutes() + 10.seconds()).humanize(), "2 hours 5 minutes");
+    assert_eq!((1.days() + 1.seconds()).humanize(), "1 day 1 second");
+    assert_eq!((13.months() + 1.days()).humanize(), "1 year 1 month");
+    assert_eq!((1.seconds() + 1.milliseconds()).humanize(), "1 second 1 millisecond");
+
+    // The time component isn't rolled over into days
+    assert_eq!(26.hours().humanize(), "26 hours");
+    assert_eq!(26.hours().normalize_time().humanize(), "1 day 2 hours");
+
+    // Negative intervals
+    assert_eq!((-(2.hours() + 5.minutes())).humanize(), "2 hours 5 minutes ago");
+    assert_eq!((-1).days().humanize(), "1 day ago");
+    assert_eq!((-500).microseconds().humanize(), "500 microseconds ago");
+    assert_eq!((1.months() - 3.days()).humanize(), "1 month -3 days");
+    assert_eq!((3.days() - 1.months()).humanize(), "1 month -3 days ago");
+    assert_eq!((2.months() - 1.days()).humanize(), "2 months -1 day");
+}
@@ -14,6 +14,23 @@ fn test_duration_isoformat() {
     assert_eq!(Duration::from_secs(15 * 60).to_iso_format(), "PT15M");
     assert_eq!(Duration::from_secs(10 * 3600).to_iso_format(), "PT10H");
     assert_eq!(Duration::from_secs(2 * 86400).to_iso_format(), "PT48H");
+    assert_eq!(Duration::ZERO.to_iso_format(), "PT0S");
+    assert_eq!(Duration::from_secs(61).to_iso_format(), "PT1M1S");
+    assert_eq!(Duration::from_secs(90).to_iso_format(), "PT1M30S");
+    assert_eq!(Duration::from_secs(3661).to_iso_format(), "PT1H1M1S");
+    assert_eq!(Duration::from_secs(3600 + 1).to_iso_format(), "PT1H1S");
+    assert_eq!(Duration::from_millis(90_500).to_iso_format(), "PT1M30.5S");
+    assert_eq!(Duration::from_millis(3_600_250).to_iso_format(), "PT1H0.25S");
+    assert_eq!(Duration::new(3661, 1).to_iso_format(), "PT1H1M1.000000001S");
+
+    for duration in [
+        Duration::from_secs(90),
+        Duration::from_secs(3661),
+        Duration::from_millis(90_500),
+        Duration::from_millis(3_600_250),
+    ] {
+        assert_eq!(Duration::from_iso_format(&duration.to_iso_format()).unwrap(), duration);
+    }
 }
 
 #[test]
@@ -219,6 +236,48 @@ fn test_interval_isoformat() {
     assert_eq!(eos::Interval::ZERO.to_iso_format(), "PT0S");
     assert_eq!((1.days() + 3.months() + 1.years()).to_iso_format(), "P1Y3M1D");
     assert_eq!((3.seconds() + 500.milliseconds()).to_iso_format(), "PT3.5S");
+    assert_eq!((-500).milliseconds().to_iso_format(), "PT-0.5S");
+    assert_eq!(((-3).seconds() + (-5).microseconds()).to_iso_format(), "PT-3.000005S");
+    assert_eq!((2.hours() + 123_456.microseconds()).to_iso_format(), "PT2H0.123456S");
+    assert_eq!(
+        (1.years() + 2.months() + 10.days() + 2.hours() + 30.minutes()).to_iso_format(),
+        "P1Y2M10DT2H30M"
+    );
+    // Years are not truncated to 16 bits
+    assert_eq!(Interval::from_postgres(40_000 * 12, 0, 0).to_iso_format(), "P40000Y");
+}
+
+#[test]
+fn test_interval_isoformat_roundtrip() -> Result<(), eos::fmt::ParseError> {
+    for interval in [
+        (-500).milliseconds(),
+        -(3.weeks() + 500.milliseconds()),
+        (-1).hours() + 1.microseconds(),
+        1.minutes() + (-999_999).microseconds(),
+        (-14).months() + 3.days() + (-61).seconds() + (-250).milliseconds(),
+        Interval::from_postgres(i32::MAX, 0, 0),
+        Interval::from_postgres(i32::MIN, 0, 0),
+        Interval::from_postgres(40_000 * 12 + 5, 0, 0),
+    ] {
+        assert_eq!(Interval::from_iso_format(&interval.to_iso_format())?, interval);
+    }
+
+    assert_eq!(
+        Interval::from_iso_format("P178956971Y"),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    assert_eq!(
+        Interval::from_iso_format("-P-178956970Y-8M"),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+
+    assert_eq!(Interval::from_iso_format("PT-0.5S")?, (-500).milliseconds());
+    assert_eq!(Interval::from_iso_format("-PT-0.5S")?, 500.milliseconds());
+    assert_eq!(
+        Interval::from_iso_format("PT1M-0.25S")?,
+        1.minutes() + (-250).milliseconds()
+    );
+    Ok(())
 }
 
 #[test]
@@ -497,6 +556,52 @@ fn test_valid_year_week() -> Result<(), eos::fmt::ParseError> {
     Ok(())
 }
 
+#[test]
+fn test_valid_basic_date() -> Result<(), eos::fmt::ParseError> {
+    assert_eq!(Date::from_iso_format("20120213")?, date!(2012 - 02 - 13));
+    assert_eq!(Date::from_iso_format("20200229")?, date!(2020 - 02 - 29));
+    assert_eq!(Date::from_iso_format("00010101")?, date!(1 - 01 - 01));
+    assert_eq!(Date::from_iso_format("2012W101")?, date!(2012 - 03 - 05));
+    assert_eq!(Date::from_iso_format("2012W10")?, date!(2012 - 03 - 05));
+    assert_eq!(Date::from_iso_format("2009W537")?, date!(2010 - 01 - 03));
+    assert_eq!(Date::from_iso_format("2021048")?, date!(2021 - 02 - 17));
+    assert_eq!(Date::from_iso_format("2020366")?, date!(2020 - 12 - 31));
+
+    // Extended five digit years still work
+    assert_eq!(Date::from_iso_format("10000-01-01")?, date!(10000 - 01 - 01));
+
+    assert_eq!(
+        DateTime::from_iso_format("20120213T10:30:00Z")?,
+        datetime!(2012-02-13 10:30 +00:00)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_basic_date() {
+    use eos::fmt::ParseError;
+
+    // Mixing the basic and extended formats is not allowed
+    assert_eq!(Date::from_iso_format("2012-0213"), Err(ParseError::UnexpectedChar('3')));
+    assert_eq!(Date::from_iso_format("201202-13"), Err(ParseError::UnexpectedNonDigit));
+    assert_eq!(Date::from_iso_format("2012W10-1"), Err(ParseError::UnexpectedChar('-')));
+    assert_eq!(Date::from_iso_format("2012-W101"), Err(ParseError::UnexpectedChar('1')));
+
+    // YYYYMM is not a valid basic format
+    assert_eq!(Date::from_iso_format("201202"), Err(ParseError::UnexpectedEnd));
+
+    assert_eq!(Date::from_iso_format("20121313"), Err(ParseError::OutOfBounds));
+    assert_eq!(Date::from_iso_format("20120230"), Err(ParseError::OutOfBounds));
+    assert_eq!(Date::from_iso_format("20120200"), Err(ParseError::OutOfBounds));
+    assert_eq!(Date::from_iso_format("2021366"), Err(ParseError::OutOfBounds));
+    assert_eq!(Date::from_iso_format("2012W108"), Err(ParseError::OutOfBounds));
+    assert_eq!(Date::from_iso_format("2021W53"), Err(ParseError::OutOfBounds));
+    assert_eq!(
+        Date::from_iso_format("2012021300"),
+        Err(ParseError::UnexpectedChar('0'))
+    );
+}
+
 #[test]
 fn test_valid_time() -> Result<(), eos::fmt::ParseError> {
     assert_eq!(
@@ -524,9 +629,135 @@ fn test_valid_time() -> Result<(), eos::fmt::ParseError> {
         time!(15:34:56).with_nanosecond(123456789).unwrap()
     );
     assert_eq!(Time::from_iso_format("18:30")?, time!(18:30));
+    assert_eq!(
+        Time::from_iso_format("10:24:30,5")?,
+        time!(10:24:30).with_millisecond(500).unwrap()
+    );
+    assert_eq!(
+        Time::from_iso_format("10:24:30.5")?,
+        Time::from_iso_format("10:24:30,5")?
+    );
+
+    // Leap seconds roll over into the nanosecond component
+    let leap = Time::from_iso_format("23:59:60")?;
+    assert_eq!(leap.second(), 59);
+    assert_eq!(leap.nanosecond(), 1_000_000_000);
+    assert_eq!(leap, time!(23:59:59).with_nanosecond(1_000_000_000).unwrap());
+    let leap = Time::from_iso_format("23:59:60.25")?;
+    assert_eq!(leap.nanosecond(), 1_250_000_000);
+    Ok(())
+}
+
+#[test]
+fn test_time_comma_isoformat() -> Result<(), eos::fmt::ParseError> {
+    use eos::fmt::IsoFormatPrecision;
+
+    let time = time!(10:24:30).with_millisecond(500).unwrap();
+    assert_eq!(
+        time.to_iso_format_with_precision(IsoFormatPrecision::Decisecond),
+        "10:24:30.5"
+    );
+    assert_eq!(
+        time.to_iso_format_with_comma(IsoFormatPrecision::Decisecond),
+        "10:24:30,5"
+    );
+    assert_eq!(
+        time.to_iso_format_with_comma(IsoFormatPrecision::Microsecond),
+        "10:24:30,500000"
+    );
+    assert_eq!(time.to_iso_format_with_comma(IsoFormatPrecision::Minute), "10:24");
+
+    // Tenths are truncated rather than rounded
+    let time = time!(23:59:59).with_millisecond(999).unwrap();
+    assert_eq!(
+        time.to_iso_format_with_comma(IsoFormatPrecision::Decisecond),
+        "23:59:59,9"
+    );
+    assert_eq!(
+        time!(00:00:00).to_iso_format_with_comma(IsoFormatPrecision::Decisecond),
+        "00:00:00,0"
+    );
+
+    for time in [
+        time!(10:24:30).with_millisecond(500).unwrap(),
+        time!(00:00:00),
+        time!(23:59:59).with_millisecond(100).unwrap(),
+        time!(12:00:01).with_millisecond(900).unwrap(),
+    ] {
+        let comma = time.to_iso_format_with_comma(IsoFormatPrecision::Decisecond);
+        assert_eq!(Time::from_iso_format(&comma)?, time);
+        let comma = time.to_iso_format_with_comma(IsoFormatPrecision::Millisecond);
+        assert_eq!(Time::from_iso_format(&comma)?, time);
+    }
     Ok(())
 }
 
+#[test]
+fn test_invalid_time() {
+    use eos::fmt::ParseError;
+
+    assert_eq!(Time::from_iso_format("10:24:30Z"), Err(ParseError::UnexpectedChar('Z')));
+    assert_eq!(Time::from_iso_format("10:24 "), Err(ParseError::UnexpectedChar(' ')));
+    assert_eq!(
+        Time::from_iso_format("10:24:30.5x"),
+        Err(ParseError::UnexpectedChar('x'))
+    );
+    assert_eq!(Time::from_iso_format("10:24:3"), Err(ParseError::UnexpectedEnd));
+    assert_eq!(Time::from_iso_format("10"), Err(ParseError::UnexpectedEnd));
+    assert_eq!(Time::from_iso_format("10:60"), Err(ParseError::OutOfBounds));
+    assert_eq!(Time::from_iso_format("10:24:61"), Err(ParseError::OutOfBounds));
+}
+
+#[test]
+fn test_trailing_garbage() {
+    use eos::fmt::ParseError;
+
+    assert_eq!(
+        Date::from_iso_format("2021-01-30garbage"),
+        Err(ParseError::UnexpectedChar('g'))
+    );
+    assert_eq!(
+        Date::from_iso_format("2021-01-30 "),
+        Err(ParseError::UnexpectedChar(' '))
+    );
+    assert_eq!(
+        Date::from_iso_format("2021-01-30T10:00"),
+        Err(ParseError::UnexpectedChar('T'))
+    );
+    assert_eq!(
+        Date::from_iso_format("2021-W10-1x"),
+        Err(ParseError::UnexpectedChar('x'))
+    );
+    assert_eq!(Date::from_iso_format("2021-048!"), Err(ParseError::UnexpectedChar('!')));
+
+    assert_eq!(
+        IsoWeekDate::from_iso_format("2021-W10-1x"),
+        Err(ParseError::UnexpectedChar('x'))
+    );
+    assert_eq!(
+        IsoWeekDate::from_iso_format("2021-W10x"),
+        Err(ParseError::UnexpectedChar('x'))
+    );
+
+    assert_eq!(
+        Time::from_iso_format("10:24:30garbage"),
+        Err(ParseError::UnexpectedChar('g'))
+    );
+
+    assert_eq!(
+        DateTime::from_iso_format("2021-01-30T10:24:30garbage"),
+        Err(ParseError::UnexpectedChar('g'))
+    );
+    assert_eq!(
+        DateTime::from_iso_format("2021-01-30T10:24:30Zgarbage"),
+        Err(ParseError::UnexpectedChar('g'))
+    );
+    assert_eq!(
+        DateTime::from_iso_format("2021-01-30T10:24:30+05:00 "),
+        Err(ParseError::UnexpectedChar(' '))
+    );
+}
+
 #[test]
 fn test_valid_datetime() -> Result<(), eos::fmt::ParseError> {
     assert_eq!(
@@ -695,6 +926,32 @@ fn test_valid_interval() -> Result<(), eos::fmt::ParseError> {
     assert_eq!(Interval::from_iso_format("-P30D")?, (-30).days());
     assert_eq!(Interval::from_iso_format("P-30D")?, (-30).days());
     assert_eq!(Interval::from_iso_format("-P-30DT30S")?, (30.days() - 30.seconds()));
+    assert_eq!(Interval::from_iso_format("P2W")?, 14.days());
+    assert_eq!(Interval::from_iso_format("-P1W")?, (-7).days());
+    assert_eq!(Interval::from_iso_format("P1W-3D")?, 4.days());
+    assert_eq!(
+        Interval::from_iso_format("P3Y6M4W2DT12H30M5S")?,
+        (3.years() + 6.months() + 30.days() + 12.hours() + 30.minutes() + 5.seconds())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interval_weeks_roundtrip() -> Result<(), eos::fmt::ParseError> {
+    for (s, formatted) in [
+        ("P1W", "P7D"),
+        ("P3Y6M4W2DT12H30M5S", "P3Y6M30DT12H30M5S"),
+        ("P10W", "P70D"),
+        ("-P2WT1.5S", "P-14DT-1.5S"),
+        ("P1Y2W", "P1Y14D"),
+    ] {
+        let interval = Interval::from_iso_format(s)?;
+        assert_eq!(interval.to_iso_format(), formatted);
+        assert_eq!(Interval::from_iso_format(&interval.to_iso_format())?, interval);
+    }
+
+    assert!(Interval::from_iso_format("PT1W").is_err());
+    assert!(Interval::from_iso_format("P400000000W").is_err());
     Ok(())
 }
 
@@ -709,3 +966,80 @@ fn test_valid_duration() -> Result<(), eos::fmt::ParseError> {
     );
     Ok(())
 }
+
+#[test]
+fn test_valid_utc_offset() -> Result<(), eos::fmt::ParseError> {
+    use eos::UtcOffset;
+
+    assert_eq!(UtcOffset::from_iso_format("Z")?, UtcOffset::UTC);
+    assert_eq!(UtcOffset::from_iso_format("+00")?, UtcOffset::UTC);
+    assert_eq!(UtcOffset::from_iso_format("-00:00")?, UtcOffset::UTC);
+    assert_eq!(UtcOffset::from_iso_format("-08")?, utc_offset!(-08:00));
+    assert_eq!(UtcOffset::from_iso_format("+05:30")?, utc_offset!(+05:30));
+    assert_eq!(UtcOffset::from_iso_format("-03:30")?, utc_offset!(-03:30));
+    assert_eq!(UtcOffset::from_iso_format("+10:23:45")?, utc_offset!(+10:23:45));
+    assert_eq!(UtcOffset::from_iso_format("-10:23:45")?, utc_offset!(-10:23:45));
+    assert_eq!(UtcOffset::from_iso_format("+23:59:59")?, utc_offset!(+23:59:59));
+    assert_eq!("+05:30".parse::<UtcOffset>()?, utc_offset!(+05:30));
+    assert_eq!("Z".parse::<UtcOffset>()?, UtcOffset::UTC);
+
+    // Round trips through Display
+    for offset in [utc_offset!(+05:30), utc_offset!(-08:00), utc_offset!(-10:23:45)] {
+        assert_eq!(offset.to_string().parse::<UtcOffset>()?, offset);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_invalid_utc_offset() {
+    use eos::{fmt::ParseError, UtcOffset};
+
+    assert_eq!(UtcOffset::from_iso_format(""), Err(ParseError::UnexpectedEnd));
+    assert_eq!(
+        UtcOffset::from_iso_format("05:30"),
+        Err(ParseError::UnexpectedChar('0'))
+    );
+    assert_eq!(UtcOffset::from_iso_format("+5"), Err(ParseError::UnexpectedEnd));
+    assert_eq!(UtcOffset::from_iso_format("+24"), Err(ParseError::OutOfBounds));
+    assert_eq!(UtcOffset::from_iso_format("+05:60"), Err(ParseError::OutOfBounds));
+    assert_eq!(UtcOffset::from_iso_format("-05:30:60"), Err(ParseError::OutOfBounds));
+    assert_eq!(UtcOffset::from_iso_format("+05:"), Err(ParseError::UnexpectedEnd));
+    assert_eq!(
+        UtcOffset::from_iso_format("+0530"),
+        Err(ParseError::UnexpectedChar('3'))
+    );
+    assert_eq!(UtcOffset::from_iso_format("Z "), Err(ParseError::UnexpectedChar(' ')));
+    assert_eq!(UtcOffset::from_iso_format("z"), Err(ParseError::UnexpectedChar('z')));
+    assert_eq!(
+        "+05:30:00:00".parse::<UtcOffset>(),
+        Err(ParseError::UnexpectedChar(':'))
+    );
+}
+
+#[test]
+fn test_date_isoformat_year_bounds() -> Result<(), eos::fmt::ParseError> {
+    let cases = [
+        (Date::MIN, "-32768-01-01"),
+        (Date::MAX, "+32767-12-31"),
+        (Date::new(-1, 1, 1).unwrap(), "-0001-01-01"),
+        (Date::new(-9999, 1, 1).unwrap(), "-9999-01-01"),
+        (Date::new(-10000, 1, 1).unwrap(), "-10000-01-01"),
+        (Date::new(10000, 1, 1).unwrap(), "+10000-01-01"),
+    ];
+
+    for (date, expected) in cases {
+        assert_eq!(date.to_string(), expected);
+        assert_eq!(date.to_iso_format(), expected);
+        assert_eq!(Date::from_iso_format(expected)?, date);
+    }
+
+    assert_eq!(
+        Date::from_iso_format("-32769-01-01"),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    assert_eq!(
+        Date::from_iso_format("+32768-01-01"),
+        Err(eos::fmt::ParseError::OutOfBounds)
+    );
+    Ok(())
+}
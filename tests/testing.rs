@@ -0,0 +1,68 @@
+#![cfg(feature = "testing")]
+
+use eos::{
+    datetime,
+    fmt::{format_spec, FormatSpec},
+    testing::{assert_roundtrip, roundtrip},
+    DateTime, TimeZone,
+};
+
+const ROUNDTRIP_SPEC: &[FormatSpec<'static>] = &format_spec!("%Y-%m-%dT%H:%M:%S%o");
+
+fn is_lossy<'a, Tz, S>(dt: &DateTime<Tz>, spec: S) -> bool
+where
+    Tz: TimeZone + std::panic::RefUnwindSafe,
+    S: AsRef<[FormatSpec<'a>]> + std::panic::UnwindSafe,
+{
+    std::panic::catch_unwind(|| assert_roundtrip(dt, spec)).is_err()
+}
+
+#[test]
+fn test_roundtrip() {
+    assert_roundtrip(&datetime!(2022-03-14 12:30:45 +05:00), ROUNDTRIP_SPEC);
+    assert_roundtrip(&datetime!(1969-12-31 23:59:59 -03:30), ROUNDTRIP_SPEC);
+    assert_roundtrip(&datetime!(2022-03-14 12:30:45), ROUNDTRIP_SPEC);
+    assert_roundtrip(&datetime!(12345-01-01 00:00 +00:00), ROUNDTRIP_SPEC);
+    assert_roundtrip(
+        &datetime!(2000-01-01 00:00 +00:00).with_year(-5).unwrap(),
+        ROUNDTRIP_SPEC,
+    );
+    assert_roundtrip(&DateTime::UNIX_EPOCH, ROUNDTRIP_SPEC);
+
+    let dt = datetime!(2022-03-14 12:30:45 +05:00);
+    let (formatted, parsed) = roundtrip(&dt, ROUNDTRIP_SPEC).unwrap();
+    assert_eq!(formatted, "2022-03-14T12:30:45+05:00");
+    assert_eq!(parsed, dt);
+    assert_eq!(parsed.offset(), dt.offset());
+
+    let dt = datetime!(2022-03-14 12:30:45).with_nanosecond(123_456_789).unwrap();
+    assert_roundtrip(&dt, format_spec!("%Y-%m-%dT%H:%M:%S.%f%o"));
+}
+
+#[test]
+fn test_roundtrip_lossy() {
+    let dt = datetime!(2022-03-14 12:30:45 +00:00);
+
+    // %Y reads more than four digits so it eats into the month
+    assert!(roundtrip(&dt, format_spec!("%Y%m%dT%H%M%S%z")).is_err());
+    assert!(is_lossy(&dt, format_spec!("%Y%m%dT%H%M%S%z")));
+    assert!(!is_lossy(&dt, format_spec!("%Y-%m-%dT%H%M%S%z")));
+
+    // Missing components
+    assert!(is_lossy(&dt, format_spec!("%Y-%m-%d")));
+    assert!(is_lossy(&dt, format_spec!("%Y-%m-%dT%H:%M%o")));
+    assert!(is_lossy(&dt.with_nanosecond(500).unwrap(), ROUNDTRIP_SPEC));
+
+    // Missing UTC offset on a non-UTC datetime
+    assert!(is_lossy(
+        &datetime!(2022-03-14 12:30:45 +05:00),
+        format_spec!("%Y-%m-%dT%H:%M:%S")
+    ));
+
+    // Lossy specs still round-trip if the lost information was empty
+    assert!(!is_lossy(&datetime!(2022-03-14 00:00 +00:00), format_spec!("%Y-%m-%d")));
+    assert!(!is_lossy(
+        &datetime!(2022-03-14 12:30:45),
+        format_spec!("%Y-%m-%dT%H:%M:%S")
+    ));
+}
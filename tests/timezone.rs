@@ -64,7 +64,7 @@ impl TimeZone for AmericanTimeZone {
         }
     }
 
-    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    fn convert_utc(&self, mut utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
@@ -74,10 +74,10 @@ impl TimeZone for AmericanTimeZone {
             self.offset
         };
         utc.shift(offset);
-        utc.with_timezone(self)
+        utc.with_timezone(*self)
     }
 
-    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    fn resolve(&self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
@@ -93,15 +93,15 @@ impl TimeZone for AmericanTimeZone {
             // Ambiguous time because DST ended
             // 2021-11-7 1:30 -04:00 <- earlier
             // 2021-11-7 1:30 -05:00 <- later
-            DateTimeResolution::ambiguous(date, time, dst_offset, self.offset, self)
+            DateTimeResolution::ambiguous(date, time, dst_offset, self.offset, *self)
         } else if date == start && time.hour() >= 2 && time.hour() < 3 {
             // Impossible time because DST started (and time was skipped)
             // In this cas
-            DateTimeResolution::missing(date, time, self.offset, dst_offset, self)
+            DateTimeResolution::missing(date, time, self.offset, dst_offset, *self)
         } else if dt >= start_dt && dt < end_dt {
-            DateTimeResolution::unambiguous(date, time, dst_offset, self)
+            DateTimeResolution::unambiguous(date, time, dst_offset, *self)
         } else {
-            DateTimeResolution::unambiguous(date, time, self.offset, self)
+            DateTimeResolution::unambiguous(date, time, self.offset, *self)
         }
     }
 }
@@ -140,20 +140,20 @@ impl TimeZone for AlwaysEasternStandard {
         utc_offset!(-5:00)
     }
 
-    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    fn convert_utc(&self, mut utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
         utc.shift(utc_offset!(-5:00));
-        utc.with_timezone(self)
+        utc.with_timezone(*self)
     }
 
-    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    fn resolve(&self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
         // This is a bit weird so
-        DateTimeResolution::unambiguous(date, time, utc_offset!(-05:00), self)
+        DateTimeResolution::unambiguous(date, time, utc_offset!(-05:00), *self)
     }
 }
 
@@ -242,6 +242,35 @@ fn test_datetime_resolve() -> Result<(), eos::Error> {
     Ok(())
 }
 
+#[test]
+fn test_builder_build_at() -> Result<(), eos::Error> {
+    let mut builder = eos::Builder::new();
+    builder.year(2021).month(11).day(7).hour(1).minute(30);
+
+    // Ambiguous times resolve to the earlier time
+    assert_eq!(builder.build_at(EAST)?, datetime!(2021-11-07 1:30 am -04:00));
+    let resolve = builder.build_at_strict(EAST)?;
+    assert!(resolve.is_ambiguous());
+    assert_eq!(resolve.later()?, datetime!(2021-11-07 1:30 am -05:00));
+    // The builder's own timezone is exact
+    assert!(builder.clone().timezone(EAST).build().is_err());
+
+    // Missing times skip the gap
+    builder.month(3).day(14).hour(2);
+    assert_eq!(builder.build_at(EAST)?, datetime!(2021-03-14 03:30 am -04:00));
+    assert!(builder.build_at_strict(EAST)?.is_missing());
+
+    builder.hour(12);
+    assert_eq!(builder.build_at(EAST)?, datetime!(2021-03-14 12:30 pm -04:00));
+    assert!(builder.build_at_strict(EAST)?.is_unambiguous());
+
+    // Invalid components are still an error
+    builder.hour(25);
+    assert!(builder.build_at(EAST).is_err());
+    assert!(builder.build_at_strict(EAST).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_datetime_missing_interval() {
     let local = datetime!(2021-03-14 01:30).with_timezone(EAST);
@@ -256,3 +285,222 @@ fn test_datetime_missing_interval() {
     assert_eq!(local - 1.minutes(), datetime!(2021-03-14 01:59 -05:00));
     assert_eq!(local + 30.minutes(), datetime!(2021-03-14 03:30 -04:00));
 }
+
+#[test]
+fn test_subsecond_precision_across_timezones() {
+    const NANOS: u32 = 123_456_789;
+
+    // Includes a time right before the DST transition and one that crosses a day boundary
+    for utc in [
+        datetime!(2021-07-04 12:00:30),
+        datetime!(2021-03-14 06:59:59),
+        datetime!(2021-11-07 05:59:59),
+        datetime!(2021-12-31 23:59:59),
+        datetime!(1969-12-31 23:59:59),
+    ] {
+        let utc = utc.with_nanosecond(NANOS).unwrap();
+
+        for offset in [
+            utc_offset!(00:00),
+            utc_offset!(-05:00),
+            utc_offset!(+05:30),
+            utc_offset!(+14:00),
+            utc_offset!(-12:15:30),
+        ] {
+            let local = utc.in_timezone(offset);
+            assert_eq!(local.nanosecond(), NANOS);
+            assert_eq!(local, utc);
+            assert_eq!(local.in_timezone(Utc).nanosecond(), NANOS);
+            assert_eq!(local.with_timezone(Utc).nanosecond(), NANOS);
+        }
+
+        for tz in [EAST, CENTRAL, MOUNTAIN, PACIFIC] {
+            let local = utc.in_timezone(tz);
+            assert_eq!(local.nanosecond(), NANOS);
+            assert_eq!(local, utc);
+            assert_eq!(tz.convert_utc(utc).nanosecond(), NANOS);
+            assert_eq!(local.in_timezone(AlwaysEasternStandard).nanosecond(), NANOS);
+        }
+
+        let mut shifted = utc;
+        shifted.shift(utc_offset!(+09:45));
+        assert_eq!(shifted.nanosecond(), NANOS);
+        shifted.shift(utc_offset!(-09:45));
+        assert_eq!(shifted, utc);
+
+        let ts = utc.timestamp();
+        assert_eq!(DateTime::from_timestamp(ts, utc_offset!(-03:00)).nanosecond(), NANOS);
+        assert_eq!(DateTime::from_timestamp(ts, EAST).nanosecond(), NANOS);
+    }
+}
+
+#[test]
+fn test_tomorrow_yesterday_across_dst() {
+    use core::time::Duration;
+
+    // DST starts on 2021-03-14, so the day is only 23 hours long
+    let before = EAST.resolve(datetime!(2021-03-13 12:00).date(), time!(12:00)).lenient();
+    let after = before.tomorrow();
+    assert_eq!(after.date(), datetime!(2021-03-14 00:00).date());
+    assert_eq!(after.time(), time!(12:00));
+    assert_eq!(after.offset(), utc_offset!(-04:00));
+    assert_eq!(after.duration_since(&before), Duration::from_secs(23 * 3600));
+    assert_eq!(after.yesterday(), before);
+
+    // DST ends on 2021-11-07, so the day is 25 hours long
+    let before = EAST.resolve(datetime!(2021-11-06 12:00).date(), time!(12:00)).lenient();
+    let after = before.tomorrow();
+    assert_eq!(after.date(), datetime!(2021-11-07 00:00).date());
+    assert_eq!(after.time(), time!(12:00));
+    assert_eq!(after.offset(), utc_offset!(-05:00));
+    assert_eq!(after.duration_since(&before), Duration::from_secs(25 * 3600));
+    assert_eq!(after.yesterday(), before);
+
+    // Exact arithmetic does not preserve the wall time
+    assert_eq!(
+        (before.in_timezone(Utc) + 24.hours()).in_timezone(EAST).time(),
+        time!(11:00)
+    );
+
+    // Fixed offsets are always 24 hours apart
+    let fixed = datetime!(2021-03-13 12:00 -05:00);
+    assert_eq!(fixed.tomorrow().duration_since(&fixed), Duration::from_secs(24 * 3600));
+}
+
+#[test]
+fn test_add_reporting_dst() {
+    // DST starts on 2021-03-14
+    let dt = EAST.resolve(datetime!(2021-03-13 12:00).date(), time!(12:00)).lenient();
+    let (after, crossed) = dt.add_reporting_dst(1.days());
+    assert!(crossed);
+    assert_eq!(after, dt + 1.days());
+    assert_eq!(after.time(), time!(12:00));
+    assert_eq!(after.offset(), utc_offset!(-04:00));
+
+    // A normal day does not cross a transition
+    let dt = EAST.resolve(datetime!(2021-03-10 12:00).date(), time!(12:00)).lenient();
+    let (after, crossed) = dt.add_reporting_dst(1.days());
+    assert!(!crossed);
+    assert_eq!(after.offset(), dt.offset());
+
+    // Going back across the transition is also reported
+    let dt = EAST.resolve(datetime!(2021-03-15 12:00).date(), time!(12:00)).lenient();
+    let (before, crossed) = dt.add_reporting_dst((-2).days());
+    assert!(crossed);
+    assert_eq!(before.offset(), utc_offset!(-05:00));
+
+    // DST ends on 2021-11-07
+    let dt = EAST.resolve(datetime!(2021-11-06 23:30).date(), time!(23:30)).lenient();
+    assert!(dt.add_reporting_dst(3.hours()).1);
+    assert!(!dt.add_reporting_dst(1.hours()).1);
+}
+
+#[test]
+fn test_month_and_year_boundaries_across_dst() {
+    // 2021-03-31 is in DST while 2021-03-01 is not
+    let dt = EAST.resolve(datetime!(2021-03-31 12:00).date(), time!(12:00)).lenient();
+    assert_eq!(dt.offset(), utc_offset!(-04:00));
+
+    let first = dt.first_day_of_month();
+    assert_eq!(first.date(), datetime!(2021-03-01 00:00).date());
+    assert_eq!(first.time(), time!(12:00));
+    assert_eq!(first.offset(), utc_offset!(-05:00));
+    assert_eq!(first.last_day_of_month(), dt);
+
+    let first = dt.first_day_of_year();
+    assert_eq!(first.date(), datetime!(2021-01-01 00:00).date());
+    assert_eq!(first.offset(), utc_offset!(-05:00));
+
+    let last = dt.last_day_of_year();
+    assert_eq!(last.date(), datetime!(2021-12-31 00:00).date());
+    assert_eq!(last.time(), time!(12:00));
+    assert_eq!(last.offset(), utc_offset!(-05:00));
+}
+
+/// An [`AmericanTimeZone`] that relies on the default [`TimeZone::resolve`] implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct OffsetOnly(AmericanTimeZone);
+
+impl TimeZone for OffsetOnly {
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.0.offset(ts)
+    }
+
+    fn convert_utc(&self, utc: DateTime<Utc>) -> DateTime<Self> {
+        self.0.convert_utc(utc).with_timezone(*self)
+    }
+}
+
+#[test]
+fn test_default_resolve() {
+    // The other timezones use the eastern transition instants for their offsets
+    let tz = EAST;
+    for dt in [
+        datetime!(2021-01-01 12:00),
+        datetime!(2021-07-04 12:00),
+        datetime!(2021-03-14 01:59),
+        datetime!(2021-03-14 02:00),
+        datetime!(2021-03-14 02:30),
+        datetime!(2021-03-14 03:00),
+        datetime!(2021-11-07 00:59),
+        datetime!(2021-11-07 01:00),
+        datetime!(2021-11-07 01:30),
+        datetime!(2021-11-07 02:00),
+    ] {
+        let expected = tz.resolve(dt.date(), dt.time());
+        let resolved = OffsetOnly(tz).resolve(dt.date(), dt.time());
+        assert_eq!(resolved.kind(), expected.kind(), "{dt}");
+        assert_eq!(resolved.earlier_offset(), expected.earlier_offset(), "{dt}");
+        assert_eq!(resolved.later_offset(), expected.later_offset(), "{dt}");
+    }
+
+    let resolved = AlwaysEasternStandard.offset_at_local(DT.date(), DT.time());
+    assert!(resolved.is_unambiguous());
+    assert_eq!(resolved.earlier_offset(), &utc_offset!(-05:00));
+}
+
+#[test]
+fn test_clamp_between() {
+    // 2021-03-14 06:00 to 2021-03-14 12:00 UTC, which crosses the DST transition in EAST
+    let min = datetime!(2021-03-14 11:30 +05:30);
+    let max = datetime!(2021-03-14 02:00 -10:00);
+    let date = Date::new(2021, 3, 14).unwrap();
+
+    let local = EAST.at(date, time!(00:00));
+    let clamped = local.clamp_between(min, max);
+    assert_eq!(clamped, datetime!(2021-03-14 06:00));
+    assert_eq!(clamped.offset(), utc_offset!(-05:00));
+    assert_eq!(clamped.time(), time!(01:00));
+
+    let local = EAST.at(date, time!(09:00));
+    let clamped = local.clamp_between(min, max);
+    assert_eq!(clamped, datetime!(2021-03-14 12:00));
+    assert_eq!(clamped.offset(), utc_offset!(-04:00));
+    assert_eq!(clamped.time(), time!(08:00));
+
+    // Within the bounds returns the same wall-clock time
+    let local = EAST.at(date, time!(04:00));
+    let clamped = local.clamp_between(min, max);
+    assert_eq!(clamped.time(), time!(04:00));
+    assert_eq!(clamped.offset(), utc_offset!(-04:00));
+
+    // The bounds are inclusive
+    let dt = datetime!(2021-03-14 06:00);
+    assert_eq!(dt.clamp_between(min, max).offset(), UtcOffset::UTC);
+    assert_eq!(dt.clamp_between(min, max).time(), time!(06:00));
+
+    // Instants are compared, so sub-second differences matter even across offsets
+    let min = min.with_nanosecond(500).unwrap();
+    let clamped = dt.clamp_between(min, max);
+    assert_eq!(clamped, datetime!(2021-03-14 06:00).with_nanosecond(500).unwrap());
+    assert!(dt < min);
+    assert!(min > dt);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_between_reversed_bounds() {
+    let min = datetime!(2021-03-14 11:30 +05:30);
+    let max = datetime!(2021-03-14 02:00 -10:00);
+    let _ = datetime!(2021-03-14 06:00).clamp_between(max, min);
+}